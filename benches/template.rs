@@ -0,0 +1,106 @@
+use caroline::types::AWS::EC2::Subnet::SubnetBuilder;
+use caroline::types::AWS::EC2::VPC::VPCBuilder;
+use caroline::types::AWS::S3::Bucket::BucketBuilder;
+use caroline::{Intrinsic, Template};
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Number of resources of the synthetic templates
+const SIZES: [usize; 3] = [100, 1_000, 5_000];
+
+/// A template of `size` resources, one VPC per ten resources, with subnets and buckets
+/// referencing it
+fn synthetic(size: usize) -> Template {
+    let mut template = Template::new();
+    for idx in 0..size {
+        let vpc_id = format!("Vpc{}", idx / 10);
+        match idx % 10 {
+            0 => {
+                let vpc = VPCBuilder::default()
+                    .CidrBlock("10.0.0.0/16")
+                    .EnableDnsSupport(true)
+                    .build()
+                    .unwrap();
+                template.add_resource(vpc_id, vpc).unwrap();
+            }
+            n if n < 7 => {
+                let subnet = SubnetBuilder::default()
+                    .VpcId(Intrinsic::Ref(vpc_id))
+                    .CidrBlock(format!("10.0.{}.0/24", n))
+                    .AvailabilityZone(Intrinsic::select(n as u32 - 1, Intrinsic::get_azs("")))
+                    .build()
+                    .unwrap();
+                template
+                    .add_resource(format!("Subnet{}", idx), subnet)
+                    .unwrap();
+            }
+            _ => {
+                let bucket = BucketBuilder::default()
+                    .BucketName(Intrinsic::sub(format!("bucket-{}-${{AWS::Region}}", idx)))
+                    .build()
+                    .unwrap();
+                template
+                    .add_resource(format!("Bucket{}", idx), bucket)
+                    .unwrap()
+                    .depends_on(vpc_id)
+                    .unwrap();
+            }
+        }
+    }
+    template
+}
+
+fn assemble(c: &mut Criterion) {
+    let mut group = c.benchmark_group("assemble");
+    for size in SIZES.iter() {
+        group.bench_with_input(BenchmarkId::from_parameter(size), size, |b, &size| {
+            b.iter(|| synthetic(black_box(size)))
+        });
+    }
+    group.finish();
+}
+
+fn serialize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("serialize");
+    for size in SIZES.iter() {
+        let template = synthetic(*size);
+        group.bench_with_input(BenchmarkId::new("json", size), &template, |b, template| {
+            b.iter(|| template.to_json().unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("yaml", size), &template, |b, template| {
+            b.iter(|| template.to_yaml().unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn deserialize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("deserialize");
+    for size in SIZES.iter() {
+        let template = synthetic(*size);
+        let json = template.to_json().unwrap();
+        let yaml = template.to_yaml().unwrap();
+        group.bench_with_input(BenchmarkId::new("json", size), &json, |b, json| {
+            b.iter(|| Template::from_json(json).unwrap())
+        });
+        group.bench_with_input(BenchmarkId::new("yaml", size), &yaml, |b, yaml| {
+            b.iter(|| Template::from_yaml(yaml).unwrap())
+        });
+    }
+    group.finish();
+}
+
+fn validate(c: &mut Criterion) {
+    let mut group = c.benchmark_group("validate");
+    for size in SIZES.iter() {
+        let template = synthetic(*size);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(size),
+            &template,
+            |b, template| b.iter(|| template.validate()),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, assemble, serialize, deserialize, validate);
+criterion_main!(benches);
@@ -1,11 +1,15 @@
 use proffer::*;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
-use std::collections::HashMap;
+use serde_json::{json, Value};
+use std::collections::btree_map::Entry;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::env;
 use std::fs;
+use std::path::Path;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Default)]
 pub enum PrimitiveType {
+    #[default]
     String,
     Boolean,
     Integer,
@@ -21,27 +25,50 @@ impl PrimitiveType {
             PrimitiveType::Boolean => "bool",
             PrimitiveType::Integer => "i32",
             PrimitiveType::Double => "f32",
-            PrimitiveType::Timestamp => "u32",
+            PrimitiveType::Timestamp => "String",
             PrimitiveType::Json => "Value",
-            PrimitiveType::Long => "u32",
+            PrimitiveType::Long => "u64",
         }
     }
-}
-impl Default for PrimitiveType {
-    fn default() -> PrimitiveType {
-        PrimitiveType::String
+
+    /// JSON Schema of a value of the type, Json taking any value and timestamps being ISO 8601
+    /// strings as in templates
+    pub fn as_json_schema(&self) -> Value {
+        match self {
+            PrimitiveType::String => json!({"type": "string"}),
+            PrimitiveType::Boolean => json!({"type": "boolean"}),
+            PrimitiveType::Integer => json!({"type": "integer"}),
+            PrimitiveType::Double => json!({"type": "number"}),
+            PrimitiveType::Timestamp => json!({"type": "string", "format": "date-time"}),
+            PrimitiveType::Long => json!({"type": "integer", "minimum": 0}),
+            PrimitiveType::Json => json!({}),
+        }
+    }
+
+    /// Rust type of a property, values other than Json may also be computed by intrinsic functions.
+    pub fn as_property_ty(&self) -> String {
+        match self {
+            PrimitiveType::Json => self.as_rust_ty().to_string(),
+            _ => format!("Expr<{}>", self.as_rust_ty()),
+        }
     }
 }
 
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, Default)]
 pub enum UpdateType {
     Mutable,
+    #[default]
     Immutable,
     Conditional,
 }
-impl Default for UpdateType {
-    fn default() -> UpdateType {
-        UpdateType::Immutable
+
+impl UpdateType {
+    pub fn as_str(&self) -> &str {
+        match self {
+            UpdateType::Mutable => "Mutable",
+            UpdateType::Immutable => "Immutable",
+            UpdateType::Conditional => "Conditional",
+        }
     }
 }
 
@@ -64,6 +91,33 @@ pub struct Property {
     primitive_item_type: Option<PrimitiveType>,
 }
 
+impl Property {
+    /// Type, requirement and update behaviour of the property, as compared by spec diffs
+    pub fn signature(&self) -> Value {
+        let primitive = |ty: &PrimitiveType| serde_json::to_value(ty).unwrap();
+        let item = || match (&self.item_type, &self.primitive_item_type) {
+            (Some(item), _) => item.clone(),
+            (None, Some(item)) => primitive(item).as_str().unwrap().to_string(),
+            (None, None) => "Value".to_string(),
+        };
+        let type_ = match self.type_.as_deref() {
+            Some(collection @ "List") | Some(collection @ "Map") => {
+                format!("{}<{}>", collection, item())
+            }
+            Some(type_) => type_.to_string(),
+            None => primitive(&self.primitive_type)
+                .as_str()
+                .unwrap()
+                .to_string(),
+        };
+        json!({
+            "Type": type_,
+            "Required": self.required,
+            "UpdateType": self.update_type.as_str(),
+        })
+    }
+}
+
 /// A Resource or Property Type
 #[derive(Serialize, Deserialize, Default)]
 pub struct Type {
@@ -76,13 +130,27 @@ pub struct Type {
 /// Both Resource and Property Types
 type Types = HashMap<String, Type>;
 
+/// Default values of properties, keyed by type name then property name
+type Defaults = HashMap<String, HashMap<String, Value>>;
+
+/// Rust types of the crate replacing the primitive type of properties, ie. an enum for a
+/// string with a fixed set of values, keyed by type name then property name
+type Overrides = HashMap<String, HashMap<String, String>>;
+
+/// Names identifying an existing resource when importing it into a stack, ie. `BucketName`,
+/// keyed by resource type name. Types without an entry can't be imported.
+type Identifiers = HashMap<String, Vec<String>>;
+
+/// Properties holding secrets, ie. passwords, keyed by type name
+type Sensitive = HashMap<String, Vec<String>>;
+
 pub struct TypeMetadata {
     pub module_path: Vec<String>,
     pub struct_name: String,
     pub is_sub_property: bool,
 }
 
-impl<'a> From<&'a str> for TypeMetadata {
+impl From<&str> for TypeMetadata {
     fn from(path: &str) -> Self {
         /*
         Can get this: AWS::EMR::Cluster or something like this: AWS::EMR::Cluster.VolumeSpecification
@@ -105,30 +173,816 @@ impl<'a> From<&'a str> for TypeMetadata {
                 }
             })
             .collect();
-        let struct_name = s[s.len() - 1].split('.').last().unwrap().to_string();
+        let struct_name = s[s.len() - 1].split('.').next_back().unwrap().to_string();
 
-        Self {
+        let mut meta = Self {
             module_path,
             struct_name,
             is_sub_property: path.contains('.'),
+        };
+        if meta.is_sub_property {
+            meta.struct_name = meta.resolve_type_name(&meta.struct_name);
+        }
+        meta
+    }
+}
+
+impl TypeMetadata {
+    /// Name of the struct a type reference resolves to from within this type's module.
+    ///
+    /// Property types sharing the name of their resource, ie. `AWS::SES::Template.Template`,
+    /// are suffixed with `Property` so they don't collide with the resource struct.
+    pub fn resolve_type_name(&self, type_name: &str) -> String {
+        match self.module_path.last() {
+            Some(resource) if resource == type_name => format!("{}Property", type_name),
+            _ => type_name.to_string(),
+        }
+    }
+}
+
+/// Attribute gating the service of a module path, ie. `AWS::EC2`, behind its Cargo feature.
+/// Third-party registry types, ie. `MongoDB::Atlas`, are gated behind `registry` instead.
+pub fn feature_cfg(module_path: &[String]) -> String {
+    match feature_name(module_path) {
+        Some(feature) => format!(r#"#[cfg(any(feature = "all", feature = "{}"))]"#, feature),
+        None => String::new(),
+    }
+}
+
+/// Cargo feature enabling the service module of `module_path`, see `feature_cfg`
+pub fn feature_name(module_path: &[String]) -> Option<String> {
+    match module_path {
+        [namespace, service, ..] if namespace == "AWS" => Some(service.to_lowercase()),
+        [_, _, ..] => Some("registry".to_string()),
+        _ => None,
+    }
+}
+
+/// Whether the Cargo features the crate is built with enable the service module of any of
+/// `resource_names`, Cargo sets `CARGO_FEATURE_<NAME>` for each of them
+pub fn services_enabled(resource_names: &[String]) -> bool {
+    env::var_os("CARGO_FEATURE_ALL").is_some()
+        || resource_names.iter().any(|type_name| {
+            feature_name(&TypeMetadata::from(type_name.as_str()).module_path).is_some_and(
+                |feature| {
+                    env::var_os(format!("CARGO_FEATURE_{}", feature.to_uppercase())).is_some()
+                },
+            )
+        })
+}
+
+/// Merge `other` types into `types`, extending the properties of any type already defined.
+pub fn merge_types(types: &mut Types, other: Types) {
+    other
+        .into_iter()
+        .for_each(|(type_name, the_type)| match types.get_mut(&type_name) {
+            Some(existing) => {
+                if existing.documentation.is_empty() {
+                    existing.documentation = the_type.documentation;
+                }
+                existing.properties.extend(the_type.properties);
+            }
+            None => {
+                types.insert(type_name, the_type);
+            }
+        });
+}
+
+/// Read the property and resource types of a specification file.
+///
+/// Either section may be left out, ie. by fragments adding resource types only, but a malformed
+/// one fails the build rather than silently dropping its types.
+pub fn read_spec(path: &Path) -> (Types, Types) {
+    let spec_bytes = fs::read(path).unwrap();
+    let spec: Value = serde_json::from_slice(&spec_bytes).unwrap();
+
+    let section = |name: &str| -> Types {
+        match spec.get(name) {
+            Some(types) => serde_json::from_value(types.clone())
+                .unwrap_or_else(|e| panic!("{}: {}: {}", path.display(), name, e)),
+            None => Types::default(),
+        }
+    };
+    (section("PropertyTypes"), section("ResourceTypes"))
+}
+
+/// Read the `ResourceSpecificationVersion` of a specification file.
+pub fn read_spec_version(path: &Path) -> String {
+    let spec: Value = serde_json::from_slice(&fs::read(path).unwrap()).unwrap();
+    spec["ResourceSpecificationVersion"]
+        .as_str()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Read a CloudFormation Registry resource schema, ie. of the third-party type
+/// `MongoDB::Atlas::Cluster`, as the property and resource types of a specification.
+///
+/// Read-only properties are left out as they're attributes, create-only properties are
+/// `Immutable` and everything else `Mutable`. Definitions with properties of their own become
+/// property types, anything the specification format can't express is kept as `Json`.
+pub fn read_registry_schema(path: &Path) -> (Types, Types) {
+    let schema: Value = serde_json::from_slice(&fs::read(path).unwrap()).unwrap();
+    let type_name = schema["typeName"].as_str().unwrap().to_string();
+    let documentation = schema["documentationUrl"]
+        .as_str()
+        .or_else(|| schema["sourceUrl"].as_str())
+        .unwrap_or_default()
+        .to_string();
+    let pointers = |key: &str| {
+        schema[key]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|pointer| pointer.as_str()?.strip_prefix("/properties/"))
+            .map(str::to_string)
+            .collect::<Vec<String>>()
+    };
+    let (read_only, create_only) = (
+        pointers("readOnlyProperties"),
+        pointers("createOnlyProperties"),
+    );
+    let definitions = schema["definitions"]
+        .as_object()
+        .cloned()
+        .unwrap_or_default();
+
+    let schema_type = |schema: &Value| {
+        let required = schema["required"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(Value::as_str)
+            .collect::<Vec<&str>>();
+        let properties = schema["properties"]
+            .as_object()
+            .into_iter()
+            .flatten()
+            .map(|(name, property)| {
+                let mut property = schema_property(property, &definitions);
+                property.required = required.contains(&name.as_str());
+                property.update_type = UpdateType::Mutable;
+                property.documentation = documentation.clone();
+                (name.clone(), property)
+            })
+            .collect();
+        Type {
+            documentation: documentation.clone(),
+            properties,
         }
+    };
+
+    let mut resource = schema_type(&schema);
+    resource
+        .properties
+        .retain(|name, _| !read_only.contains(name));
+    for (name, property) in resource.properties.iter_mut() {
+        if create_only.contains(name) {
+            property.update_type = UpdateType::Immutable;
+        }
+    }
+    let property_types = definitions
+        .iter()
+        .filter(|(_, definition)| definition["properties"].is_object())
+        .map(|(name, definition)| (format!("{}.{}", type_name, name), schema_type(definition)))
+        .collect();
+    let resource_types = vec![(type_name, resource)].into_iter().collect();
+    (property_types, resource_types)
+}
+
+/// Spec representation of a property of a registry schema, with `Required` and `UpdateType`
+/// left for the caller to fill in
+fn schema_property(schema: &Value, definitions: &serde_json::Map<String, Value>) -> Property {
+    let mut property = Property::default();
+    if let Some(name) = schema["$ref"]
+        .as_str()
+        .and_then(|pointer| pointer.strip_prefix("#/definitions/"))
+    {
+        match definitions.get(name) {
+            Some(definition) if definition["properties"].is_object() => {
+                property.type_ = Some(name.to_string())
+            }
+            Some(definition) => return schema_property(definition, definitions),
+            None => property.primitive_type = PrimitiveType::Json,
+        }
+        return property;
+    }
+    match schema["type"].as_str() {
+        Some("array") => {
+            let items = schema_property(&schema["items"], definitions);
+            property.type_ = Some("List".to_string());
+            match items.type_ {
+                Some(item) if item != "List" => property.item_type = Some(item),
+                Some(_) => property.primitive_item_type = Some(PrimitiveType::Json),
+                None => property.primitive_item_type = Some(items.primitive_type),
+            }
+        }
+        Some(primitive) => {
+            property.primitive_type = match primitive {
+                "string" => PrimitiveType::String,
+                "integer" => PrimitiveType::Integer,
+                "number" => PrimitiveType::Double,
+                "boolean" => PrimitiveType::Boolean,
+                _ => PrimitiveType::Json,
+            }
+        }
+        None => property.primitive_type = PrimitiveType::Json,
     }
+    property
 }
 
-/// Get the last segment of the module path, which is the struct name.
+/// Read the property and resource types of a `definitions` directory, the bundled
+/// specification merged with the fragments under `extensions`, along with its version.
+pub fn read_definitions(definitions: &Path) -> (Types, Types, String) {
+    let spec = definitions.join("CloudFormationResourceSpecification.json");
+    let (mut property_types, mut resource_types) = read_spec(&spec);
+
+    // Services and types not yet covered by the bundled specification are described by
+    // fragments in the same format under `definitions/extensions`.
+    let mut extensions = fs::read_dir(definitions.join("extensions"))
+        .map(|entries| {
+            entries
+                .map(|entry| entry.unwrap().path())
+                .filter(|path| path.extension().map(|ext| ext == "json").unwrap_or(false))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    extensions.sort();
+    extensions.iter().for_each(|path| {
+        let (extra_property_types, extra_resource_types) = read_spec(path);
+        merge_types(&mut property_types, extra_property_types);
+        merge_types(&mut resource_types, extra_resource_types);
+    });
+
+    // Third-party and private types of the CloudFormation Registry are described by their
+    // resource schemas under `definitions/registry`.
+    read_registry_schemas(
+        &definitions.join("registry"),
+        &mut property_types,
+        &mut resource_types,
+    );
+    (property_types, resource_types, read_spec_version(&spec))
+}
+
+/// Add the types of every registry schema within `dir`, see `read_registry_schema`
+pub fn read_registry_schemas(dir: &Path, property_types: &mut Types, resource_types: &mut Types) {
+    let mut schemas = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .map(|entry| entry.unwrap().path())
+                .filter(|path| path.extension().map(|ext| ext == "json").unwrap_or(false))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    schemas.sort();
+    schemas.iter().for_each(|path| {
+        let (extra_property_types, extra_resource_types) = read_registry_schema(path);
+        merge_types(property_types, extra_property_types);
+        merge_types(resource_types, extra_resource_types);
+    });
+}
+
+/// Import identifiers of the registry schemas within `dir`, from their `primaryIdentifier`
+pub fn read_registry_identifiers(dir: &Path, identifiers: &mut Identifiers) {
+    let schemas = fs::read_dir(dir)
+        .map(|entries| {
+            entries
+                .map(|entry| entry.unwrap().path())
+                .filter(|path| path.extension().map(|ext| ext == "json").unwrap_or(false))
+                .collect::<Vec<_>>()
+        })
+        .unwrap_or_default();
+    for path in schemas {
+        let schema: Value = serde_json::from_slice(&fs::read(&path).unwrap()).unwrap();
+        let names = schema["primaryIdentifier"]
+            .as_array()
+            .into_iter()
+            .flatten()
+            .filter_map(|pointer| pointer.as_str()?.strip_prefix("/properties/"))
+            .map(str::to_string)
+            .collect::<Vec<String>>();
+        if !names.is_empty() {
+            let type_name = schema["typeName"].as_str().unwrap().to_string();
+            identifiers.insert(type_name, names);
+        }
+    }
+}
+
+/// Report of the types and properties added, removed and changed from the `old` to the `new`
+/// specification, with both resource and property types keyed by their full type name.
+///
+/// ```json
+/// {
+///   "From": "6.2.0",
+///   "To": "6.3.0",
+///   "Added": ["AWS::S3::AccessPoint"],
+///   "Removed": [],
+///   "Changed": {
+///     "AWS::S3::Bucket": {
+///       "Added": ["OwnershipControls"],
+///       "Removed": [],
+///       "Changed": {
+///         "BucketName": {
+///           "From": {"Type": "String", "Required": false, "UpdateType": "Mutable"},
+///           "To": {"Type": "String", "Required": false, "UpdateType": "Immutable"}
+///         }
+///       }
+///     }
+///   }
+/// }
+/// ```
+pub fn diff_specs(old: &Types, old_version: &str, new: &Types, new_version: &str) -> Value {
+    let added = missing(new, old);
+    let removed = missing(old, new);
+
+    let mut changed = BTreeMap::new();
+    for (type_name, new_type) in new.iter() {
+        let old_type = match old.get(type_name) {
+            Some(old_type) => old_type,
+            None => continue,
+        };
+        let mut changed_properties = BTreeMap::new();
+        for (name, property) in new_type.properties.iter() {
+            if let Some(old_property) = old_type.properties.get(name) {
+                let (from, to) = (old_property.signature(), property.signature());
+                if from != to {
+                    changed_properties.insert(name, json!({"From": from, "To": to}));
+                }
+            }
+        }
+        let added = missing(&new_type.properties, &old_type.properties);
+        let removed = missing(&old_type.properties, &new_type.properties);
+        if !added.is_empty() || !removed.is_empty() || !changed_properties.is_empty() {
+            changed.insert(
+                type_name,
+                json!({"Added": added, "Removed": removed, "Changed": changed_properties}),
+            );
+        }
+    }
+
+    json!({
+        "From": old_version,
+        "To": new_version,
+        "Added": added,
+        "Removed": removed,
+        "Changed": changed,
+    })
+}
+
+/// Keys of `of` which aren't in `other`, in order
+fn missing<'a, T, U>(
+    of: &'a HashMap<String, T>,
+    other: &HashMap<String, U>,
+) -> BTreeSet<&'a String> {
+    of.keys().filter(|key| !other.contains_key(*key)).collect()
+}
+
+/// JSON Schema (draft-07) of the properties of `resource_type`, with its property types under
+/// `definitions`, so tooling outside of Rust can check input before it reaches the generated
+/// types. Values which the generated types take as `Expr`s may also be intrinsic functions.
+///
+/// ```json
+/// {
+///   "$schema": "http://json-schema.org/draft-07/schema#",
+///   "title": "AWS::EC2::Instance",
+///   "type": "object",
+///   "properties": {
+///     "BlockDeviceMappings": {
+///       "type": "array",
+///       "items": {"$ref": "#/definitions/BlockDeviceMapping"}
+///     },
+///     "ImageId": {"anyOf": [{"type": "string"}, {"$ref": "#/definitions/Intrinsic"}]}
+///   },
+///   "additionalProperties": false,
+///   "definitions": {"BlockDeviceMapping": {...}, "Intrinsic": {...}}
+/// }
+/// ```
+pub fn json_schema(resource_type: &str, types: &Types) -> Value {
+    let mut definitions = BTreeMap::new();
+    definitions.insert(
+        "Intrinsic".to_string(),
+        json!({
+            "type": "object",
+            "minProperties": 1,
+            "maxProperties": 1,
+            "propertyNames": {"pattern": "^(Ref|Condition|Fn::[A-Za-z0-9]+)$"}
+        }),
+    );
+    let mut pending = vec![];
+    let mut schema = object_schema(resource_type, &types[resource_type], types, &mut pending);
+    // Property types may be recursive, each is defined once whatever refers to it
+    while let Some((name, type_name)) = pending.pop() {
+        if let Entry::Vacant(entry) = definitions.entry(name) {
+            entry.insert(object_schema(
+                &type_name,
+                &types[&type_name],
+                types,
+                &mut pending,
+            ));
+        }
+    }
+    schema["$schema"] = json!("http://json-schema.org/draft-07/schema#");
+    schema["title"] = json!(resource_type);
+    schema["definitions"] = json!(definitions);
+    schema
+}
+
+/// Schema of the properties of `the_type`, queueing the property types it refers to in
+/// `pending` by their definition name and full type name
+fn object_schema(
+    type_name: &str,
+    the_type: &Type,
+    types: &Types,
+    pending: &mut Vec<(String, String)>,
+) -> Value {
+    let properties = the_type
+        .properties
+        .iter()
+        .map(|(name, property)| (name, property_schema(type_name, property, types, pending)))
+        .collect::<BTreeMap<&String, Value>>();
+    let required = the_type
+        .properties
+        .iter()
+        .filter(|(_, property)| property.required)
+        .map(|(name, _)| name)
+        .collect::<BTreeSet<&String>>();
+    let mut schema = json!({
+        "type": "object",
+        "properties": properties,
+        "additionalProperties": false,
+    });
+    if !the_type.documentation.is_empty() {
+        schema["description"] = json!(the_type.documentation);
+    }
+    if !required.is_empty() {
+        schema["required"] = json!(required);
+    }
+    schema
+}
+
+fn property_schema(
+    type_name: &str,
+    property: &Property,
+    types: &Types,
+    pending: &mut Vec<(String, String)>,
+) -> Value {
+    let primitive = |primitive: &PrimitiveType| match primitive {
+        PrimitiveType::Json => primitive.as_json_schema(),
+        _ => json!({"anyOf": [primitive.as_json_schema(), {"$ref": "#/definitions/Intrinsic"}]}),
+    };
+    // Property types are defined within the resource, but for global ones like `Tag`
+    let mut type_ref = |name: &str| {
+        let resource = type_name.split('.').next().unwrap();
+        let full_name = match types.contains_key(&format!("{}.{}", resource, name)) {
+            true => format!("{}.{}", resource, name),
+            false => name.to_string(),
+        };
+        pending.push((name.to_string(), full_name));
+        json!({"$ref": format!("#/definitions/{}", name)})
+    };
+    let item = match (&property.item_type, &property.primitive_item_type) {
+        (Some(item), _) => type_ref(item.as_str()),
+        (None, item) => primitive(item.as_ref().unwrap_or(&PrimitiveType::Json)),
+    };
+    let mut schema = match property.type_.as_deref() {
+        Some("List") => json!({"type": "array", "items": item}),
+        Some("Map") => json!({"type": "object", "additionalProperties": item}),
+        Some(name) => type_ref(name),
+        None => primitive(&property.primitive_type),
+    };
+    if !property.documentation.is_empty() {
+        schema["description"] = json!(property.documentation);
+    }
+    schema
+}
+
+/// Write the JSON Schema of every resource type to `dir`, named after the type,
+/// ie. `aws-ec2-vpc.json` for `AWS::EC2::VPC`
+pub fn write_json_schemas(dir: &Path, resource_names: &[String], types: &Types) {
+    fs::create_dir_all(dir).unwrap();
+    for resource_type in resource_names {
+        let file_name = format!("{}.json", resource_type.replace("::", "-").to_lowercase());
+        let schema = json_schema(resource_type, types);
+        fs::write(
+            dir.join(file_name),
+            serde_json::to_string_pretty(&schema).unwrap(),
+        )
+        .unwrap();
+    }
+}
+
+/// Rust type of a property, leaving out the `Option` of properties which aren't required.
+/// Primitives are `Expr`s of their primitive type, or of `override_` if given.
+fn property_ty(meta: &TypeMetadata, property: &Property, override_: Option<&String>) -> String {
+    let primitive_ty = |primitive: &PrimitiveType| match override_ {
+        Some(rust_ty) => format!("Expr<{}>", rust_ty),
+        None => primitive.as_property_ty(),
+    };
+    // Lists and maps of items of unknown type take any JSON rather than strings
+    let item_ty = || {
+        property
+            .item_type
+            .as_deref()
+            .map(|v| meta.resolve_type_name(v))
+            .unwrap_or_else(|| {
+                primitive_ty(
+                    property
+                        .primitive_item_type
+                        .as_ref()
+                        .unwrap_or(&PrimitiveType::Json),
+                )
+            })
+    };
+    match property.type_.as_deref() {
+        Some("List") => format!("Vec<{}>", item_ty()),
+        Some("Map") => format!("HashMap<String, {}>", item_ty()),
+        Some(a) => meta.resolve_type_name(a),
+        None => primitive_ty(&property.primitive_type),
+    }
+}
+
+/// Snake case getters and setters of the properties of a type, ie. `max_capacity()` and
+/// `set_max_capacity(...)` for `MaxCapacity`, so user code doesn't have to name fields in
+/// PascalCase. Properties whose names would collide with another method are left out.
+fn accessors(
+    meta: &TypeMetadata,
+    the_type: &Type,
+    type_overrides: &HashMap<String, String>,
+) -> Vec<Function> {
+    let mut names = the_type.properties.keys().collect::<Vec<&String>>();
+    names.sort();
+    let methods = |name: &str| vec![snake_case(name), format!("set_{}", snake_case(name))];
+    let mut counts: HashMap<String, usize> = ["new", "default_for_field", "visit_properties"]
+        .iter()
+        .map(|name| (name.to_string(), 1))
+        .collect();
+    for method in names.iter().flat_map(|name| methods(name)) {
+        *counts.entry(method).or_default() += 1;
+    }
+
+    names
+        .into_iter()
+        .filter(|name| methods(name).iter().all(|method| counts[method] == 1))
+        .flat_map(|name| {
+            let property = &the_type.properties[name];
+            let snake = snake_case(name);
+            let type_ = property_ty(meta, property, type_overrides.get(name));
+            let (getter_ty, getter_body, value) = match property.required {
+                true => (
+                    format!("&{}", type_),
+                    format!("&self.{}", name),
+                    "value.into()",
+                ),
+                false => (
+                    format!("Option<&{}>", type_),
+                    format!("self.{}.as_ref()", name),
+                    "Some(value.into())",
+                ),
+            };
+            let getter = Function::new(identifier(snake.clone()))
+                .set_is_pub(true)
+                .add_doc(format!("/// The `{}` property", name))
+                .add_parameter(Parameter::new("self", "&Self"))
+                .set_return_ty(getter_ty)
+                .set_body(getter_body)
+                .to_owned();
+            let setter = Function::new(format!("set_{}", snake))
+                .set_is_pub(true)
+                .add_doc(format!("/// Set the `{}` property", name))
+                .add_parameter(Parameter::new("self", "&mut Self"))
+                .add_parameter(Parameter::new("value", format!("impl Into<{}>", type_)))
+                .set_return_ty("&mut Self")
+                .set_body(format!("self.{} = {};\nself", name, value))
+                .to_owned();
+            vec![getter, setter]
+        })
+        .collect()
+}
+
+/// What the generated types of a service cover, documented on its module, see `coverage_docs`,
+/// and available at runtime through `registry::services`
+#[derive(Default)]
+pub struct Coverage {
+    /// Number of properties and of property types of each resource, and whether the bundled
+    /// specification defines it
+    resources: BTreeMap<String, (usize, usize, bool)>,
+    /// Types and properties added on top of the bundled specification, by extensions or
+    /// registry schemas
+    added: BTreeSet<String>,
+    /// Properties of arbitrary JSON without an override, kept as an untyped `Value`
+    untyped: BTreeSet<String>,
+    /// Types and properties of the bundled specification the generated types lack
+    missing: BTreeSet<String>,
+}
+
+/// Coverage of the generated `types` keyed by service, ie. `AWS::EC2`, `spec` being the types
+/// of the bundled specification alone.
+pub fn build_coverage(
+    types: &Types,
+    spec: &Types,
+    overrides: &Overrides,
+) -> BTreeMap<String, Coverage> {
+    let mut services: BTreeMap<String, Coverage> = BTreeMap::new();
+    for (type_name, the_type) in types.iter() {
+        let meta = TypeMetadata::from(type_name.as_str());
+        // Global types, ie. `Tag`, belong to no service
+        if meta.module_path.len() < 3 {
+            continue;
+        }
+        let (service_path, resource) = meta.module_path.split_at(meta.module_path.len() - 1);
+        let coverage = services.entry(service_path.join("::")).or_default();
+
+        let (properties, property_types, specified) =
+            coverage.resources.entry(resource[0].clone()).or_default();
+        match meta.is_sub_property {
+            true => *property_types += 1,
+            false => {
+                *properties = the_type.properties.len();
+                *specified = spec.contains_key(type_name);
+            }
+        }
+
+        match spec.get(type_name) {
+            Some(spec_type) => {
+                for name in missing(&the_type.properties, &spec_type.properties) {
+                    coverage.added.insert(format!("{}.{}", type_name, name));
+                }
+                for name in missing(&spec_type.properties, &the_type.properties) {
+                    coverage.missing.insert(format!("{}.{}", type_name, name));
+                }
+            }
+            None => {
+                coverage.added.insert(type_name.clone());
+            }
+        }
+
+        let type_overrides = overrides.get(type_name);
+        for (name, property) in the_type.properties.iter() {
+            let is_json = match property.type_.as_deref() {
+                Some("List") | Some("Map") => {
+                    property.item_type.is_none()
+                        && matches!(property.primitive_item_type, Some(PrimitiveType::Json))
+                }
+                Some(_) => false,
+                None => matches!(property.primitive_type, PrimitiveType::Json),
+            };
+            if is_json && !type_overrides.is_some_and(|names| names.contains_key(name)) {
+                coverage.untyped.insert(format!("{}.{}", type_name, name));
+            }
+        }
+    }
+
+    // Types of the specification nothing was generated for, of services covered or not
+    for type_name in missing(spec, types) {
+        let meta = TypeMetadata::from(type_name.as_str());
+        if meta.module_path.len() < 3 {
+            continue;
+        }
+        let service_path = &meta.module_path[..meta.module_path.len() - 1];
+        services
+            .entry(service_path.join("::"))
+            .or_default()
+            .missing
+            .insert(type_name.clone());
+    }
+    services
+}
+
+/// Lines of the module documentation of `service`, listing its resources along with the
+/// specification version and what it's missing.
+fn coverage_docs(service: &str, coverage: &Coverage, spec_version: &str) -> Vec<String> {
+    let shorten = |names: &BTreeSet<String>| {
+        names
+            .iter()
+            .map(|name| format!("`{}`", name.trim_start_matches(&format!("{}::", service))))
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+    let mut lines = vec![
+        format!(
+            " Types of `{}`, generated from version {} of the CloudFormation resource specification.",
+            service, spec_version
+        ),
+        String::new(),
+        " | Resource | Properties | Property types | Source |".to_string(),
+        " | --- | ---: | ---: | --- |".to_string(),
+    ];
+    lines.extend(coverage.resources.iter().map(
+        |(resource, (properties, property_types, specified))| {
+            format!(
+                " | `{}` | {} | {} | {} |",
+                resource,
+                properties,
+                property_types,
+                match *specified {
+                    true => "specification",
+                    false => "crate",
+                }
+            )
+        },
+    ));
+    let added = coverage
+        .added
+        .iter()
+        .filter(|name| name.contains('.'))
+        .cloned()
+        .collect::<BTreeSet<String>>();
+    if !added.is_empty() {
+        lines.push(String::new());
+        lines.push(format!(
+            " Added by the crate on top of the specification: {}.",
+            shorten(&added)
+        ));
+    }
+    if !coverage.untyped.is_empty() {
+        lines.push(String::new());
+        lines.push(format!(
+            " Not covered, kept as untyped `serde_json::Value`: {}.",
+            shorten(&coverage.untyped)
+        ));
+    }
+    if !coverage.missing.is_empty() {
+        lines.push(String::new());
+        lines.push(format!(
+            " In the specification but not generated: {}.",
+            shorten(&coverage.missing)
+        ));
+    }
+    lines
+}
+
+/// Register the coverage of each service, see `registry::services`
+pub fn build_service_coverage(coverage: &BTreeMap<String, Coverage>) -> String {
+    let names = |names: &BTreeSet<String>| {
+        names
+            .iter()
+            .map(|name| format!("{:?}", name))
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+    let registrations = coverage
+        .iter()
+        .map(|(service, coverage)| {
+            let module_path = service.split("::").map(str::to_string).collect::<Vec<String>>();
+            format!(
+                "        {cfg}\n        crate::registry::ServiceCoverage {{ service: {service:?}, resources: {resources}, property_types: {property_types}, properties: {properties}, added: &[{added}], untyped: &[{untyped}], missing: &[{missing}] }},\n",
+                cfg = feature_cfg(&module_path),
+                service = service,
+                resources = coverage.resources.len(),
+                property_types = coverage.resources.values().map(|(_, count, _)| count).sum::<usize>(),
+                properties = coverage.resources.values().map(|(count, _, _)| count).sum::<usize>(),
+                added = names(&coverage.added),
+                untyped = names(&coverage.untyped),
+                missing = names(&coverage.missing)
+            )
+        })
+        .collect::<String>();
+    format!(
+        r#"
+/// Coverage of the enabled services by the generated types
+pub(crate) fn registered_services() -> Vec<crate::registry::ServiceCoverage> {{
+    // A slice rather than `vec![]`, whose elements can't be gated by `#[cfg]`
+    let services: &[crate::registry::ServiceCoverage] = &[
+{registrations}    ];
+    services.to_vec()
+}}
+"#,
+        registrations = registrations
+    )
+}
 
-pub fn build_types(types: &Types) -> Module {
+pub fn build_types(
+    types: &Types,
+    defaults: &Defaults,
+    overrides: &Overrides,
+    sensitive: &Sensitive,
+    coverage: &BTreeMap<String, Coverage>,
+    spec_version: &str,
+) -> Module {
     let mut parent_module = Module::new("types")
         .set_is_pub(true)
-        .add_attribute("#![allow(unused_imports, non_snake_case)]")
+        .add_attribute("#![allow(unused_imports, non_snake_case, clippy::too_many_arguments, clippy::needless_arbitrary_self_type)]")
         .to_owned();
 
     types.iter().for_each(|(type_name, the_type)| {
         let meta = TypeMetadata::from(type_name.as_str());
+        let type_defaults = defaults.get(type_name).cloned().unwrap_or_default();
+        let type_overrides = overrides.get(type_name).cloned().unwrap_or_default();
+        type_overrides.keys().for_each(|property_name| {
+            match the_type.properties.get(property_name) {
+                Some(property) if property.item_type.is_none() => (),
+                _ => panic!("Override given for non-primitive {}.{}", type_name, property_name),
+            }
+        });
 
+        // Types with sensitive properties implement `Debug` redacting them, see `build_debug_impls`
+        let derives = match sensitive.contains_key(type_name) {
+            true => "#[derive(Default, Clone, Builder, Serialize, Deserialize)]",
+            false => "#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]",
+        };
         let mut strct = Struct::new(&meta.struct_name)
             .set_is_pub(true)
-            .add_attribute("#[derive(Default, Clone, Builder, Debug, Serialize, Deserialize)]")
+            .add_attribute(derives)
             .add_attribute("#[builder(default, setter(into, strip_option))]")
             .add_doc(format!(
                 "/// Official documentation: [{}]({})",
@@ -136,76 +990,102 @@ pub fn build_types(types: &Types) -> Module {
             ))
             .to_owned();
 
-        // implement new(...) method
+        // implement new(...) method, taking the required properties in alphabetical order
         let mut new_method = Function::new("new")
             .set_is_pub(true)
             .set_return_ty("Self")
-            .add_doc(format!("/// Create a new `{}`", &meta.struct_name))
+            .add_doc(format!(
+                "/// Create a new `{}` of its required properties, leaving out the others",
+                &meta.struct_name
+            ))
             .to_owned();
 
         let mut new_method_body = "Self { ".to_string();
 
-        let inner_self = the_type
-            .properties
-            .iter()
+        let mut properties = the_type.properties.iter().collect::<Vec<_>>();
+        properties.sort_by_key(|(property_name, _)| *property_name);
+        let inner_self = properties
+            .into_iter()
             .map(|(property_name, property)| {
-                let mut type_ = match property.type_.as_ref().map(|v| v.as_str()) {
-                    Some("List") => format!(
-                        "Vec<{}>",
-                        property
-                            .item_type
-                            .as_ref()
-                            .map(|v| v.as_str())
-                            .unwrap_or_else(|| {
-                                property
-                                    .primitive_item_type
-                                    .as_ref()
-                                    .map(|v| v.as_rust_ty())
-                                    .unwrap_or("String")
-                            })
-                    ),
-                    Some("Map") => format!(
-                        "HashMap<String, {}>",
-                        property
-                            .item_type
-                            .as_ref()
-                            .map(|v| v.as_str())
-                            .unwrap_or_else(|| {
-                                property
-                                    .primitive_item_type
-                                    .as_ref()
-                                    .map(|v| v.as_rust_ty())
-                                    .unwrap_or("String")
-                            })
-                    ),
-                    Some(a) => a.to_string(),
-                    None => property.primitive_type.as_rust_ty().to_string(),
-                };
+                let mut type_ =
+                    property_ty(&meta, property, type_overrides.get(property_name));
 
                 // If this param is not required.
                 if !property.required {
                     type_ = format!("Option<{}>", type_);
                 }
 
-                strct.add_field(
-                    Field::new(property_name, &type_)
-                        .set_is_pub(true)
-                        .add_doc(format!(
-                            "/// Official documentation: [{}]({})",
-                            property.documentation, property.documentation
-                        ))
-                        .to_owned(),
-                );
-                new_method.add_parameter(Parameter::new(property_name, &type_));
-
-                property_name.as_str()
+                let mut field = Field::new(property_name, &type_)
+                    .set_is_pub(true)
+                    .add_doc(format!(
+                        "/// Official documentation: [{}]({})",
+                        property.documentation, property.documentation
+                    ))
+                    .to_owned();
+                if !property.required {
+                    field.add_attribute(r#"#[serde(skip_serializing_if = "Option::is_none")]"#);
+                }
+                if let Some(default) = type_defaults.get(property_name) {
+                    field
+                        .add_doc("///")
+                        .add_doc(format!("/// Defaults to `{}` when omitted.", default));
+                }
+
+                strct.add_field(field);
+                match property.required {
+                    true => {
+                        new_method.add_parameter(Parameter::new(property_name, &type_));
+                        property_name.to_string()
+                    }
+                    false => format!("{}: None", property_name),
+                }
             })
-            .collect::<Vec<&str>>();
+            .collect::<Vec<String>>();
+
+        // Properties missing from the specification are kept rather than dropped
+        strct.add_field(
+            Field::new("extra", "serde_json::Map<String, Value>")
+                .set_is_pub(true)
+                .add_doc("/// Properties not part of the resource specification, kept as given")
+                .add_attribute("#[serde(flatten)]")
+                .to_owned(),
+        );
 
-        new_method_body.push_str(&inner_self.join(", "));
-        new_method_body.push_str("}");
+        new_method_body.push_str(
+            &inner_self
+                .into_iter()
+                .chain(std::iter::once("extra: Default::default()".to_string()))
+                .collect::<Vec<String>>()
+                .join(", "),
+        );
+        new_method_body.push('}');
         new_method.set_body(new_method_body);
 
+        // implement default_for_field(...), defaults are embedded as JSON literals
+        type_defaults.keys().for_each(|property_name| {
+            if !the_type.properties.contains_key(property_name) {
+                panic!("Default given for unknown property {}.{}", type_name, property_name);
+            }
+        });
+        let mut default_entries = type_defaults
+            .iter()
+            .map(|(property_name, default)| {
+                format!("({:?}, {:?})", property_name, default.to_string())
+            })
+            .collect::<Vec<String>>();
+        default_entries.sort();
+        let default_for_field = Function::new("default_for_field")
+            .set_is_pub(true)
+            .set_return_ty("Option<Value>")
+            .add_parameter(Parameter::new("field", "&str"))
+            .add_doc("/// The value CloudFormation uses for `field` when it is omitted, if documented.")
+            .set_body(format!(
+                "let defaults: &[(&str, &str)] = &[{}];\n\
+                 defaults.iter().find(|(name, _)| *name == field).map(|(_, value)| serde_json::from_str(value).unwrap())",
+                default_entries.join(", ")
+            ))
+            .to_owned();
+
         // Get or create the nested submodule this struct should live.
         let module = meta.module_path.iter().enumerate().fold(
             &mut parent_module,
@@ -218,16 +1098,20 @@ pub fn build_types(types: &Types) -> Module {
                             .add_use_statement("use serde::{Serialize, Deserialize};")
                             .add_use_statement("use std::collections::HashMap;")
                             .add_use_statement("use derive_builder::Builder;")
+                            .add_use_statement("use crate::Expr;")
                             .set_is_pub(true)
                             .to_owned();
 
                         // One module path down is the major resource, ie AWS::EC2
                         // or AWS::Lambda, AWS::EMR
                         if mod_idx == 1 {
-                            m.add_attribute(format!(
-                                r#"#[cfg(any(feature = "all", feature = "{}"))]"#,
-                                &mod_name.to_lowercase()
-                            ));
+                            m.add_attribute(feature_cfg(&meta.module_path));
+                            let service = meta.module_path[..2].join("::");
+                            if let Some(coverage) = coverage.get(&service) {
+                                for line in coverage_docs(&service, coverage, spec_version) {
+                                    m.add_attribute(format!("#[doc = {:?}]", line));
+                                }
+                            }
                         }
                         // `Tag` struct is special
                         if &meta.struct_name != "Tag" {
@@ -241,35 +1125,826 @@ pub fn build_types(types: &Types) -> Module {
             },
         );
 
-        module.add_struct(strct).add_impl(
-            Impl::new(meta.struct_name)
-                .add_function(new_method)
-                .to_owned(),
-        );
+        let mut impl_ = Impl::new(&meta.struct_name)
+            .add_function(new_method)
+            .add_function(default_for_field)
+            .to_owned();
+        for accessor in accessors(&meta, the_type, &type_overrides) {
+            impl_.add_function(accessor);
+        }
+        module.add_struct(strct).add_impl(impl_);
     });
 
     parent_module
 }
 
+/// Implement `Resource` and `Display` for the generated resource types
+pub fn build_resource_impls(resource_types: &Types, identifiers: &Identifiers) -> String {
+    identifiers.keys().for_each(|type_name| {
+        if !resource_types.contains_key(type_name) {
+            panic!("Identifiers given for unknown resource type {}", type_name);
+        }
+    });
+    let mut type_names = resource_types.keys().collect::<Vec<&String>>();
+    type_names.sort();
+    type_names
+        .iter()
+        .map(|type_name| {
+            let meta = TypeMetadata::from(type_name.as_str());
+            let cfg = feature_cfg(&meta.module_path);
+            let path = format!(
+                "types::{}::{}",
+                meta.module_path.join("::"),
+                meta.struct_name
+            );
+            let identifiers = match identifiers.get(*type_name) {
+                Some(names) => format!(
+                    "\n    const IDENTIFIERS: &'static [&'static str] = &[{}];",
+                    names
+                        .iter()
+                        .map(|name| format!("\"{}\"", name))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+                None => String::new(),
+            };
+            format!(
+                r#"
+{cfg}
+impl crate::Resource for {path} {{
+    const TYPE: &'static str = "{type_name}";{identifiers}
+}}
+
+{cfg}
+impl std::fmt::Display for {path} {{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {{
+        crate::pretty::fmt_resource(self, f)
+    }}
+}}
+"#,
+                cfg = cfg,
+                path = path,
+                type_name = type_name,
+                identifiers = identifiers
+            )
+        })
+        .collect()
+}
+
+/// `PropertyKind` of a property, referring to the `PROPERTIES` of the type it contains
+fn property_kind(
+    types: &Types,
+    type_name: &str,
+    meta: &TypeMetadata,
+    property: &Property,
+) -> String {
+    let resource_name = type_name.split('.').next().unwrap();
+    let properties_of = |item: &str| {
+        let path = match types.contains_key(&format!("{}.{}", resource_name, item)) {
+            true => format!(
+                "{}::{}",
+                meta.module_path.join("::"),
+                meta.resolve_type_name(item)
+            ),
+            false => format!("{}::{}", item, item),
+        };
+        format!("|| types::{}::PROPERTIES", path)
+    };
+    let item = || match property.item_type.as_deref() {
+        Some(item) => format!("Some({})", properties_of(item)),
+        None => "None".to_string(),
+    };
+    match property.type_.as_deref() {
+        Some("List") => format!("crate::PropertyKind::List({})", item()),
+        Some("Map") => format!("crate::PropertyKind::Map({})", item()),
+        Some(a) => format!("crate::PropertyKind::Struct({})", properties_of(a)),
+        None => "crate::PropertyKind::Primitive".to_string(),
+    }
+}
+
+/// Implement `Debug` for the generated structs with sensitive properties, showing them as
+/// `<redacted>` unless formatted through `DebugUnredacted::debug_unredacted`
+pub fn build_debug_impls(types: &Types, sensitive: &Sensitive) -> String {
+    let mut type_names = sensitive.keys().collect::<Vec<&String>>();
+    type_names.sort();
+    type_names
+        .iter()
+        .map(|type_name| {
+            let meta = TypeMetadata::from(type_name.as_str());
+            let the_type = &types[*type_name];
+            let mut names = the_type.properties.keys().collect::<Vec<&String>>();
+            names.sort();
+            let fields = names
+                .into_iter()
+                .map(|name| {
+                    let value = match (
+                        sensitive[*type_name].contains(name),
+                        the_type.properties[name].required,
+                    ) {
+                        (true, true) => format!("crate::redact::sensitive(&self.{})", name),
+                        (true, false) => {
+                            format!("&crate::redact::sensitive_option(&self.{})", name)
+                        }
+                        (false, _) => format!("&self.{}", name),
+                    };
+                    format!("            .field(\"{}\", {})\n", name, value)
+                })
+                .collect::<String>();
+            format!(
+                r#"
+{cfg}
+impl std::fmt::Debug for types::{path}::{name} {{
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {{
+        f.debug_struct("{name}")
+{fields}            .field("extra", &self.extra)
+            .finish()
+    }}
+}}
+"#,
+                cfg = feature_cfg(&meta.module_path),
+                path = meta.module_path.join("::"),
+                name = meta.struct_name,
+                fields = fields
+            )
+        })
+        .collect()
+}
+
+/// Implement `Walk` for every generated struct, visiting fields under their CloudFormation
+/// names, and `visit_properties` to start a walk
+pub fn build_walk_impls(types: &Types) -> String {
+    let mut type_names = types.keys().collect::<Vec<&String>>();
+    type_names.sort();
+    type_names
+        .iter()
+        .map(|type_name| {
+            let meta = TypeMetadata::from(type_name.as_str());
+            let mut names = types[*type_name].properties.keys().collect::<Vec<&String>>();
+            names.sort();
+            let fields = names
+                .into_iter()
+                .map(|name| {
+                    format!(
+                        "        path.push(crate::Segment::Key(\"{name}\"));\n        crate::Walk::walk(&self.{name}, path, visitor);\n        path.pop();\n",
+                        name = name
+                    )
+                })
+                .collect::<String>();
+            format!(
+                r#"
+{cfg}
+impl crate::Walk for types::{path}::{name} {{
+    fn walk<'a>(&'a self, path: &mut crate::PropertyPath<'a>, visitor: &mut dyn crate::Visitor) {{
+{fields}        crate::Walk::walk(&self.extra, path, visitor);
+    }}
+}}
+
+{cfg}
+impl types::{path}::{name} {{
+    /// Visit every property value, with paths of CloudFormation property names
+    pub fn visit_properties(&self, visitor: &mut impl crate::Visitor) {{
+        crate::Walk::walk(self, &mut crate::PropertyPath::new(), visitor);
+    }}
+}}
+"#,
+                cfg = feature_cfg(&meta.module_path),
+                path = meta.module_path.join("::"),
+                name = meta.struct_name,
+                fields = fields
+            )
+        })
+        .collect()
+}
+
+/// `PROPERTIES` metadata of every generated struct, and the registry of resource types
+pub fn build_property_metadata(
+    types: &Types,
+    resource_names: &[String],
+    sensitive: &Sensitive,
+) -> String {
+    sensitive.iter().for_each(|(type_name, names)| {
+        for name in names {
+            match types.get(type_name) {
+                Some(the_type) if the_type.properties.contains_key(name) => (),
+                _ => panic!("Sensitive property {}.{} is unknown", type_name, name),
+            }
+        }
+    });
+    let mut type_names = types.keys().collect::<Vec<&String>>();
+    type_names.sort();
+    let mut src_code = type_names
+        .iter()
+        .map(|type_name| {
+            let meta = TypeMetadata::from(type_name.as_str());
+            let mut properties = types[*type_name]
+                .properties
+                .iter()
+                .collect::<Vec<(&String, &Property)>>();
+            properties.sort_by_key(|(name, _)| *name);
+            let properties = properties
+                .into_iter()
+                .map(|(name, property)| {
+                    let is_sensitive = sensitive
+                        .get(*type_name)
+                        .is_some_and(|names| names.contains(name));
+                    format!(
+                        "        crate::PropertyInfo {{ name: \"{}\", required: {}, update_type: crate::UpdateType::{}, kind: {}, sensitive: {} }},\n",
+                        name,
+                        property.required,
+                        property.update_type.as_str(),
+                        property_kind(types, type_name, &meta, property),
+                        is_sensitive
+                    )
+                })
+                .collect::<String>();
+            format!(
+                r#"
+{cfg}
+impl types::{path}::{name} {{
+    /// Name, `Required` and `UpdateType` of each property, as given by the resource specification,
+    /// and whether it holds a secret
+    pub const PROPERTIES: &'static [crate::PropertyInfo] = &[
+{properties}    ];
+}}
+"#,
+                cfg = feature_cfg(&meta.module_path),
+                path = meta.module_path.join("::"),
+                name = meta.struct_name,
+                properties = properties
+            )
+        })
+        .collect::<String>();
+
+    let registrations = resource_names
+        .iter()
+        .map(|type_name| {
+            let meta = TypeMetadata::from(type_name.as_str());
+            let path = format!(
+                "types::{}::{}",
+                meta.module_path.join("::"),
+                meta.struct_name
+            );
+            format!(
+                "        {cfg}\n        (\"{type_name}\", {path}::PROPERTIES, <{path} as crate::Resource>::IDENTIFIERS, crate::registry::typed::<{path}> as crate::registry::Typed),\n",
+                cfg = feature_cfg(&meta.module_path),
+                type_name = type_name,
+                path = path
+            )
+        })
+        .collect::<String>();
+    src_code.push_str(&format!(
+        r#"
+/// Resource types of the enabled services along with their properties, import identifiers and
+/// how to parse their properties into the generated type
+pub(crate) fn registered_resources() -> Vec<crate::registry::Registration> {{
+    // A slice rather than `vec![]`, whose elements can't be gated by `#[cfg]`
+    let resources: &[crate::registry::Registration] = &[
+{registrations}    ];
+    resources.to_vec()
+}}
+"#,
+        registrations = registrations
+    ));
+    src_code
+}
+
+/// `name` in snake case, ie. `db_instance_identifier` for `DBInstanceIdentifier`, keeping
+/// plurals of acronyms together, ie. `target_group_arns` for `TargetGroupARNs`
+fn snake_case(name: &str) -> String {
+    let chars = name.chars().collect::<Vec<char>>();
+    let at = |idx: usize| chars.get(idx).copied();
+    let mut snake = String::with_capacity(name.len() + 4);
+    for (idx, c) in chars.iter().enumerate() {
+        let prev = match idx.checked_sub(1).and_then(at) {
+            Some(prev) => prev,
+            None => {
+                snake.push(c.to_ascii_lowercase());
+                continue;
+            }
+        };
+        let (next, after_next) = (at(idx + 1), at(idx + 2));
+        let acronym_suffix = match next {
+            Some('s') => after_next.is_none_or(|c| c.is_ascii_uppercase()),
+            Some('v') => after_next.is_some_and(|c| c.is_ascii_digit()),
+            _ => false,
+        };
+        let boundary = match c {
+            'v' => prev.is_ascii_uppercase() && next.is_some_and(|c| c.is_ascii_digit()),
+            c if c.is_ascii_uppercase() => {
+                prev.is_ascii_lowercase()
+                    || prev.is_ascii_digit()
+                    || (prev.is_ascii_uppercase()
+                        && next.is_some_and(|c| c.is_ascii_lowercase())
+                        && !acronym_suffix)
+            }
+            _ => false,
+        };
+        if boundary {
+            snake.push('_');
+        }
+        snake.push(c.to_ascii_lowercase());
+    }
+    snake
+}
+
+/// `name` usable as an identifier, escaped when it's a keyword
+fn identifier(name: String) -> String {
+    match name.as_str() {
+        "abstract" | "as" | "async" | "await" | "become" | "box" | "break" | "const"
+        | "continue" | "do" | "dyn" | "else" | "enum" | "extern" | "false" | "final" | "fn"
+        | "for" | "if" | "impl" | "in" | "let" | "loop" | "macro" | "match" | "mod" | "move"
+        | "mut" | "override" | "priv" | "pub" | "ref" | "return" | "static" | "struct"
+        | "trait" | "true" | "try" | "type" | "typeof" | "unsafe" | "unsized" | "use"
+        | "virtual" | "where" | "while" | "yield" => format!("r#{}", name),
+        "crate" | "self" | "super" => format!("{}_", name),
+        _ => name,
+    }
+}
+
+/// Per-service modules re-exporting the generated types under shorter names, which
+/// `crate::prelude` makes available, ie. `ec2::LaunchTemplate` and
+/// `ec2::launch_template::Data` for `types::AWS::EC2::LaunchTemplate::LaunchTemplateData`.
+///
+/// Names only depend on the names of the types themselves, so they stay the same as the
+/// specification gains services, resources and properties.
+pub fn build_preludes(types: &Types, resource_names: &[String]) -> String {
+    // `#[cfg]`, resource re-exports and resource modules of each service
+    let mut services: BTreeMap<String, (String, String, String)> = BTreeMap::new();
+    for type_name in resource_names {
+        let meta = TypeMetadata::from(type_name.as_str());
+        let service_path = &meta.module_path[..meta.module_path.len() - 1];
+        // AWS services are named like their Cargo feature, third-party ones after the namespace
+        let service = match service_path {
+            [namespace, service] if namespace == "AWS" => service.to_lowercase(),
+            _ => service_path.join("_").to_lowercase(),
+        };
+        let path = format!("crate::types::{}", meta.module_path.join("::"));
+
+        let mut property_names = types
+            .keys()
+            .filter(|name| name.starts_with(&format!("{}.", type_name)))
+            .map(|name| TypeMetadata::from(name.as_str()).struct_name)
+            .collect::<Vec<String>>();
+        property_names.sort();
+        // Property types prefixed by the name of their resource, ie. `LaunchTemplateData`,
+        // are also available without the prefix, unless that's taken by another type
+        let aliases = property_names
+            .iter()
+            .filter_map(|name| {
+                let alias = name.strip_prefix(&meta.struct_name)?;
+                match alias.starts_with(|c: char| c.is_ascii_uppercase())
+                    && !property_names.iter().any(|name| name == alias)
+                {
+                    true => Some(format!(
+                        "    pub use {path}::{{{name} as {alias}, {name}Builder as {alias}Builder}};\n",
+                        path = path,
+                        name = name,
+                        alias = alias
+                    )),
+                    false => None,
+                }
+            })
+            .collect::<String>();
+
+        let (_, resources, modules) = services
+            .entry(service)
+            .or_insert_with(|| (feature_cfg(&meta.module_path), String::new(), String::new()));
+        resources.push_str(&format!(
+            "pub use {path}::{{{name}, {name}Builder}};\n",
+            path = path,
+            name = meta.struct_name
+        ));
+        modules.push_str(&format!(
+            "/// Property types of `{type_name}`\npub mod {module} {{\n    pub use {path}::*;\n{aliases}}}\n",
+            type_name = type_name,
+            module = identifier(snake_case(&meta.struct_name)),
+            path = path,
+            aliases = aliases
+        ));
+    }
+
+    let services = services
+        .into_iter()
+        .map(|(service, (cfg, resources, modules))| {
+            format!(
+                "{cfg}\npub mod {service} {{\n{resources}{modules}}}\n",
+                cfg = cfg,
+                service = service,
+                resources = resources,
+                modules = modules
+            )
+        })
+        .collect::<String>();
+    format!(
+        "\n/// Resources of each service, see `crate::prelude`\npub(crate) mod preludes {{\n{}}}\n",
+        services
+    )
+}
+
+/// Regions of each partition, `definitions/Endpoints.json`
+#[derive(Deserialize)]
+pub struct Endpoints {
+    partitions: Vec<EndpointPartition>,
+}
+
+/// A partition of `definitions/Endpoints.json`, in the format of the AWS SDKs' endpoint
+/// metadata
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EndpointPartition {
+    partition: String,
+    partition_name: String,
+    dns_suffix: String,
+    regions: BTreeMap<String, EndpointRegion>,
+}
+
+#[derive(Deserialize)]
+pub struct EndpointRegion {
+    description: String,
+}
+
+/// Name of the enum variant of a region or partition code, ie. `UsGovWest1` for `us-gov-west-1`
+fn variant_name(code: &str) -> String {
+    code.split('-')
+        .map(|part| {
+            let mut chars = part.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase().to_string() + chars.as_str())
+                .unwrap_or_default()
+        })
+        .collect()
+}
+
+/// `Region` and `AwsPartition` enums of the regions and partitions of the endpoint metadata,
+/// parsing and formatting them is implemented in `src/region.rs`
+pub fn build_regions(partitions: &[EndpointPartition]) -> String {
+    let mut partition_variants = String::new();
+    let mut region_variants = String::new();
+    let (mut ids, mut names, mut suffixes) = (String::new(), String::new(), String::new());
+    let (mut codes, mut descriptions, mut partition_of) =
+        (String::new(), String::new(), String::new());
+    let (mut all_partitions, mut all_regions) = (vec![], vec![]);
+    for partition in partitions {
+        let variant = variant_name(&partition.partition);
+        partition_variants.push_str(&format!(
+            "    /// {}\n    #[serde(rename = {:?})]\n    {},\n",
+            partition.partition_name, partition.partition, variant
+        ));
+        ids.push_str(&format!(
+            "            AwsPartition::{} => {:?},\n",
+            variant, partition.partition
+        ));
+        names.push_str(&format!(
+            "            AwsPartition::{} => {:?},\n",
+            variant, partition.partition_name
+        ));
+        suffixes.push_str(&format!(
+            "            AwsPartition::{} => {:?},\n",
+            variant, partition.dns_suffix
+        ));
+        all_partitions.push(format!("AwsPartition::{}", variant));
+
+        for (code, region) in partition.regions.iter() {
+            let region_variant = variant_name(code);
+            region_variants.push_str(&format!(
+                "    /// {}\n    #[serde(rename = {:?})]\n    {},\n",
+                region.description, code, region_variant
+            ));
+            codes.push_str(&format!(
+                "            Region::{} => {:?},\n",
+                region_variant, code
+            ));
+            descriptions.push_str(&format!(
+                "            Region::{} => {:?},\n",
+                region_variant, region.description
+            ));
+            partition_of.push_str(&format!(
+                "            Region::{} => AwsPartition::{},\n",
+                region_variant, variant
+            ));
+            all_regions.push(format!("Region::{}", region_variant));
+        }
+    }
+
+    format!(
+        r#"
+/// A partition of AWS, a group of regions isolated from those of other partitions, as
+/// named in ARNs, ie. `aws-cn`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub enum AwsPartition {{
+{partition_variants}}}
+
+impl AwsPartition {{
+    /// Every partition known to the crate
+    pub const ALL: &'static [AwsPartition] = &[{all_partitions}];
+
+    /// Identifier of the partition within ARNs, ie. `aws-us-gov`
+    pub fn id(&self) -> &'static str {{
+        match self {{
+{ids}        }}
+    }}
+
+    /// Name of the partition, ie. `AWS GovCloud (US)`
+    pub fn name(&self) -> &'static str {{
+        match self {{
+{names}        }}
+    }}
+
+    /// Domain of the service endpoints within the partition, ie. `amazonaws.com.cn`
+    pub fn dns_suffix(&self) -> &'static str {{
+        match self {{
+{suffixes}        }}
+    }}
+
+    /// Regions of the partition
+    pub fn regions(&self) -> impl Iterator<Item = Region> + '_ {{
+        Region::ALL
+            .iter()
+            .copied()
+            .filter(move |region| region.partition() == *self)
+    }}
+}}
+
+/// An AWS region, serialized as its code, ie. `eu-west-1`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, serde::Serialize, serde::Deserialize)]
+pub enum Region {{
+{region_variants}}}
+
+impl Region {{
+    /// Every region known to the crate, by partition
+    pub const ALL: &'static [Region] = &[{all_regions}];
+
+    /// Code of the region, ie. `eu-west-1`
+    pub fn code(&self) -> &'static str {{
+        match self {{
+{codes}        }}
+    }}
+
+    /// Name of the region, ie. `Europe (Ireland)`
+    pub fn description(&self) -> &'static str {{
+        match self {{
+{descriptions}        }}
+    }}
+
+    /// Partition the region belongs to, ie. `AwsPartition::AwsCn` for `cn-north-1`
+    pub fn partition(&self) -> AwsPartition {{
+        match self {{
+{partition_of}        }}
+    }}
+}}
+"#,
+        partition_variants = partition_variants,
+        all_partitions = all_partitions.join(", "),
+        ids = ids,
+        names = names,
+        suffixes = suffixes,
+        region_variants = region_variants,
+        all_regions = all_regions.join(", "),
+        codes = codes,
+        descriptions = descriptions,
+        partition_of = partition_of
+    )
+}
+
+/// Attributes of EC2 instance types, `definitions/InstanceTypes.json`
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct InstanceTypes {
+    instance_types: Vec<InstanceTypeSpec>,
+}
+
+/// An instance type of `definitions/InstanceTypes.json`, in the format of the output of
+/// `aws ec2 describe-instance-types`
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct InstanceTypeSpec {
+    instance_type: String,
+    v_cpu_info: VCpuInfo,
+    memory_info: MemoryInfo,
+    ebs_info: EbsInfo,
+    processor_info: ProcessorInfo,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct VCpuInfo {
+    default_v_cpus: u32,
+    default_cores: u32,
+    default_threads_per_core: u32,
+    #[serde(default)]
+    valid_cores: Vec<u32>,
+    #[serde(default)]
+    valid_threads_per_core: Vec<u32>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MemoryInfo {
+    size_in_mi_b: u32,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct EbsInfo {
+    ebs_optimized_support: String,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ProcessorInfo {
+    supported_architectures: Vec<String>,
+}
+
+/// Table of the instance types, ordered by name so they can be looked up by binary search,
+/// see `src/instance_type.rs`
+pub fn build_instance_types(instance_types: &[InstanceTypeSpec]) -> String {
+    let mut instance_types = instance_types.iter().collect::<Vec<&InstanceTypeSpec>>();
+    instance_types.sort_by(|a, b| a.instance_type.cmp(&b.instance_type));
+    let list = |values: &[u32]| {
+        values
+            .iter()
+            .map(u32::to_string)
+            .collect::<Vec<String>>()
+            .join(", ")
+    };
+    let entries = instance_types
+        .iter()
+        .map(|spec| {
+            let ebs_optimized = match spec.ebs_info.ebs_optimized_support.as_str() {
+                "unsupported" => "Unsupported",
+                "supported" => "Supported",
+                "default" => "Default",
+                other => panic!("Unknown EBS optimization support {} of {}", other, spec.instance_type),
+            };
+            let architectures = spec
+                .processor_info
+                .supported_architectures
+                .iter()
+                .map(|architecture| match architecture.as_str() {
+                    "x86_64" => "crate::Architecture::X86_64",
+                    "arm64" => "crate::Architecture::Arm64",
+                    other => panic!("Unknown architecture {} of {}", other, spec.instance_type),
+                })
+                .collect::<Vec<&str>>()
+                .join(", ");
+            format!(
+                "    crate::InstanceTypeInfo {{ name: {:?}, vcpus: {}, default_cores: {}, default_threads_per_core: {}, valid_cores: &[{}], valid_threads_per_core: &[{}], memory_mib: {}, ebs_optimized: crate::EbsOptimizedSupport::{}, architectures: &[{}] }},\n",
+                spec.instance_type,
+                spec.v_cpu_info.default_v_cpus,
+                spec.v_cpu_info.default_cores,
+                spec.v_cpu_info.default_threads_per_core,
+                list(&spec.v_cpu_info.valid_cores),
+                list(&spec.v_cpu_info.valid_threads_per_core),
+                spec.memory_info.size_in_mi_b,
+                ebs_optimized,
+                architectures
+            )
+        })
+        .collect::<String>();
+    format!(
+        "\n/// Attributes of the EC2 instance types known to the crate, ordered by name\npub(crate) const INSTANCE_TYPES: &[crate::InstanceTypeInfo] = &[\n{}];\n",
+        entries
+    )
+}
+
 fn main() {
-    let spec_path = concat!(
-        env!("CARGO_MANIFEST_DIR"),
-        "/definitions",
-        "/CloudFormationResourceSpecification.json"
-    );
-    let spec_bytes = fs::read(spec_path).unwrap();
-    let spec: Value = serde_json::from_slice(&spec_bytes).unwrap();
+    let definitions = concat!(env!("CARGO_MANIFEST_DIR"), "/definitions");
+    println!("cargo:rerun-if-changed=build.rs");
+    println!("cargo:rerun-if-changed={}", definitions);
 
-    let property_types: Types = serde_json::from_value(spec["PropertyTypes"].clone()).unwrap();
-    let resource_types: Types = serde_json::from_value(spec["ResourceTypes"].clone()).unwrap();
+    println!("cargo:rerun-if-env-changed=CAROLINE_SPEC_DIFF");
+    println!("cargo:rerun-if-env-changed=CAROLINE_SPEC_DIFF_REPORT");
+    println!("cargo:rerun-if-env-changed=CAROLINE_JSON_SCHEMAS");
 
+    println!("cargo:rerun-if-env-changed=CAROLINE_REGISTRY_SCHEMAS");
+
+    let (mut property_types, mut resource_types, spec_version) =
+        read_definitions(Path::new(definitions));
+
+    // Registry schemas of private types may also be kept outside of the crate
+    if let Ok(schemas) = env::var("CAROLINE_REGISTRY_SCHEMAS") {
+        println!("cargo:rerun-if-changed={}", schemas);
+        read_registry_schemas(
+            Path::new(&schemas),
+            &mut property_types,
+            &mut resource_types,
+        );
+    }
+
+    let mut identifiers: Identifiers = serde_json::from_slice(
+        &fs::read(Path::new(definitions).join("ResourceIdentifiers.json")).unwrap(),
+    )
+    .unwrap();
+    read_registry_identifiers(&Path::new(definitions).join("registry"), &mut identifiers);
+    if let Ok(schemas) = env::var("CAROLINE_REGISTRY_SCHEMAS") {
+        read_registry_identifiers(Path::new(&schemas), &mut identifiers);
+    }
+
+    let resource_impls = build_resource_impls(&resource_types, &identifiers);
+    let mut resource_names = resource_types.keys().cloned().collect::<Vec<String>>();
+    resource_names.sort();
     let types: Types = property_types.into_iter().chain(resource_types).collect();
+    let sensitive: Sensitive = serde_json::from_slice(
+        &fs::read(Path::new(definitions).join("SensitiveProperties.json")).unwrap(),
+    )
+    .unwrap();
+    let property_metadata = build_property_metadata(&types, &resource_names, &sensitive);
+    let walk_impls = build_walk_impls(&types);
+    let debug_impls = build_debug_impls(&types, &sensitive);
+    let preludes = build_preludes(&types, &resource_names);
+    // `crate::prelude` re-exports the service modules of `preludes`, of which there may be none
+    println!("cargo:rustc-check-cfg=cfg(caroline_services)");
+    if services_enabled(&resource_names) {
+        println!("cargo:rustc-cfg=caroline_services");
+    }
 
-    let module = build_types(&types);
+    // Diff against the definitions of a previous release, either its `definitions` directory
+    // or a bare specification file, to document what the generated API gained and lost.
+    if let Ok(previous) = env::var("CAROLINE_SPEC_DIFF") {
+        let previous = Path::new(&previous);
+        let (old_property_types, old_resource_types, old_version) = match previous.is_dir() {
+            true => read_definitions(previous),
+            false => {
+                let (property_types, resource_types) = read_spec(previous);
+                (property_types, resource_types, read_spec_version(previous))
+            }
+        };
+        let old_types: Types = old_property_types
+            .into_iter()
+            .chain(old_resource_types)
+            .collect();
+        let report = diff_specs(&old_types, &old_version, &types, &spec_version);
+        let report_path = env::var("CAROLINE_SPEC_DIFF_REPORT")
+            .unwrap_or_else(|_| concat!(env!("CARGO_MANIFEST_DIR"), "/spec-diff.json").to_string());
+        fs::write(report_path, serde_json::to_string_pretty(&report).unwrap()).unwrap();
+    }
+
+    // Schemas of the generated types, for tooling outside of Rust checking input before it's
+    // handed to the crate
+    if let Ok(dir) = env::var("CAROLINE_JSON_SCHEMAS") {
+        write_json_schemas(Path::new(&dir), &resource_names, &types);
+    }
+
+    let defaults: Defaults = serde_json::from_slice(
+        &fs::read(Path::new(definitions).join("PropertyDefaults.json")).unwrap(),
+    )
+    .unwrap();
+
+    let overrides: Overrides = serde_json::from_slice(
+        &fs::read(Path::new(definitions).join("PropertyOverrides.json")).unwrap(),
+    )
+    .unwrap();
+
+    // Coverage is measured against the bundled specification alone, so whatever extensions
+    // and registry schemas add on top of it shows up as such
+    let (spec_property_types, spec_resource_types) =
+        read_spec(&Path::new(definitions).join("CloudFormationResourceSpecification.json"));
+    let spec: Types = spec_property_types
+        .into_iter()
+        .chain(spec_resource_types)
+        .collect();
+    let coverage = build_coverage(&types, &spec, &overrides);
+    let service_coverage = build_service_coverage(&coverage);
+
+    let endpoints: Endpoints =
+        serde_json::from_slice(&fs::read(Path::new(definitions).join("Endpoints.json")).unwrap())
+            .unwrap();
+    let regions = build_regions(&endpoints.partitions);
+    let instance_types: InstanceTypes = serde_json::from_slice(
+        &fs::read(Path::new(definitions).join("InstanceTypes.json")).unwrap(),
+    )
+    .unwrap();
+    let instance_types = build_instance_types(&instance_types.instance_types);
+
+    let module = build_types(
+        &types,
+        &defaults,
+        &overrides,
+        &sensitive,
+        &coverage,
+        &spec_version,
+    );
 
-    let src_code = module.generate();
+    let mut src_code = module.generate();
+    src_code.push_str(&resource_impls);
+    src_code.push_str(&property_metadata);
+    src_code.push_str(&walk_impls);
+    src_code.push_str(&debug_impls);
+    src_code.push_str(&preludes);
+    src_code.push_str(&service_coverage);
+    src_code.push_str(&regions);
+    src_code.push_str(&instance_types);
+    src_code.push_str(&format!(
+        r#"
+/// Version of the CloudFormation resource specification the types were generated from,
+/// services and properties it doesn't cover yet are added by the crate on top of it
+pub const SPEC_VERSION: &str = "{}";
+"#,
+        spec_version
+    ));
 
-    let _ = fs::write(
+    fs::write(
         concat!(env!("CARGO_MANIFEST_DIR"), "/src", "/aws.rs"),
         src_code,
     )
@@ -0,0 +1,54 @@
+/* C interface of caroline, built with the `ffi` feature, ie.
+ *
+ *   cargo rustc --release --features ffi --crate-type cdylib
+ *
+ * Functions return CAROLINE_OK or another status code, the message of the last failure of
+ * the calling thread is available from caroline_last_error. Strings are UTF-8 buffers of a
+ * pointer and a length, without a trailing NUL. Templates and buffers handed out are owned
+ * by the caller, who frees them with caroline_template_free and caroline_buffer_free. Panics
+ * are caught rather than aborting the process, returning CAROLINE_PANIC.
+ */
+#ifndef CAROLINE_H
+#define CAROLINE_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+#define CAROLINE_OK 0
+#define CAROLINE_NULL_POINTER 1
+#define CAROLINE_INVALID_UTF8 2
+#define CAROLINE_PARSE_ERROR 3
+#define CAROLINE_INVALID_TEMPLATE 4
+#define CAROLINE_PANIC 5
+
+typedef struct CarolineTemplate CarolineTemplate;
+
+typedef struct CarolineBuffer {
+    uint8_t *data;
+    size_t len;
+} CarolineBuffer;
+
+int32_t caroline_template_from_json(const uint8_t *data, size_t len, CarolineTemplate **out);
+int32_t caroline_template_from_yaml(const uint8_t *data, size_t len, CarolineTemplate **out);
+int32_t caroline_template_to_json(const CarolineTemplate *template_, CarolineBuffer *out);
+int32_t caroline_template_to_yaml(const CarolineTemplate *template_, CarolineBuffer *out);
+
+/* Writes the issues as a JSON array of {"LogicalId": ..., "Message": ...} objects, returns
+ * CAROLINE_INVALID_TEMPLATE if there are any. *out is filled, and has to be freed with
+ * caroline_buffer_free, on CAROLINE_INVALID_TEMPLATE as well as on CAROLINE_OK. */
+int32_t caroline_template_validate(const CarolineTemplate *template_, CarolineBuffer *out);
+
+CarolineBuffer caroline_last_error(void);
+
+void caroline_template_free(CarolineTemplate *template_);
+void caroline_buffer_free(CarolineBuffer buffer);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif
@@ -0,0 +1,183 @@
+use crate::{Result, Template};
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Cloud assembly schema version the manifests are written in
+const SCHEMA_VERSION: &str = "21.0.0";
+
+/// Qualifier of the default CDK bootstrap stack, `cdk bootstrap` without `--qualifier`
+const BOOTSTRAP_QUALIFIER: &str = "hnb659fds";
+
+/// How an asset is uploaded, either as is or zipped up, ie. a directory of Lambda code
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Packaging {
+    File,
+    Zip,
+}
+
+/// A local file or directory to be uploaded to the bootstrap bucket on deploy
+#[derive(Clone, Debug, PartialEq)]
+pub struct AssemblyAsset {
+    /// Hash of the asset's content, also used as its object key
+    pub source_hash: String,
+    /// Location of the asset, relative paths are resolved from the assembly directory
+    pub path: PathBuf,
+    pub packaging: Packaging,
+}
+
+impl AssemblyAsset {
+    /// Key the asset is uploaded under in the bootstrap bucket
+    pub fn object_key(&self) -> String {
+        match self.packaging {
+            Packaging::File => self.source_hash.clone(),
+            Packaging::Zip => format!("{}.zip", self.source_hash),
+        }
+    }
+}
+
+/// A minimal CDK cloud assembly, ie. the `cdk.out` directory, holding a single stack and the
+/// assets it uses, so it can be deployed with `cdk deploy --app <dir>`.
+///
+/// Assets are published to the bucket created by `cdk bootstrap`, which has to have been run
+/// in the target account and region.
+#[derive(Clone, Debug)]
+pub struct CloudAssembly {
+    stack_name: String,
+    template: Template,
+    account: Option<String>,
+    region: Option<String>,
+    assets: Vec<AssemblyAsset>,
+}
+
+impl CloudAssembly {
+    pub fn new(stack_name: impl Into<String>, template: Template) -> Self {
+        Self {
+            stack_name: stack_name.into(),
+            template,
+            account: None,
+            region: None,
+            assets: vec![],
+        }
+    }
+
+    /// Deploy to this account and region, rather than those of the deploying credentials
+    pub fn environment(mut self, account: impl Into<String>, region: impl Into<String>) -> Self {
+        self.account = Some(account.into());
+        self.region = Some(region.into());
+        self
+    }
+
+    pub fn add_asset(&mut self, asset: AssemblyAsset) -> &mut Self {
+        if !self.assets.contains(&asset) {
+            self.assets.push(asset);
+        }
+        self
+    }
+
+    pub fn assets(&self) -> &[AssemblyAsset] {
+        &self.assets
+    }
+
+    /// Name of the bootstrap bucket assets are published to
+    pub fn bucket_name(&self) -> String {
+        format!(
+            "cdk-{}-assets-{}-{}",
+            BOOTSTRAP_QUALIFIER,
+            self.account.as_deref().unwrap_or("${AWS::AccountId}"),
+            self.region.as_deref().unwrap_or("${AWS::Region}")
+        )
+    }
+
+    fn template_file(&self) -> String {
+        format!("{}.template.json", self.stack_name)
+    }
+
+    fn assets_file(&self) -> String {
+        format!("{}.assets.json", self.stack_name)
+    }
+
+    /// The assembly's `manifest.json`
+    pub fn manifest(&self) -> Value {
+        let environment = format!(
+            "aws://{}/{}",
+            self.account.as_deref().unwrap_or("unknown-account"),
+            self.region.as_deref().unwrap_or("unknown-region")
+        );
+        let assets_artifact = format!("{}.assets", self.stack_name);
+        json!({
+            "version": SCHEMA_VERSION,
+            "artifacts": {
+                &assets_artifact: {
+                    "type": "cdk:asset-manifest",
+                    "properties": {
+                        "file": self.assets_file(),
+                        "requiresBootstrapStackVersion": 6,
+                        "bootstrapStackVersionSsmParameter":
+                            format!("/cdk-bootstrap/{}/version", BOOTSTRAP_QUALIFIER),
+                    }
+                },
+                &self.stack_name: {
+                    "type": "aws:cloudformation:stack",
+                    "environment": environment,
+                    "properties": {
+                        "templateFile": self.template_file(),
+                        "stackName": self.stack_name,
+                    },
+                    "dependencies": [assets_artifact],
+                }
+            }
+        })
+    }
+
+    /// The asset manifest, listing where each asset is published to
+    pub fn asset_manifest(&self) -> Value {
+        let destination = match (&self.account, &self.region) {
+            (Some(account), Some(region)) => format!("{}-{}", account, region),
+            _ => "current_account-current_region".to_string(),
+        };
+        let files = self
+            .assets
+            .iter()
+            .map(|asset| {
+                let entry = json!({
+                    "source": {
+                        "path": asset.path,
+                        "packaging": asset.packaging,
+                    },
+                    "destinations": {
+                        &destination: {
+                            "bucketName": self.bucket_name(),
+                            "objectKey": asset.object_key(),
+                        }
+                    }
+                });
+                (asset.source_hash.clone(), entry)
+            })
+            .collect::<serde_json::Map<String, Value>>();
+        json!({
+            "version": SCHEMA_VERSION,
+            "files": files,
+        })
+    }
+
+    /// Write the assembly into `dir`, creating it if needed
+    pub fn write(&self, dir: impl AsRef<Path>) -> Result<()> {
+        let dir = dir.as_ref();
+        fs::create_dir_all(dir)?;
+        let version = json!({ "version": SCHEMA_VERSION });
+        fs::write(dir.join("cdk.out"), serde_json::to_string_pretty(&version)?)?;
+        fs::write(
+            dir.join("manifest.json"),
+            serde_json::to_string_pretty(&self.manifest())?,
+        )?;
+        fs::write(dir.join(self.template_file()), self.template.to_json()?)?;
+        fs::write(
+            dir.join(self.assets_file()),
+            serde_json::to_string_pretty(&self.asset_manifest())?,
+        )?;
+        Ok(())
+    }
+}
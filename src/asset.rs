@@ -0,0 +1,198 @@
+use crate::{AssemblyAsset, CloudAssembly, Intrinsic, Packaging, Result, Template};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Prefix of the placeholders assets put in place of their S3 coordinates
+const PLACEHOLDER_PREFIX: &str = "caroline-asset://";
+
+/// A local file or directory to be uploaded to S3, ie. Lambda code or an Elastic Beanstalk
+/// source bundle.
+///
+/// Assets are keyed by a hash of their content, so unchanged assets keep their key across
+/// deployments. Use `Asset::bucket` and `Asset::key` as the S3 bucket and key properties of
+/// a resource, and an `AssetResolver` to substitute the final coordinates once known.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Asset {
+    path: PathBuf,
+    packaging: Packaging,
+    hash: String,
+}
+
+impl Asset {
+    /// A single file, uploaded as is
+    pub fn file(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let hash = hex(&Sha256::digest(fs::read(&path)?));
+        Ok(Self {
+            path,
+            packaging: Packaging::File,
+            hash,
+        })
+    }
+
+    /// A directory, zipped up on upload. The hash covers the relative path and content of
+    /// every file within it.
+    pub fn directory(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let mut files = vec![];
+        collect_files(&path, &mut files)?;
+        files.sort();
+
+        let mut hasher = Sha256::new();
+        for file in files.iter() {
+            let relative = file.strip_prefix(&path).unwrap_or(file);
+            hasher.update(relative.to_string_lossy().replace('\\', "/").as_bytes());
+            hasher.update([0]);
+            hasher.update(fs::read(file)?);
+        }
+        Ok(Self {
+            path,
+            packaging: Packaging::Zip,
+            hash: hex(&hasher.finalize()),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn packaging(&self) -> Packaging {
+        self.packaging
+    }
+
+    /// Hex encoded SHA-256 of the asset's content
+    pub fn hash(&self) -> &str {
+        &self.hash
+    }
+
+    /// Key of the asset within the bucket, excluding any prefix given to the resolver
+    pub fn object_key(&self) -> String {
+        AssemblyAsset::from(self).object_key()
+    }
+
+    /// Placeholder for the bucket the asset is uploaded to
+    pub fn bucket(&self) -> String {
+        format!("{}{}/bucket", PLACEHOLDER_PREFIX, self.object_key())
+    }
+
+    /// Placeholder for the key the asset is uploaded under
+    pub fn key(&self) -> String {
+        format!("{}{}/key", PLACEHOLDER_PREFIX, self.object_key())
+    }
+}
+
+impl From<&Asset> for AssemblyAsset {
+    fn from(asset: &Asset) -> Self {
+        AssemblyAsset {
+            source_hash: asset.hash.clone(),
+            path: asset.path.clone(),
+            packaging: asset.packaging,
+        }
+    }
+}
+
+impl CloudAssembly {
+    /// Publish `asset` along with the stack, see `AssetResolver::for_assembly`
+    pub fn add(&mut self, asset: &Asset) -> &mut Self {
+        self.add_asset(asset.into())
+    }
+}
+
+/// Substitutes the final S3 coordinates for asset placeholders within a template
+#[derive(Clone, Debug)]
+pub struct AssetResolver {
+    bucket: String,
+    prefix: String,
+}
+
+impl AssetResolver {
+    /// Resolve assets to objects of `bucket`, which may reference pseudo parameters,
+    /// ie. `assets-${AWS::AccountId}`
+    pub fn new(bucket: impl Into<String>) -> Self {
+        Self {
+            bucket: bucket.into(),
+            prefix: String::new(),
+        }
+    }
+
+    /// Resolve assets to the bootstrap bucket `assembly` publishes its assets to
+    pub fn for_assembly(assembly: &CloudAssembly) -> Self {
+        Self::new(assembly.bucket_name())
+    }
+
+    /// Prefix object keys with `prefix`, ie. `assets/`
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Replace placeholders used as property values of `template`, returning the object keys
+    /// of the assets which were referenced
+    pub fn resolve(&self, template: &mut Template) -> BTreeSet<String> {
+        let mut resolved = BTreeSet::new();
+        let logical_ids = template
+            .resources()
+            .filter(|(_, entry)| contains_placeholder(&entry.properties))
+            .map(|(id, _)| id.to_string())
+            .collect::<Vec<String>>();
+        for logical_id in logical_ids {
+            if let Some(entry) = template.get_mut(&logical_id) {
+                self.resolve_value(&mut entry.properties, &mut resolved);
+            }
+        }
+        resolved
+    }
+
+    fn resolve_value(&self, value: &mut Value, resolved: &mut BTreeSet<String>) {
+        match value {
+            Value::String(s) if s.starts_with(PLACEHOLDER_PREFIX) => {
+                let placeholder = &s[PLACEHOLDER_PREFIX.len()..];
+                if let Some(object_key) = placeholder.strip_suffix("/bucket") {
+                    resolved.insert(object_key.to_string());
+                    *value = match self.bucket.contains("${") {
+                        true => Intrinsic::sub(self.bucket.clone()).into(),
+                        false => Value::String(self.bucket.clone()),
+                    };
+                } else if let Some(object_key) = placeholder.strip_suffix("/key") {
+                    resolved.insert(object_key.to_string());
+                    *value = Value::String(format!("{}{}", self.prefix, object_key));
+                }
+            }
+            Value::Object(map) => map
+                .values_mut()
+                .for_each(|value| self.resolve_value(value, resolved)),
+            Value::Array(items) => items
+                .iter_mut()
+                .for_each(|item| self.resolve_value(item, resolved)),
+            _ => (),
+        }
+    }
+}
+
+fn contains_placeholder(value: &Value) -> bool {
+    match value {
+        Value::String(s) => s.starts_with(PLACEHOLDER_PREFIX),
+        Value::Object(map) => map.values().any(contains_placeholder),
+        Value::Array(items) => items.iter().any(contains_placeholder),
+        _ => false,
+    }
+}
+
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
@@ -0,0 +1,169 @@
+use crate::{registry, ResourceEntry, Template, UpdateType};
+use std::collections::BTreeSet;
+
+/// Whether a change will replace the physical resource, mirroring the `Replacement` field of
+/// CloudFormation change sets
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Replacement {
+    False,
+    Conditional,
+    True,
+}
+
+impl From<UpdateType> for Replacement {
+    fn from(update_type: UpdateType) -> Self {
+        match update_type {
+            UpdateType::Mutable => Replacement::False,
+            UpdateType::Conditional => Replacement::Conditional,
+            UpdateType::Immutable => Replacement::True,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Action {
+    Add,
+    Modify,
+    Remove,
+}
+
+/// A property whose value differs between the two templates
+#[derive(Clone, Debug, PartialEq)]
+pub struct PropertyChange {
+    pub name: String,
+    /// `None` if the property isn't part of the resource specification
+    pub update_type: Option<UpdateType>,
+}
+
+/// The predicted effect of deploying a template on one of its resources
+#[derive(Clone, Debug, PartialEq)]
+pub struct ResourceChange {
+    pub logical_id: String,
+    pub resource_type: String,
+    pub action: Action,
+    pub replacement: Replacement,
+    /// Changed properties, for modified resources
+    pub properties: Vec<PropertyChange>,
+    /// Whether the physical resource is kept when the resource is removed from the template
+    retained: bool,
+}
+
+impl ResourceChange {
+    /// Whether the change deletes a physical resource, either by removing or replacing it.
+    /// Changes which are `Replacement::Conditional` may also be destructive, depending on the
+    /// new property values.
+    pub fn is_destructive(&self) -> bool {
+        match self.action {
+            Action::Remove => !self.retained,
+            Action::Modify => self.replacement == Replacement::True,
+            Action::Add => false,
+        }
+    }
+}
+
+/// Predicts how each resource would be updated going from one template to another, using the
+/// update types of the resource specification, without calling AWS.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ChangeSet {
+    changes: Vec<ResourceChange>,
+}
+
+impl ChangeSet {
+    pub fn between(before: &Template, after: &Template) -> Self {
+        let logical_ids = before
+            .resources()
+            .chain(after.resources())
+            .map(|(id, _)| id)
+            .collect::<BTreeSet<&str>>();
+        let changes = logical_ids
+            .into_iter()
+            .filter_map(|id| match (before.get(id), after.get(id)) {
+                (None, Some(entry)) => Some(added(id, entry)),
+                (Some(entry), None) => Some(removed(id, entry)),
+                (Some(old), Some(new)) if old != new => Some(modified(id, old, new)),
+                _ => None,
+            })
+            .collect();
+        Self { changes }
+    }
+
+    /// All changes, ordered by logical ID
+    pub fn changes(&self) -> &[ResourceChange] {
+        &self.changes
+    }
+
+    pub fn get(&self, logical_id: &str) -> Option<&ResourceChange> {
+        self.changes
+            .iter()
+            .find(|change| change.logical_id == logical_id)
+    }
+
+    /// Changes which remove or replace physical resources
+    pub fn destructive(&self) -> impl Iterator<Item = &ResourceChange> {
+        self.changes.iter().filter(|change| change.is_destructive())
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+fn added(logical_id: &str, entry: &ResourceEntry) -> ResourceChange {
+    ResourceChange {
+        logical_id: logical_id.to_string(),
+        resource_type: entry.type_.clone(),
+        action: Action::Add,
+        replacement: Replacement::False,
+        properties: vec![],
+        retained: false,
+    }
+}
+
+fn removed(logical_id: &str, entry: &ResourceEntry) -> ResourceChange {
+    ResourceChange {
+        action: Action::Remove,
+        retained: entry.deletion_policy.as_deref() == Some("Retain"),
+        ..added(logical_id, entry)
+    }
+}
+
+fn modified(logical_id: &str, old: &ResourceEntry, new: &ResourceEntry) -> ResourceChange {
+    let empty = serde_json::Map::new();
+    let old_properties = old.properties.as_object().unwrap_or(&empty);
+    let new_properties = new.properties.as_object().unwrap_or(&empty);
+    let names = old_properties
+        .keys()
+        .chain(new_properties.keys())
+        .collect::<BTreeSet<&String>>();
+
+    let properties = names
+        .into_iter()
+        .filter(|name| old_properties.get(*name) != new_properties.get(*name))
+        .map(|name| PropertyChange {
+            name: name.clone(),
+            update_type: registry::property(&new.type_, name).map(|info| info.update_type),
+        })
+        .collect::<Vec<PropertyChange>>();
+
+    // Changing the type always creates a new resource, and properties missing from the
+    // specification are assumed to possibly require a replacement.
+    let replacement = match old.type_ == new.type_ {
+        true => properties
+            .iter()
+            .map(|property| {
+                property
+                    .update_type
+                    .map(Replacement::from)
+                    .unwrap_or(Replacement::Conditional)
+            })
+            .max()
+            .unwrap_or(Replacement::False),
+        false => Replacement::True,
+    };
+    ResourceChange {
+        action: Action::Modify,
+        replacement,
+        properties,
+        ..added(logical_id, new)
+    }
+}
@@ -0,0 +1,69 @@
+use crate::enums::UntrustedArtifactOnDeployment;
+use crate::types::AWS::Lambda::CodeSigningConfig::{
+    AllowedPublishersBuilder, CodeSigningConfigBuilder, CodeSigningPoliciesBuilder,
+};
+use crate::types::AWS::Lambda::Function::Function;
+use crate::types::AWS::Signer::SigningProfile::SigningProfile;
+use crate::{Error, Expr, Intrinsic, LogicalId, Resource, Result, Template};
+use serde_json::Value;
+
+impl Template {
+    /// Have the `AWS::Lambda::Function` `function` only accept code signed through the
+    /// `AWS::Signer::SigningProfile` `profile`, both resources of this template.
+    ///
+    /// Adds an `AWS::Lambda::CodeSigningConfig` allowing the current version of the profile
+    /// under `<function>CodeSigningConfig`, and points the `CodeSigningConfigArn` of the
+    /// function at it. `policy` decides whether code signed otherwise is still deployed.
+    pub fn require_code_signing(
+        &mut self,
+        function: &str,
+        profile: &str,
+        policy: UntrustedArtifactOnDeployment,
+    ) -> Result<LogicalId> {
+        self.expect_type::<Function>(function)?;
+        self.expect_type::<SigningProfile>(profile)?;
+
+        let publishers = AllowedPublishersBuilder::default()
+            .SigningProfileVersionArns(vec![Expr::from(Intrinsic::get_att(
+                profile,
+                "ProfileVersionArn",
+            )?)])
+            .build()
+            .expect("All required properties are set");
+        let policies = CodeSigningPoliciesBuilder::default()
+            .UntrustedArtifactOnDeployment(policy)
+            .build()
+            .expect("All required properties are set");
+        let config = CodeSigningConfigBuilder::default()
+            .AllowedPublishers(publishers)
+            .CodeSigningPolicies(policies)
+            .build()
+            .expect("All required properties are set");
+
+        let config_id = LogicalId::new(format!("{}CodeSigningConfig", function))?;
+        self.add_resource(config_id.clone(), config)?;
+        let entry = self
+            .get_mut(function)
+            .expect("The function was found above");
+        entry.properties["CodeSigningConfigArn"] = Value::from(Intrinsic::get_att(
+            config_id.clone(),
+            "CodeSigningConfigArn",
+        )?);
+        Ok(config_id)
+    }
+
+    /// Fail unless `logical_id` is a resource of type `R`
+    fn expect_type<R: Resource>(&self, logical_id: &str) -> Result<()> {
+        let entry = self
+            .get(logical_id)
+            .ok_or_else(|| Error::UnknownLogicalId(logical_id.to_string()))?;
+        match entry.is::<R>() {
+            true => Ok(()),
+            false => Err(Error::TypeMismatch {
+                logical_id: logical_id.to_string(),
+                expected: R::TYPE.to_string(),
+                found: entry.type_.clone(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,226 @@
+use crate::validate::collect_references;
+use crate::{ResourceEntry, Result, Template};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Rules of an `AWS::EC2::SecurityGroup` which may be declared as resources of their own, by
+/// the property holding them, the type of the standalone resource and its logical ID suffix
+const SECURITY_GROUP_RULES: [(&str, &str, &str); 2] = [
+    (
+        "SecurityGroupIngress",
+        "AWS::EC2::SecurityGroupIngress",
+        "Ingress",
+    ),
+    (
+        "SecurityGroupEgress",
+        "AWS::EC2::SecurityGroupEgress",
+        "Egress",
+    ),
+];
+
+/// Resources referencing or depending on each other in a loop, directly or through other
+/// resources, so CloudFormation can't create any of them first. A resource referencing
+/// itself is a cycle of its own. Cycles are ordered by their first logical ID.
+pub(crate) fn dependency_cycles(template: &Template) -> Vec<Vec<String>> {
+    let mut cycles = strongly_connected(&template.dependencies())
+        .into_iter()
+        .filter(|component| match component.as_slice() {
+            [id] => template
+                .get(id)
+                .is_some_and(|entry| references(entry).contains(*id)),
+            _ => true,
+        })
+        .map(|mut component| {
+            component.sort_unstable();
+            component.into_iter().map(str::to_string).collect()
+        })
+        .collect::<Vec<Vec<String>>>();
+    cycles.sort();
+    cycles
+}
+
+/// Strongly connected components of `graph`, found by Tarjan's algorithm in a single walk:
+/// the nodes of a component reach each other, and a node in no loop is a component of its own
+pub(crate) fn strongly_connected<'a>(
+    graph: &BTreeMap<&'a str, BTreeSet<&'a str>>,
+) -> Vec<Vec<&'a str>> {
+    struct Walk<'a, 'g> {
+        graph: &'g BTreeMap<&'a str, BTreeSet<&'a str>>,
+        /// Order in which each node was reached
+        index: BTreeMap<&'a str, usize>,
+        /// Lowest index reachable from each node through the nodes still on the stack
+        low_link: BTreeMap<&'a str, usize>,
+        stack: Vec<&'a str>,
+        on_stack: BTreeSet<&'a str>,
+        components: Vec<Vec<&'a str>>,
+    }
+
+    impl<'a> Walk<'a, '_> {
+        fn visit(&mut self, node: &'a str) {
+            let index = self.index.len();
+            self.index.insert(node, index);
+            self.low_link.insert(node, index);
+            self.stack.push(node);
+            self.on_stack.insert(node);
+
+            let graph = self.graph;
+            for next in graph.get(node).into_iter().flatten().copied() {
+                let reachable = match self.index.get(next) {
+                    None => {
+                        self.visit(next);
+                        self.low_link[next]
+                    }
+                    Some(next_index) if self.on_stack.contains(next) => *next_index,
+                    Some(_) => continue,
+                };
+                if reachable < self.low_link[node] {
+                    self.low_link.insert(node, reachable);
+                }
+            }
+
+            // `node` is the first reached of its component, which is the top of the stack
+            if self.low_link[node] == index {
+                let mut component = vec![];
+                while let Some(member) = self.stack.pop() {
+                    self.on_stack.remove(member);
+                    component.push(member);
+                    if member == node {
+                        break;
+                    }
+                }
+                self.components.push(component);
+            }
+        }
+    }
+
+    let mut walk = Walk {
+        graph,
+        index: BTreeMap::new(),
+        low_link: BTreeMap::new(),
+        stack: vec![],
+        on_stack: BTreeSet::new(),
+        components: vec![],
+    };
+    for node in graph.keys().copied() {
+        if !walk.index.contains_key(node) {
+            walk.visit(node);
+        }
+    }
+    walk.components
+}
+
+/// Logical IDs `entry` references or depends on
+fn references(entry: &ResourceEntry) -> BTreeSet<String> {
+    let mut targets = BTreeSet::new();
+    collect_references(&entry.properties, &mut targets, &mut BTreeSet::new());
+    targets.extend(entry.depends_on.iter().map(|id| id.to_string()));
+    targets
+}
+
+/// Whether `rule` is chosen by an `Fn::If`, it has no `GroupId` of its own to set then
+fn is_conditional(rule: &Value) -> bool {
+    rule.as_object()
+        .is_some_and(|rule| rule.contains_key("Fn::If"))
+}
+
+/// Whether a cycle can be broken by `Template::break_cycles`, ie. it contains a security group
+pub(crate) fn has_security_group(template: &Template, cycle: &[String]) -> bool {
+    cycle.iter().any(|id| {
+        template
+            .get(id)
+            .is_some_and(|entry| entry.type_ == "AWS::EC2::SecurityGroup")
+    })
+}
+
+impl Template {
+    /// Logical IDs of the resources each resource references or depends on, other than itself
+    pub(crate) fn dependencies(&self) -> BTreeMap<&str, BTreeSet<&str>> {
+        let ids = self
+            .resources()
+            .map(|(id, _)| id)
+            .collect::<BTreeSet<&str>>();
+        self.resources()
+            .map(|(id, entry)| {
+                let dependencies = references(entry)
+                    .iter()
+                    .filter_map(|target| ids.get(target.as_str()).copied())
+                    .filter(|target| *target != id)
+                    .collect();
+                (id, dependencies)
+            })
+            .collect()
+    }
+
+    /// Break the dependency cycles of security groups allowing traffic from or to one another,
+    /// or themselves, as reported by `Template::validate`. Their ingress and egress rules
+    /// referencing a resource of the cycle are moved into standalone
+    /// `AWS::EC2::SecurityGroupIngress` and `AWS::EC2::SecurityGroupEgress` resources, named
+    /// after the group, ie. `WebIngress1`, which share the group's condition.
+    ///
+    /// Returns the logical IDs of the rules added. Cycles formed by other references, ie. a
+    /// `DependsOn` between the groups, and rules chosen by an `Fn::If` are left as they are.
+    pub fn break_cycles(&mut self) -> Result<Vec<String>> {
+        let mut moved = vec![];
+        for cycle in dependency_cycles(self) {
+            for group_id in cycle.iter() {
+                let group = match self.get(group_id) {
+                    Some(group) if group.type_ == "AWS::EC2::SecurityGroup" => group,
+                    _ => continue,
+                };
+                for (property, type_, suffix) in SECURITY_GROUP_RULES.iter() {
+                    let rules = match group.properties.get(*property) {
+                        Some(Value::Array(rules)) => rules,
+                        _ => continue,
+                    };
+                    let (cyclic, kept): (Vec<Value>, Vec<Value>) =
+                        rules.iter().cloned().partition(|rule| {
+                            if is_conditional(rule) {
+                                return false;
+                            }
+                            let mut targets = BTreeSet::new();
+                            collect_references(rule, &mut targets, &mut BTreeSet::new());
+                            targets.iter().any(|target| cycle.contains(target))
+                        });
+                    if !cyclic.is_empty() {
+                        moved.push((group_id.clone(), *property, *type_, *suffix, cyclic, kept));
+                    }
+                }
+            }
+        }
+
+        let mut added = vec![];
+        for (group_id, property, type_, suffix, cyclic, kept) in moved {
+            let group = self.get_mut(&group_id).expect("Groups of cycles exist");
+            match kept.is_empty() {
+                true => {
+                    if let Value::Object(properties) = &mut group.properties {
+                        properties.remove(property);
+                    }
+                }
+                false => group.properties[property] = Value::Array(kept),
+            }
+            let condition = group.condition.clone();
+
+            for rule in cyclic {
+                let mut properties = rule;
+                properties["GroupId"] = json!({"Fn::GetAtt": [group_id, "GroupId"]});
+                let logical_id = (1..)
+                    .map(|idx| format!("{}{}{}", group_id, suffix, idx))
+                    .find(|id| !self.contains(id))
+                    .expect("Some index is free");
+                let entry = ResourceEntry {
+                    type_: type_.to_string(),
+                    properties,
+                    depends_on: vec![],
+                    condition: condition.clone(),
+                    deletion_policy: None,
+                    update_replace_policy: None,
+                    metadata: Default::default(),
+                };
+                self.insert_resource(logical_id.as_str(), entry)?;
+                added.push(logical_id);
+            }
+        }
+        Ok(added)
+    }
+}
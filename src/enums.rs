@@ -0,0 +1,192 @@
+//! Properties limited to a fixed set of values, used by the generated types in place of strings.
+
+use serde::{Deserialize, Serialize};
+
+/// Whether an instance launched on a Dedicated Host stays on that host when restarted
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Affinity {
+    /// It may be restarted on any available host, the default
+    Default,
+    /// It's always restarted on the same host
+    Host,
+}
+
+/// Severity of an `AWS::SSM::Association` being out of compliance
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ComplianceSeverity {
+    Critical,
+    High,
+    Medium,
+    Low,
+    Unspecified,
+}
+
+/// Platform an `AWS::CodeDeploy::Application` or `DeploymentConfig` deploys to
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ComputePlatform {
+    Server,
+    Lambda,
+    #[serde(rename = "ECS")]
+    Ecs,
+}
+
+/// What the `FilterPolicy` of an `AWS::SNS::Subscription` is matched against
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FilterPolicyScope {
+    /// The message attributes, the default
+    MessageAttributes,
+    /// The properties of a JSON message body
+    MessageBody,
+}
+
+/// Role of an attribute in the `KeySchema` of a DynamoDB table or index
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum KeyType {
+    /// The partition key
+    Hash,
+    /// The sort key
+    Range,
+}
+
+/// The partition key, only there so the required `KeyType` can be defaulted
+impl Default for KeyType {
+    fn default() -> Self {
+        KeyType::Hash
+    }
+}
+
+/// Whether the `Value` of a CodeDeploy `MinimumHealthyHosts` is a number of instances or a
+/// percentage of the fleet
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum MinimumHealthyHostsType {
+    HostCount,
+    FleetPercent,
+}
+
+/// A number of instances, only there so the required `Type` can be defaulted
+impl Default for MinimumHealthyHostsType {
+    fn default() -> Self {
+        MinimumHealthyHostsType::HostCount
+    }
+}
+
+/// Order in which an `AWS::EC2::EC2Fleet` launches On-Demand capacity from its launch
+/// template overrides
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum OnDemandAllocationStrategy {
+    /// The cheapest instance types first, the default
+    #[serde(alias = "lowestPrice")]
+    LowestPrice,
+    /// By the `Priority` of the overrides
+    Prioritized,
+}
+
+/// Type of a key attribute in the `AttributeDefinitions` of a DynamoDB table
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ScalarAttributeType {
+    /// String
+    S,
+    /// Number
+    N,
+    /// Binary
+    B,
+}
+
+/// A string, only there so the required `AttributeType` can be defaulted
+impl Default for ScalarAttributeType {
+    fn default() -> Self {
+        ScalarAttributeType::S
+    }
+}
+
+/// How the `SpotOptions` of an `AWS::EC2::EC2Fleet` spread Spot Instances over its capacity
+/// pools, see `SpotFleetAllocationStrategy` for the names taken by `AWS::EC2::SpotFleet`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum SpotAllocationStrategy {
+    /// The cheapest pools, which are the most likely to be interrupted, validation warns
+    /// against it
+    #[serde(alias = "lowestPrice")]
+    LowestPrice,
+    /// Evenly over all pools
+    Diversified,
+    /// The pools with the most spare capacity
+    #[serde(alias = "capacityOptimized")]
+    CapacityOptimized,
+    /// The pools with the most spare capacity, preferring the `Priority` of the overrides
+    #[serde(alias = "capacityOptimizedPrioritized")]
+    CapacityOptimizedPrioritized,
+    /// The cheapest of the pools with the most spare capacity, the recommended strategy
+    #[serde(alias = "priceCapacityOptimized")]
+    PriceCapacityOptimized,
+}
+
+/// How the `SpotFleetRequestConfigData` of an `AWS::EC2::SpotFleet` spreads Spot Instances
+/// over its capacity pools, see `SpotAllocationStrategy`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SpotFleetAllocationStrategy {
+    /// The cheapest pools, which are the most likely to be interrupted, validation warns
+    /// against it
+    LowestPrice,
+    /// Evenly over all pools
+    Diversified,
+    /// The pools with the most spare capacity
+    CapacityOptimized,
+    /// The pools with the most spare capacity, preferring the `Priority` of the overrides
+    CapacityOptimizedPrioritized,
+    /// The cheapest of the pools with the most spare capacity, the recommended strategy
+    PriceCapacityOptimized,
+}
+
+/// Hardware instances run on, shared with other accounts or not
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Tenancy {
+    /// Shared hardware, the default
+    Default,
+    /// Hardware dedicated to the account
+    Dedicated,
+    /// A Dedicated Host, required when the placement gives a `HostId`
+    Host,
+}
+
+/// How traffic is shifted to the new version of a Lambda function or ECS service by a
+/// CodeDeploy `TrafficRoutingConfig`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TrafficRoutingType {
+    /// Two increments, the `TimeBasedCanary` percentage first
+    TimeBasedCanary,
+    /// Equal increments of the `TimeBasedLinear` percentage
+    TimeBasedLinear,
+    AllAtOnce,
+}
+
+/// Everything at once, only there so the required `Type` can be defaulted
+impl Default for TrafficRoutingType {
+    fn default() -> Self {
+        TrafficRoutingType::AllAtOnce
+    }
+}
+
+/// What Lambda does with code that isn't signed by an allowed publisher of an
+/// `AWS::Lambda::CodeSigningConfig`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum UntrustedArtifactOnDeployment {
+    /// Deploy it anyway, logging a warning to CloudWatch
+    Warn,
+    /// Refuse to deploy it
+    Enforce,
+}
+
+/// Warn, as CloudFormation does when the policy is omitted
+impl Default for UntrustedArtifactOnDeployment {
+    fn default() -> Self {
+        UntrustedArtifactOnDeployment::Warn
+    }
+}
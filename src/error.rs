@@ -0,0 +1,136 @@
+use std::fmt;
+
+/// Errors raised while assembling, reading or writing templates
+#[derive(Debug)]
+pub enum Error {
+    /// Converting between typed resources and their serialized form failed
+    Json(serde_json::Error),
+    /// Parsing or rendering a YAML template failed
+    Yaml(serde_yaml::Error),
+    /// Reading or writing files, ie. a cloud assembly, failed
+    Io(std::io::Error),
+    /// The logical ID is already in use within the template
+    DuplicateLogicalId(String),
+    /// No resource with this logical ID exists in the template
+    UnknownLogicalId(String),
+    /// Logical IDs have to be alphanumeric and at most 255 characters long
+    InvalidLogicalId(String),
+    /// Properties not part of the resource specification were found in strict mode,
+    /// given as paths, ie. `Vpc.CidrBlok`
+    UnknownProperties(Vec<String>),
+    /// Properties of a resource don't conform to its registry schema, given as paths and
+    /// what's wrong with them, ie. `ProviderSettings.InstanceSizeName: missing`
+    SchemaViolations(Vec<String>),
+    /// Resources can't be imported with the given physical IDs, with the logical IDs and what's
+    /// wrong with them, ie. `Logs: no DeletionPolicy`
+    InvalidImport(Vec<String>),
+    /// The spec given to `render` doesn't describe a valid template, with what's wrong with it,
+    /// ie. `Assets: unknown resource type AWS::S3::Bukcet`
+    InvalidSpec(Vec<String>),
+    /// Not a `rate(...)` or `cron(...)` schedule expression CloudFormation accepts
+    InvalidSchedule(String),
+    /// Not the code of a region known to the crate, ie. `eu-west-1`
+    InvalidRegion(String),
+    /// Not a region followed by a zone letter, ie. `eu-west-1a`
+    InvalidAvailabilityZone(String),
+    /// Not an IPv4 CIDR block, or the address has bits set beyond the prefix
+    InvalidCidr(String),
+    /// The planned subnets don't fit into the CIDR block of the VPC
+    InsufficientAddressSpace(String),
+    /// A FlexMatch rule set GameLift would reject, ie. an expansion of a rule it doesn't have
+    InvalidRuleSet(String),
+    /// The resources of a dependency cycle, which can't be split between nested stacks, are
+    /// more than fit into one
+    OversizedCycle(Vec<String>),
+    /// The resource or parameter exists, but is of a different type than requested
+    TypeMismatch {
+        logical_id: String,
+        expected: String,
+        found: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Error::Json(err) => write!(f, "{}", err),
+            Error::Yaml(err) => write!(f, "{}", err),
+            Error::Io(err) => write!(f, "{}", err),
+            Error::DuplicateLogicalId(id) => write!(f, "Logical ID '{}' is already in use", id),
+            Error::UnknownLogicalId(id) => write!(f, "No resource with logical ID '{}'", id),
+            Error::InvalidLogicalId(id) => write!(
+                f,
+                "Invalid logical ID '{}', only alphanumeric characters are allowed",
+                id
+            ),
+            Error::UnknownProperties(paths) => {
+                write!(f, "Unknown properties: {}", paths.join(", "))
+            }
+            Error::SchemaViolations(violations) => {
+                write!(f, "Schema violations: {}", violations.join(", "))
+            }
+            Error::InvalidImport(problems) => {
+                write!(f, "Invalid import: {}", problems.join(", "))
+            }
+            Error::InvalidSpec(problems) => write!(f, "Invalid spec: {}", problems.join(", ")),
+            Error::InvalidSchedule(expression) => {
+                write!(f, "Invalid schedule expression '{}'", expression)
+            }
+            Error::InvalidRegion(region) => write!(f, "Unknown region '{}'", region),
+            Error::InvalidAvailabilityZone(zone) => {
+                write!(f, "Invalid availability zone '{}'", zone)
+            }
+            Error::InvalidCidr(cidr) => write!(f, "Invalid CIDR block '{}'", cidr),
+            Error::InsufficientAddressSpace(reason) => write!(f, "{}", reason),
+            Error::InvalidRuleSet(reason) => write!(f, "Invalid rule set: {}", reason),
+            Error::OversizedCycle(cycle) => write!(
+                f,
+                "Dependency cycle of {} doesn't fit into a nested stack",
+                cycle.join(", ")
+            ),
+            Error::TypeMismatch {
+                logical_id,
+                expected,
+                found,
+            } => write!(f, "'{}' is of type {}, not {}", logical_id, found, expected),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Json(err) => Some(err),
+            Error::Yaml(err) => Some(err),
+            Error::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        Error::Json(err)
+    }
+}
+
+impl From<serde_yaml::Error> for Error {
+    fn from(err: serde_yaml::Error) -> Self {
+        Error::Yaml(err)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
+
+// Conversions which can't fail, ie. of a `LogicalId` into itself, see `IntoLogicalId`
+impl From<std::convert::Infallible> for Error {
+    fn from(err: std::convert::Infallible) -> Self {
+        match err {}
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
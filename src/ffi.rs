@@ -0,0 +1,251 @@
+//! C interface to parse, validate and serialize templates, behind the `ffi` feature, so
+//! deployment tools written in C, C++ or Go embed the crate rather than reimplement it.
+//!
+//! Functions return `CAROLINE_OK` or one of the other status codes, the message of the last
+//! failure of the calling thread is available from `caroline_last_error`. Panics are caught
+//! rather than unwinding into the caller, failing with `CAROLINE_PANIC`. Strings are passed
+//! as UTF-8 buffers of a pointer and a length, without a trailing NUL. Templates and buffers
+//! handed out are owned by the caller, who frees them with `caroline_template_free` and
+//! `caroline_buffer_free`. `include/caroline.h` declares the interface for C.
+
+use crate::{Error, Result, Template};
+use serde_json::{json, Value};
+use std::cell::RefCell;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+
+pub const CAROLINE_OK: i32 = 0;
+/// A pointer argument was null
+pub const CAROLINE_NULL_POINTER: i32 = 1;
+/// An input buffer wasn't valid UTF-8
+pub const CAROLINE_INVALID_UTF8: i32 = 2;
+/// The input isn't a template of the expected format
+pub const CAROLINE_PARSE_ERROR: i32 = 3;
+/// The template has issues, or couldn't be serialized
+pub const CAROLINE_INVALID_TEMPLATE: i32 = 4;
+/// The crate or one of its dependencies panicked, which is a bug
+pub const CAROLINE_PANIC: i32 = 5;
+
+thread_local! {
+    static LAST_ERROR: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+/// UTF-8 bytes handed out to the caller, freed by `caroline_buffer_free`
+#[repr(C)]
+#[derive(Debug)]
+pub struct CarolineBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+impl CarolineBuffer {
+    fn new(string: String) -> Self {
+        let bytes = string.into_bytes().into_boxed_slice();
+        let len = bytes.len();
+        Self {
+            data: Box::into_raw(bytes) as *mut u8,
+            len,
+        }
+    }
+
+    fn empty() -> Self {
+        Self::new(String::new())
+    }
+}
+
+/// Keep `message` for `caroline_last_error`, returning `status`
+fn fail(status: i32, message: impl ToString) -> i32 {
+    LAST_ERROR.with(|last| *last.borrow_mut() = Some(message.to_string()));
+    status
+}
+
+/// Run `f`, failing with `CAROLINE_PANIC` if it panics rather than unwinding into C, which
+/// would abort the process
+fn guard(f: impl FnOnce() -> i32) -> i32 {
+    panic::catch_unwind(AssertUnwindSafe(f)).unwrap_or_else(|payload| {
+        let message = match payload.downcast_ref::<&str>() {
+            Some(message) => message.to_string(),
+            None => payload
+                .downcast_ref::<String>()
+                .cloned()
+                .unwrap_or_default(),
+        };
+        fail(CAROLINE_PANIC, format!("Panicked: {}", message))
+    })
+}
+
+fn status(err: &Error) -> i32 {
+    match err {
+        Error::Json(_) | Error::Yaml(_) => CAROLINE_PARSE_ERROR,
+        _ => CAROLINE_INVALID_TEMPLATE,
+    }
+}
+
+unsafe fn parse(
+    data: *const u8,
+    len: usize,
+    out: *mut *mut Template,
+    parse: fn(&str) -> Result<Template>,
+) -> i32 {
+    if data.is_null() || out.is_null() {
+        return fail(CAROLINE_NULL_POINTER, "Null pointer given");
+    }
+    let input = match std::str::from_utf8(std::slice::from_raw_parts(data, len)) {
+        Ok(input) => input,
+        Err(err) => return fail(CAROLINE_INVALID_UTF8, err),
+    };
+    match parse(input) {
+        Ok(template) => {
+            *out = Box::into_raw(Box::new(template));
+            CAROLINE_OK
+        }
+        Err(err) => fail(status(&err), err),
+    }
+}
+
+unsafe fn write(
+    template: *const Template,
+    out: *mut CarolineBuffer,
+    write: fn(&Template) -> Result<String>,
+) -> i32 {
+    if template.is_null() || out.is_null() {
+        return fail(CAROLINE_NULL_POINTER, "Null pointer given");
+    }
+    match write(&*template) {
+        Ok(output) => {
+            *out = CarolineBuffer::new(output);
+            CAROLINE_OK
+        }
+        Err(err) => fail(status(&err), err),
+    }
+}
+
+/// Parse a JSON template of `len` bytes at `data` into `*out`
+///
+/// # Safety
+///
+/// `data` has to point to `len` readable bytes and `out` to a writable pointer.
+#[no_mangle]
+pub unsafe extern "C" fn caroline_template_from_json(
+    data: *const u8,
+    len: usize,
+    out: *mut *mut Template,
+) -> i32 {
+    guard(|| parse(data, len, out, Template::from_json))
+}
+
+/// Parse a YAML template of `len` bytes at `data` into `*out`
+///
+/// # Safety
+///
+/// `data` has to point to `len` readable bytes and `out` to a writable pointer.
+#[no_mangle]
+pub unsafe extern "C" fn caroline_template_from_yaml(
+    data: *const u8,
+    len: usize,
+    out: *mut *mut Template,
+) -> i32 {
+    guard(|| parse(data, len, out, Template::from_yaml))
+}
+
+/// Serialize `template` as pretty printed JSON into `*out`
+///
+/// # Safety
+///
+/// `template` has to come from one of the parse functions, not yet freed, and `out` has to
+/// point to a writable `CarolineBuffer`.
+#[no_mangle]
+pub unsafe extern "C" fn caroline_template_to_json(
+    template: *const Template,
+    out: *mut CarolineBuffer,
+) -> i32 {
+    guard(|| write(template, out, Template::to_json))
+}
+
+/// Serialize `template` as YAML into `*out`
+///
+/// # Safety
+///
+/// `template` has to come from one of the parse functions, not yet freed, and `out` has to
+/// point to a writable `CarolineBuffer`.
+#[no_mangle]
+pub unsafe extern "C" fn caroline_template_to_yaml(
+    template: *const Template,
+    out: *mut CarolineBuffer,
+) -> i32 {
+    guard(|| write(template, out, Template::to_yaml))
+}
+
+/// Validate `template`, writing its issues into `*out` as a JSON array of objects with the
+/// `LogicalId` they were found in and a `Message`. Returns `CAROLINE_INVALID_TEMPLATE` if
+/// there are any, `*out` is written and has to be freed either way.
+///
+/// # Safety
+///
+/// `template` has to come from one of the parse functions, not yet freed, and `out` has to
+/// point to a writable `CarolineBuffer`.
+#[no_mangle]
+pub unsafe extern "C" fn caroline_template_validate(
+    template: *const Template,
+    out: *mut CarolineBuffer,
+) -> i32 {
+    if template.is_null() || out.is_null() {
+        return fail(CAROLINE_NULL_POINTER, "Null pointer given");
+    }
+    guard(|| {
+        let issues = (*template).validate();
+        let report = issues
+            .iter()
+            .map(|issue| json!({"LogicalId": issue.logical_id(), "Message": issue.to_string()}))
+            .collect::<Value>();
+        *out = CarolineBuffer::new(report.to_string());
+        match issues.is_empty() {
+            true => CAROLINE_OK,
+            false => fail(
+                CAROLINE_INVALID_TEMPLATE,
+                format!("{} issues found", issues.len()),
+            ),
+        }
+    })
+}
+
+/// Message of the last failure of the calling thread, empty if none failed yet
+#[no_mangle]
+pub extern "C" fn caroline_last_error() -> CarolineBuffer {
+    panic::catch_unwind(|| {
+        LAST_ERROR.with(|last| match last.borrow().as_ref() {
+            Some(message) => CarolineBuffer::new(message.clone()),
+            None => CarolineBuffer::empty(),
+        })
+    })
+    .unwrap_or_else(|_| CarolineBuffer::empty())
+}
+
+/// Free a template returned by one of the parse functions, null is ignored
+///
+/// # Safety
+///
+/// `template` has to come from one of the parse functions and not be freed already.
+#[no_mangle]
+pub unsafe extern "C" fn caroline_template_free(template: *mut Template) {
+    if !template.is_null() {
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| drop(Box::from_raw(template))));
+    }
+}
+
+/// Free a buffer handed out by the crate, one with a null `data` is ignored
+///
+/// # Safety
+///
+/// `buffer` has to be handed out by the crate, unchanged, and not be freed already.
+#[no_mangle]
+pub unsafe extern "C" fn caroline_buffer_free(buffer: CarolineBuffer) {
+    if !buffer.data.is_null() {
+        let _ = panic::catch_unwind(|| {
+            drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+                buffer.data,
+                buffer.len,
+            )))
+        });
+    }
+}
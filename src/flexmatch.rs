@@ -0,0 +1,325 @@
+use crate::{Error, Result};
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+
+/// Type of a player attribute, ie. a player's skill as a `Number`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AttributeType {
+    String,
+    Number,
+    StringList,
+    StringNumberMap,
+}
+
+/// A value players provide when requesting a match, which rules measure
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct PlayerAttribute {
+    name: String,
+    #[serde(rename = "type")]
+    type_: AttributeType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default: Option<Value>,
+}
+
+impl PlayerAttribute {
+    pub fn new(name: impl Into<String>, type_: AttributeType) -> Self {
+        Self {
+            name: name.into(),
+            type_,
+            default: None,
+        }
+    }
+
+    /// Value of players not providing the attribute
+    pub fn default(mut self, value: impl Into<Value>) -> Self {
+        self.default = Some(value.into());
+        self
+    }
+}
+
+/// A team of a match, with the number of players it takes
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Team {
+    name: String,
+    min_players: u32,
+    max_players: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    quantity: Option<u32>,
+}
+
+impl Team {
+    pub fn new(name: impl Into<String>, min_players: u32, max_players: u32) -> Self {
+        Self {
+            name: name.into(),
+            min_players,
+            max_players,
+            quantity: None,
+        }
+    }
+
+    /// Create `quantity` teams of this definition, ie. for free-for-all matches
+    pub fn quantity(mut self, quantity: u32) -> Self {
+        self.quantity = Some(quantity);
+        self
+    }
+}
+
+/// Kind of a rule, which decides the parameters it takes
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RuleType {
+    AbsoluteSort,
+    BatchDistance,
+    Collection,
+    Comparison,
+    Compound,
+    Distance,
+    DistanceSort,
+    Latency,
+}
+
+/// A rule players of a match have to satisfy, ie. teams of similar average skill
+///
+/// Parameters common to the rule types have their own setters, anything else can be given
+/// through `parameter`.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Rule {
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(rename = "type")]
+    type_: RuleType,
+    #[serde(flatten)]
+    parameters: Map<String, Value>,
+}
+
+impl Rule {
+    pub fn new(name: impl Into<String>, type_: RuleType) -> Self {
+        Self {
+            name: name.into(),
+            description: None,
+            type_,
+            parameters: Map::new(),
+        }
+    }
+
+    /// A `distance` rule, players' measurements have to be within a distance of a reference
+    pub fn distance(name: impl Into<String>) -> Self {
+        Self::new(name, RuleType::Distance)
+    }
+
+    /// A `comparison` rule, players' measurements compared by `operation`, ie. `=`
+    pub fn comparison(name: impl Into<String>, operation: &str) -> Self {
+        Self::new(name, RuleType::Comparison).parameter("operation", operation)
+    }
+
+    /// A `collection` rule, on player attributes which are lists, by `operation`, ie. `intersection`
+    pub fn collection(name: impl Into<String>, operation: &str) -> Self {
+        Self::new(name, RuleType::Collection).parameter("operation", operation)
+    }
+
+    /// A `latency` rule, players' latency to the region of the match has to be within
+    /// `max_latency` milliseconds
+    pub fn latency(name: impl Into<String>, max_latency: u32) -> Self {
+        Self::new(name, RuleType::Latency).parameter("maxLatency", max_latency)
+    }
+
+    /// A `compound` rule, combining other rules by `statement`, ie. `or(FastConnection, SameRegion)`
+    pub fn compound(name: impl Into<String>, statement: impl Into<String>) -> Self {
+        Self::new(name, RuleType::Compound).parameter("statement", statement.into())
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// Property expressions of the players' attributes to measure, ie.
+    /// `avg(teams[*].players.attributes[skill])`
+    pub fn measurements<I: IntoIterator<Item = S>, S: Into<String>>(self, measurements: I) -> Self {
+        let measurements = measurements
+            .into_iter()
+            .map(|measurement| Value::String(measurement.into()))
+            .collect::<Vec<Value>>();
+        self.parameter("measurements", measurements)
+    }
+
+    /// Value measurements are compared with, a number or a property expression
+    pub fn reference_value(self, value: impl Into<Value>) -> Self {
+        self.parameter("referenceValue", value)
+    }
+
+    pub fn min_distance(self, distance: f64) -> Self {
+        self.parameter("minDistance", distance)
+    }
+
+    pub fn max_distance(self, distance: f64) -> Self {
+        self.parameter("maxDistance", distance)
+    }
+
+    /// Any parameter of the rule, ie. `partyAggregation`
+    pub fn parameter(mut self, name: impl Into<String>, value: impl Into<Value>) -> Self {
+        self.parameters.insert(name.into(), value.into());
+        self
+    }
+}
+
+/// One relaxation of an expansion, applied once a match has been searched for `wait_time_seconds`
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExpansionStep {
+    wait_time_seconds: u32,
+    value: Value,
+}
+
+/// Relaxes a rule or team value, ie. `rules[FairTeamSkill].maxDistance`, the longer players
+/// wait for a match
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct Expansion {
+    target: String,
+    steps: Vec<ExpansionStep>,
+}
+
+impl Expansion {
+    pub fn new(target: impl Into<String>) -> Self {
+        Self {
+            target: target.into(),
+            steps: vec![],
+        }
+    }
+
+    /// Use `value` once a match has been searched for `wait_time_seconds`
+    pub fn step(mut self, wait_time_seconds: u32, value: impl Into<Value>) -> Self {
+        self.steps.push(ExpansionStep {
+            wait_time_seconds,
+            value: value.into(),
+        });
+        self
+    }
+}
+
+/// A FlexMatch rule set, serialized into the `RuleSetBody` of an
+/// `AWS::GameLift::MatchmakingRuleSet` rather than written by hand.
+///
+/// ```
+/// # use caroline::{AttributeType, Expansion, PlayerAttribute, Rule, RuleSet, Team};
+/// let rule_set = RuleSet::new("duel")
+///     .player_attribute(PlayerAttribute::new("skill", AttributeType::Number).default(10))
+///     .team(Team::new("players", 1, 1).quantity(2))
+///     .rule(
+///         Rule::distance("FairSkill")
+///             .measurements(vec!["avg(teams[*].players.attributes[skill])"])
+///             .reference_value("avg(flatten(teams[*].players.attributes[skill]))")
+///             .max_distance(10.0),
+///     )
+///     .expansion(Expansion::new("rules[FairSkill].maxDistance").step(10, 50.0));
+/// let body = rule_set.body().unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleSet {
+    name: String,
+    rule_language_version: String,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    player_attributes: Vec<PlayerAttribute>,
+    teams: Vec<Team>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    rules: Vec<Rule>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    expansions: Vec<Expansion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    algorithm: Option<Value>,
+}
+
+impl RuleSet {
+    /// An empty rule set of version `1.0` of the rule language
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            rule_language_version: "1.0".to_string(),
+            player_attributes: vec![],
+            teams: vec![],
+            rules: vec![],
+            expansions: vec![],
+            algorithm: None,
+        }
+    }
+
+    pub fn player_attribute(mut self, attribute: PlayerAttribute) -> Self {
+        self.player_attributes.push(attribute);
+        self
+    }
+
+    pub fn team(mut self, team: Team) -> Self {
+        self.teams.push(team);
+        self
+    }
+
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    pub fn expansion(mut self, expansion: Expansion) -> Self {
+        self.expansions.push(expansion);
+        self
+    }
+
+    /// How players are batched and sorted into matches, ie. `{"strategy": "balanced"}`
+    pub fn algorithm(mut self, algorithm: impl Into<Value>) -> Self {
+        self.algorithm = Some(algorithm.into());
+        self
+    }
+
+    /// The rule set as the JSON string `RuleSetBody` takes, failing with
+    /// `Error::InvalidRuleSet` on mistakes GameLift would reject it for, ie. teams without
+    /// room for their minimum of players or expansions of rules which don't exist
+    pub fn body(&self) -> Result<String> {
+        self.check()?;
+        Ok(serde_json::to_string(self)?)
+    }
+
+    fn check(&self) -> Result<()> {
+        let invalid = |reason: String| Err(Error::InvalidRuleSet(reason));
+        if self.teams.is_empty() {
+            return invalid(format!("Rule set '{}' has no teams", self.name));
+        }
+        if let Some(team) = self
+            .teams
+            .iter()
+            .find(|team| team.min_players > team.max_players || team.max_players == 0)
+        {
+            return invalid(format!(
+                "Team '{}' takes {} to {} players",
+                team.name, team.min_players, team.max_players
+            ));
+        }
+        let mut names = BTreeSet::new();
+        if let Some(rule) = self
+            .rules
+            .iter()
+            .find(|rule| !names.insert(rule.name.as_str()))
+        {
+            return invalid(format!("Rule '{}' is defined more than once", rule.name));
+        }
+        for expansion in self.expansions.iter() {
+            let rule = expansion
+                .target
+                .strip_prefix("rules[")
+                .and_then(|target| target.split(']').next());
+            if let Some(rule) = rule.filter(|rule| !names.contains(rule)) {
+                return invalid(format!(
+                    "Expansion '{}' targets the unknown rule '{}'",
+                    expansion.target, rule
+                ));
+            }
+            if expansion.steps.is_empty() {
+                return invalid(format!("Expansion '{}' has no steps", expansion.target));
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,65 @@
+use crate::types::AWS::EC2::Host::Host;
+use crate::{Expr, TypedRef};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// The ID of a Dedicated Host, ie. `h-0123456789abcdef0`, taken by the `HostId` of a
+/// placement along with a `TypedRef` to an `AWS::EC2::Host` of the template
+///
+/// ```
+/// # use caroline::enums::Tenancy;
+/// # use caroline::types::AWS::EC2::Host::HostBuilder;
+/// # use caroline::types::AWS::EC2::LaunchTemplate::PlacementBuilder;
+/// # use caroline::{Intrinsic, Template};
+/// let mut template = Template::new();
+/// let host = HostBuilder::default()
+///     .AvailabilityZone(Intrinsic::select(0, Intrinsic::get_azs("")))
+///     .InstanceFamily("m5")
+///     .build()
+///     .unwrap();
+/// let host = template.add_typed_resource("Host", host).unwrap();
+/// let placement = PlacementBuilder::default()
+///     .HostId(host)
+///     .Tenancy(Tenancy::Host)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct HostId(String);
+
+impl HostId {
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for HostId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl From<HostId> for Expr<HostId> {
+    fn from(id: HostId) -> Self {
+        Expr::Literal(id)
+    }
+}
+
+impl From<&str> for Expr<HostId> {
+    fn from(id: &str) -> Self {
+        Expr::Literal(HostId(id.to_string()))
+    }
+}
+
+impl From<String> for Expr<HostId> {
+    fn from(id: String) -> Self {
+        Expr::Literal(HostId(id))
+    }
+}
+
+impl From<TypedRef<Host>> for Expr<HostId> {
+    fn from(host: TypedRef<Host>) -> Self {
+        Expr::Intrinsic(host.into())
+    }
+}
@@ -0,0 +1,132 @@
+use crate::{registry, Error, Result, Template};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// The physical identifier of an existing resource, either its only identifier, ie. the name of
+/// a bucket, or its identifiers by name for types which have several, ie. `Cluster` and
+/// `ServiceArn` of an ECS service
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PhysicalId {
+    Id(String),
+    Identifiers(BTreeMap<String, String>),
+}
+
+impl From<&str> for PhysicalId {
+    fn from(id: &str) -> Self {
+        PhysicalId::Id(id.to_string())
+    }
+}
+
+impl From<String> for PhysicalId {
+    fn from(id: String) -> Self {
+        PhysicalId::Id(id)
+    }
+}
+
+impl From<BTreeMap<String, String>> for PhysicalId {
+    fn from(identifiers: BTreeMap<String, String>) -> Self {
+        PhysicalId::Identifiers(identifiers)
+    }
+}
+
+impl<const N: usize> From<[(&str, &str); N]> for PhysicalId {
+    fn from(identifiers: [(&str, &str); N]) -> Self {
+        let identifiers = identifiers
+            .iter()
+            .map(|(name, id)| (name.to_string(), id.to_string()))
+            .collect();
+        PhysicalId::Identifiers(identifiers)
+    }
+}
+
+/// An existing resource to bring under management of a stack, serialized as an element of the
+/// `ResourcesToImport` of an `IMPORT` change set
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ResourceToImport {
+    pub resource_type: String,
+    pub logical_resource_id: String,
+    pub resource_identifier: BTreeMap<String, String>,
+}
+
+impl Template {
+    /// The `ResourcesToImport` of a change set importing existing resources as the resources of
+    /// the template with the given logical IDs.
+    ///
+    /// Fails with `Error::InvalidImport` listing every problem CloudFormation would reject the
+    /// import for, ie. resources of types which can't be imported, physical IDs lacking an
+    /// identifier, or resources without a `DeletionPolicy`.
+    ///
+    /// ```
+    /// # use caroline::types::AWS::S3::Bucket::BucketBuilder;
+    /// # use caroline::Template;
+    /// let mut template = Template::new();
+    /// let bucket = BucketBuilder::default().build().unwrap();
+    /// template.add_resource("Logs", bucket).unwrap();
+    /// template.get_mut("Logs").unwrap().set_deletion_policy("Retain");
+    ///
+    /// let import = template.resources_to_import(vec![("Logs", "my-logs")]).unwrap();
+    /// let payload = serde_json::to_string(&import).unwrap();
+    /// ```
+    pub fn resources_to_import<I, K, V>(&self, physical_ids: I) -> Result<Vec<ResourceToImport>>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<PhysicalId>,
+    {
+        let mut problems = vec![];
+        let mut resources = vec![];
+        for (logical_id, physical_id) in physical_ids {
+            let logical_id = logical_id.into();
+            let entry = match self.get(&logical_id) {
+                Some(entry) => entry,
+                None => {
+                    problems.push(format!("{}: unknown logical ID", logical_id));
+                    continue;
+                }
+            };
+            let names = match registry::identifiers(&entry.type_) {
+                Some(names) => names,
+                None => {
+                    problems.push(format!("{}: {} can't be imported", logical_id, entry.type_));
+                    continue;
+                }
+            };
+            if entry.deletion_policy.is_none() {
+                problems.push(format!("{}: no DeletionPolicy", logical_id));
+            }
+            let identifier = match physical_id.into() {
+                PhysicalId::Id(id) if names.len() == 1 => {
+                    BTreeMap::from([(names[0].to_string(), id)])
+                }
+                PhysicalId::Id(_) => {
+                    problems.push(format!(
+                        "{}: identified by {}",
+                        logical_id,
+                        names.join(", ")
+                    ));
+                    continue;
+                }
+                PhysicalId::Identifiers(identifiers) => identifiers,
+            };
+            for name in names.iter().filter(|name| !identifier.contains_key(**name)) {
+                problems.push(format!("{}: missing identifier {}", logical_id, name));
+            }
+            for name in identifier
+                .keys()
+                .filter(|name| !names.contains(&name.as_str()))
+            {
+                problems.push(format!("{}: unknown identifier {}", logical_id, name));
+            }
+            resources.push(ResourceToImport {
+                resource_type: entry.type_.clone(),
+                logical_resource_id: logical_id,
+                resource_identifier: identifier,
+            });
+        }
+        match problems.is_empty() {
+            true => Ok(resources),
+            false => Err(Error::InvalidImport(problems)),
+        }
+    }
+}
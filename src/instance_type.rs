@@ -0,0 +1,166 @@
+use crate::aws::INSTANCE_TYPES;
+use serde::{Deserialize, Serialize};
+
+/// Processor architecture of an EC2 instance type
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Architecture {
+    #[serde(rename = "x86_64")]
+    X86_64,
+    #[serde(rename = "arm64")]
+    Arm64,
+}
+
+/// Whether an EC2 instance type can be EBS-optimized
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum EbsOptimizedSupport {
+    Unsupported,
+    /// Only with `EbsOptimized` set, at an additional cost
+    Supported,
+    /// Always, whatever `EbsOptimized` is set to
+    Default,
+}
+
+/// Attributes of an EC2 instance type, ie. `m6i.large`
+///
+/// ```
+/// # use caroline::{Architecture, InstanceTypeInfo};
+/// let m6i = InstanceTypeInfo::get("m6i.large").unwrap();
+/// assert_eq!(m6i.vcpus, 2);
+/// assert_eq!(m6i.architectures, &[Architecture::X86_64]);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct InstanceTypeInfo {
+    pub name: &'static str,
+    pub vcpus: u32,
+    pub default_cores: u32,
+    pub default_threads_per_core: u32,
+    /// Core counts the `CpuOptions` of an instance may set, none if it can't set any
+    pub valid_cores: &'static [u32],
+    /// Threads per core the `CpuOptions` of an instance may set, none if it can't set any
+    pub valid_threads_per_core: &'static [u32],
+    pub memory_mib: u32,
+    pub ebs_optimized: EbsOptimizedSupport,
+    pub architectures: &'static [Architecture],
+}
+
+impl InstanceTypeInfo {
+    /// Attributes of the instance type `name`, `None` if it's not known to the crate
+    pub fn get(name: &str) -> Option<&'static Self> {
+        INSTANCE_TYPES
+            .binary_search_by(|info| info.name.cmp(name))
+            .ok()
+            .map(|idx| &INSTANCE_TYPES[idx])
+    }
+
+    /// Every instance type known to the crate, ordered by name
+    pub fn all() -> impl Iterator<Item = &'static Self> {
+        INSTANCE_TYPES.iter()
+    }
+
+    /// Family of the instance type, ie. `m6i` for `m6i.large`
+    pub fn family(&self) -> &'static str {
+        self.name.split('.').next().unwrap_or(self.name)
+    }
+
+    /// Size of the instance type within its family, ie. `large` for `m6i.large`
+    pub fn size(&self) -> &'static str {
+        self.name.split('.').nth(1).unwrap_or_default()
+    }
+
+    /// Whether instances of this type may set `CpuOptions`
+    pub fn supports_cpu_options(&self) -> bool {
+        !self.valid_cores.is_empty()
+    }
+
+    /// Whether instances of this type accept `CpuOptions` of `core_count` and
+    /// `threads_per_core`, either falling back to the default of the type when not given
+    pub fn accepts_cpu_options(
+        &self,
+        core_count: Option<u32>,
+        threads_per_core: Option<u32>,
+    ) -> bool {
+        self.supports_cpu_options()
+            && core_count.is_none_or(|cores| self.valid_cores.contains(&cores))
+            && threads_per_core.is_none_or(|threads| self.valid_threads_per_core.contains(&threads))
+    }
+}
+
+/// Finds instance types by their attributes, ie. for constructs sizing instances to a
+/// workload
+///
+/// ```
+/// # use caroline::{Architecture, InstanceTypeQuery};
+/// let instance_type = InstanceTypeQuery::new()
+///     .min_vcpus(4)
+///     .min_memory_mib(16 * 1024)
+///     .architecture(Architecture::Arm64)
+///     .family("m7g")
+///     .smallest()
+///     .unwrap();
+/// assert_eq!(instance_type.name, "m7g.xlarge");
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InstanceTypeQuery {
+    min_vcpus: u32,
+    min_memory_mib: u32,
+    architecture: Option<Architecture>,
+    families: Vec<String>,
+    cpu_options: bool,
+}
+
+impl InstanceTypeQuery {
+    /// A query matching every instance type
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn min_vcpus(mut self, vcpus: u32) -> Self {
+        self.min_vcpus = vcpus;
+        self
+    }
+
+    pub fn min_memory_mib(mut self, memory_mib: u32) -> Self {
+        self.min_memory_mib = memory_mib;
+        self
+    }
+
+    pub fn architecture(mut self, architecture: Architecture) -> Self {
+        self.architecture = Some(architecture);
+        self
+    }
+
+    /// Only match instance types of `family`, ie. `m6i`, or of any family added this way
+    pub fn family(mut self, family: impl Into<String>) -> Self {
+        self.families.push(family.into());
+        self
+    }
+
+    /// Only match instance types which may set `CpuOptions`
+    pub fn cpu_options(mut self) -> Self {
+        self.cpu_options = true;
+        self
+    }
+
+    pub fn matches(&self, info: &InstanceTypeInfo) -> bool {
+        info.vcpus >= self.min_vcpus
+            && info.memory_mib >= self.min_memory_mib
+            && self
+                .architecture
+                .is_none_or(|architecture| info.architectures.contains(&architecture))
+            && (self.families.is_empty()
+                || self.families.iter().any(|family| family == info.family()))
+            && (!self.cpu_options || info.supports_cpu_options())
+    }
+
+    /// Matching instance types, ordered by name
+    pub fn iter(&self) -> impl Iterator<Item = &'static InstanceTypeInfo> + '_ {
+        InstanceTypeInfo::all().filter(move |info| self.matches(info))
+    }
+
+    /// The matching instance type with the fewest vCPUs, then the least memory, ties going to
+    /// the first by name
+    pub fn smallest(&self) -> Option<&'static InstanceTypeInfo> {
+        self.iter().min_by_key(|info| (info.vcpus, info.memory_mib))
+    }
+}
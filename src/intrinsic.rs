@@ -0,0 +1,229 @@
+use crate::enums::{
+    Affinity, ComplianceSeverity, ComputePlatform, FilterPolicyScope, KeyType,
+    MinimumHealthyHostsType, OnDemandAllocationStrategy, ScalarAttributeType,
+    SpotAllocationStrategy, SpotFleetAllocationStrategy, Tenancy, TrafficRoutingType,
+    UntrustedArtifactOnDeployment,
+};
+use crate::visit::is_intrinsic;
+use crate::{
+    AvailabilityZone, DeliveryPolicy, FilterPolicy, IntoLogicalId, LogicalId, PolicyDocument,
+    Recurrence, RedriveAllowPolicy, RedrivePolicy, Result, ScheduleExpression,
+};
+use serde::de::{DeserializeOwned, Error as _};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::convert::TryFrom;
+
+/// A CloudFormation intrinsic function, serialized in its long form, ie. `{"Ref": "MyVpc"}`
+///
+/// `Ref` takes any string as it may also refer to pseudo parameters, ie. `AWS::Region`,
+/// use `Intrinsic::reference` to refer to a resource or parameter by `LogicalId`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum Intrinsic {
+    #[serde(rename = "Ref")]
+    Ref(String),
+    #[serde(rename = "Fn::GetAtt", deserialize_with = "get_att")]
+    GetAtt(String, String),
+    #[serde(rename = "Fn::Sub")]
+    Sub(Value),
+    #[serde(rename = "Fn::Join")]
+    Join(String, Vec<Value>),
+    #[serde(rename = "Fn::Select")]
+    Select(Value, Value),
+    #[serde(rename = "Fn::Split")]
+    Split(String, Value),
+    #[serde(rename = "Fn::GetAZs")]
+    GetAZs(Value),
+    #[serde(rename = "Fn::If")]
+    If(String, Value, Value),
+    #[serde(rename = "Fn::ImportValue")]
+    ImportValue(Value),
+    #[serde(rename = "Fn::Base64")]
+    Base64(Value),
+    #[serde(rename = "Fn::FindInMap")]
+    FindInMap(Value, Value, Value),
+    #[serde(rename = "Fn::Cidr")]
+    Cidr(Value, Value, Value),
+    #[serde(rename = "Fn::Equals")]
+    Equals(Value, Value),
+    #[serde(rename = "Fn::And")]
+    And(Vec<Value>),
+    #[serde(rename = "Fn::Or")]
+    Or(Vec<Value>),
+    #[serde(rename = "Fn::Not")]
+    Not(Vec<Value>),
+    #[serde(rename = "Condition")]
+    Condition(String),
+}
+
+impl Intrinsic {
+    /// `Ref` of the resource or parameter `target`, failing with `Error::InvalidLogicalId` if
+    /// it isn't a `LogicalId`, or of the pseudo parameter `target`, ie. `AWS::StackId`
+    pub fn reference(target: impl AsRef<str>) -> Result<Self> {
+        let target = target.as_ref();
+        match target.starts_with("AWS::") {
+            true => Ok(Intrinsic::Ref(target.to_string())),
+            false => Ok(Intrinsic::Ref(LogicalId::try_from(target)?.into_string())),
+        }
+    }
+
+    /// `Fn::GetAtt` of `attribute` on the resource `logical_id`
+    pub fn get_att(logical_id: impl IntoLogicalId, attribute: impl Into<String>) -> Result<Self> {
+        Ok(Intrinsic::GetAtt(
+            logical_id.into_logical_id()?.into_string(),
+            attribute.into(),
+        ))
+    }
+
+    /// `Fn::Sub` of a string containing `${...}` variables
+    pub fn sub(string: impl Into<String>) -> Self {
+        Intrinsic::Sub(Value::String(string.into()))
+    }
+
+    /// `Fn::Join` of `values` separated by `delimiter`
+    pub fn join(delimiter: impl Into<String>, values: Vec<Value>) -> Self {
+        Intrinsic::Join(delimiter.into(), values)
+    }
+
+    /// `Fn::Select` of the item at `index` from `list`
+    pub fn select(index: u32, list: impl Into<Value>) -> Self {
+        Intrinsic::Select(Value::from(index), list.into())
+    }
+
+    /// `Fn::GetAZs` of `region`, an empty string is the region the stack is deployed to
+    pub fn get_azs(region: impl Into<String>) -> Self {
+        Intrinsic::GetAZs(Value::String(region.into()))
+    }
+}
+
+/// The logical ID and attribute of an `Fn::GetAtt`, given as a list of both or in the short
+/// form `"Resource.Attribute"`, the attribute may contain dots itself, ie. `Db.Endpoint.Address`
+fn get_att<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<(String, String), D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum GetAtt {
+        List(String, String),
+        Path(String),
+    }
+
+    match GetAtt::deserialize(deserializer)? {
+        GetAtt::List(logical_id, attribute) => Ok((logical_id, attribute)),
+        GetAtt::Path(path) => match path.split_once('.') {
+            Some((logical_id, attribute)) => Ok((logical_id.to_string(), attribute.to_string())),
+            None => Err(D::Error::custom(format!(
+                "expected Fn::GetAtt of Resource.Attribute, got {}",
+                path
+            ))),
+        },
+    }
+}
+
+impl From<Intrinsic> for Value {
+    fn from(intrinsic: Intrinsic) -> Self {
+        serde_json::to_value(intrinsic).expect("Intrinsic functions are always serializable")
+    }
+}
+
+/// A property value which is either given literally or computed by an intrinsic function
+///
+/// Numbers and booleans are also read from strings, ie. `"FromPort": "22"`, as CloudFormation
+/// accepts them either way.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+#[serde(untagged)]
+pub enum Expr<T> {
+    Literal(T),
+    Intrinsic(Intrinsic),
+}
+
+impl<'de, T: DeserializeOwned> Deserialize<'de> for Expr<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let value = Value::deserialize(deserializer)?;
+        // Intrinsics come first, a literal of optional properties only would take any object
+        if matches!(&value, Value::Object(values) if is_intrinsic(values)) {
+            return Intrinsic::deserialize(value)
+                .map(Expr::Intrinsic)
+                .map_err(D::Error::custom);
+        }
+        let err = match T::deserialize(&value) {
+            Ok(literal) => return Ok(Expr::Literal(literal)),
+            Err(err) => err,
+        };
+        if let Value::String(string) = &value {
+            if let Ok(parsed @ (Value::Number(_) | Value::Bool(_))) = serde_json::from_str(string) {
+                return T::deserialize(parsed)
+                    .map(Expr::Literal)
+                    .map_err(D::Error::custom);
+            }
+        }
+        Intrinsic::deserialize(value)
+            .map(Expr::Intrinsic)
+            .map_err(|_| D::Error::custom(err))
+    }
+}
+
+impl<T: Default> Default for Expr<T> {
+    fn default() -> Self {
+        Expr::Literal(T::default())
+    }
+}
+
+impl<T> Expr<T> {
+    /// The literal value, if not computed by an intrinsic function
+    pub fn as_literal(&self) -> Option<&T> {
+        match self {
+            Expr::Literal(value) => Some(value),
+            Expr::Intrinsic(_) => None,
+        }
+    }
+}
+
+impl<T> From<Intrinsic> for Expr<T> {
+    fn from(intrinsic: Intrinsic) -> Self {
+        Expr::Intrinsic(intrinsic)
+    }
+}
+
+macro_rules! expr_from_literal {
+    ($($from:ty => $to:ty),*) => {
+        $(
+            impl From<$from> for Expr<$to> {
+                fn from(value: $from) -> Self {
+                    Expr::Literal(value.into())
+                }
+            }
+        )*
+    };
+}
+
+expr_from_literal!(
+    String => String,
+    &str => String,
+    bool => bool,
+    i32 => i32,
+    u32 => u32,
+    u64 => u64,
+    f32 => f32,
+    ScheduleExpression => ScheduleExpression,
+    Recurrence => Recurrence,
+    Affinity => Affinity,
+    ComplianceSeverity => ComplianceSeverity,
+    ComputePlatform => ComputePlatform,
+    FilterPolicyScope => FilterPolicyScope,
+    KeyType => KeyType,
+    MinimumHealthyHostsType => MinimumHealthyHostsType,
+    OnDemandAllocationStrategy => OnDemandAllocationStrategy,
+    ScalarAttributeType => ScalarAttributeType,
+    SpotAllocationStrategy => SpotAllocationStrategy,
+    SpotFleetAllocationStrategy => SpotFleetAllocationStrategy,
+    Tenancy => Tenancy,
+    TrafficRoutingType => TrafficRoutingType,
+    UntrustedArtifactOnDeployment => UntrustedArtifactOnDeployment,
+    AvailabilityZone => AvailabilityZone,
+    PolicyDocument => PolicyDocument,
+    RedrivePolicy => RedrivePolicy,
+    RedriveAllowPolicy => RedriveAllowPolicy,
+    FilterPolicy => FilterPolicy,
+    DeliveryPolicy => DeliveryPolicy
+);
@@ -1,3 +1,90 @@
+mod assembly;
+mod asset;
 mod aws;
+mod changeset;
+#[cfg(all(
+    any(feature = "all", feature = "lambda"),
+    any(feature = "all", feature = "signer")
+))]
+mod code_signing;
+mod cycles;
+pub mod enums;
+mod error;
+#[cfg(feature = "ffi")]
+pub mod ffi;
+#[cfg(any(feature = "all", feature = "gamelift"))]
+mod flexmatch;
+#[cfg(any(feature = "all", feature = "ec2"))]
+mod host;
+mod import;
+mod instance_type;
+mod intrinsic;
+mod logical_id;
+mod metadata;
+#[cfg(any(feature = "all", feature = "ec2"))]
+mod network;
+pub mod parameter_types;
+#[cfg(any(feature = "all", feature = "cloudformation"))]
+mod partition;
+mod policy;
+pub mod prelude;
+mod pretty;
+mod redact;
+mod redrive;
+mod region;
+pub mod registry;
+mod render;
+mod resource;
+mod rewrite;
+mod schedule;
+mod schema;
+mod secrets;
+mod strict;
+mod subscription;
+mod template;
+mod validate;
+mod view;
+mod visit;
+mod workspace;
+mod yaml;
 
+pub use assembly::{AssemblyAsset, CloudAssembly, Packaging};
+pub use asset::{Asset, AssetResolver};
 pub use aws::*;
+pub use changeset::{Action, ChangeSet, PropertyChange, Replacement, ResourceChange};
+pub use error::{Error, Result};
+#[cfg(any(feature = "all", feature = "gamelift"))]
+pub use flexmatch::{
+    AttributeType, Expansion, ExpansionStep, PlayerAttribute, Rule, RuleSet, RuleType, Team,
+};
+#[cfg(any(feature = "all", feature = "ec2"))]
+pub use host::HostId;
+pub use import::{PhysicalId, ResourceToImport};
+pub use instance_type::{Architecture, EbsOptimizedSupport, InstanceTypeInfo, InstanceTypeQuery};
+pub use intrinsic::{Expr, Intrinsic};
+pub use logical_id::{IntoLogicalId, LogicalId, MAX_LOGICAL_ID_LEN};
+pub use metadata::{Extension, Metadata};
+#[cfg(any(feature = "all", feature = "ec2"))]
+pub use network::{az_count, Cidr, PlannedSubnet, SubnetPlan};
+#[cfg(any(feature = "all", feature = "cloudformation"))]
+pub use partition::{Partition, MAX_RESOURCES};
+pub use policy::{Effect, PolicyDocument, Statement};
+pub use pretty::PrettyPrinter;
+pub use redact::{DebugUnredacted, Unredacted};
+pub use redrive::{RedriveAllowPolicy, RedrivePermission, RedrivePolicy};
+pub use region::AvailabilityZone;
+pub use registry::{PropertyInfo, PropertyKind, UpdateType};
+pub use render::render;
+pub use resource::{Resource, TypedRef, UnknownResource};
+pub use schedule::{RateUnit, Recurrence, ScheduleExpression};
+pub use schema::RegistrySchema;
+pub use subscription::{
+    BackoffFunction, DeliveryPolicy, FilterPolicy, HealthyRetryPolicy, RequestPolicy,
+    ThrottlePolicy,
+};
+pub use template::{CollisionPolicy, Export, Output, Parameter, ResourceEntry, Template};
+pub use validate::{Issue, Validator};
+pub use view::{ResourceRef, TemplateRef};
+pub use visit::{PropertyPath, Segment, Visitor, Walk};
+pub use workspace::Workspace;
+pub use yaml::COMMENT_KEY;
@@ -0,0 +1,169 @@
+use crate::{Error, Result};
+use serde::{de, Deserialize, Deserializer, Serialize};
+use std::borrow::Borrow;
+use std::convert::{TryFrom, TryInto};
+use std::fmt;
+use std::ops::Deref;
+
+/// Longest logical ID CloudFormation accepts
+pub const MAX_LOGICAL_ID_LEN: usize = 255;
+
+/// The logical ID of a resource, parameter or output, ie. `PublicSubnet0`
+///
+/// CloudFormation only accepts alphanumeric logical IDs of at most 255 characters, which is
+/// checked on construction rather than when the stack is deployed. `LogicalId::new` and
+/// `TryFrom` a string reject anything else, `sanitize` makes a logical ID of any name.
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(transparent)]
+pub struct LogicalId(String);
+
+impl LogicalId {
+    /// A logical ID of `id`, failing with `Error::InvalidLogicalId` unless it's alphanumeric
+    pub fn new(id: impl Into<String>) -> Result<Self> {
+        let id = id.into();
+        match Self::is_valid(&id) {
+            true => Ok(Self(id)),
+            false => Err(Error::InvalidLogicalId(id)),
+        }
+    }
+
+    /// A logical ID made from `name` by dropping anything other than ASCII letters and digits,
+    /// capitalizing the letter following each dropped character, ie. `public-subnet-a`
+    /// becomes `publicSubnetA`. Names which are already valid are kept as they are.
+    ///
+    /// Fails with `Error::InvalidLogicalId` if `name` has no letters or digits at all.
+    pub fn sanitize(name: &str) -> Result<Self> {
+        if Self::is_valid(name) {
+            return Ok(Self(name.to_string()));
+        }
+        let mut id = String::with_capacity(name.len());
+        let mut capitalize = false;
+        for c in name.chars() {
+            if !c.is_ascii_alphanumeric() {
+                capitalize = !id.is_empty();
+            } else if capitalize {
+                id.push(c.to_ascii_uppercase());
+                capitalize = false;
+            } else {
+                id.push(c);
+            }
+        }
+        id.truncate(MAX_LOGICAL_ID_LEN);
+        Self::new(id).map_err(|_| Error::InvalidLogicalId(name.to_string()))
+    }
+
+    /// Whether `id` is a logical ID CloudFormation accepts
+    pub fn is_valid(id: &str) -> bool {
+        !id.is_empty()
+            && id.len() <= MAX_LOGICAL_ID_LEN
+            && id.chars().all(|c| c.is_ascii_alphanumeric())
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+/// Validates `id`, see `LogicalId::new`
+impl TryFrom<&str> for LogicalId {
+    type Error = Error;
+
+    fn try_from(id: &str) -> Result<Self> {
+        Self::new(id)
+    }
+}
+
+/// Validates `id`, see `LogicalId::new`
+impl TryFrom<String> for LogicalId {
+    type Error = Error;
+
+    fn try_from(id: String) -> Result<Self> {
+        Self::new(id)
+    }
+}
+
+impl TryFrom<&String> for LogicalId {
+    type Error = Error;
+
+    fn try_from(id: &String) -> Result<Self> {
+        Self::new(id.as_str())
+    }
+}
+
+impl From<&LogicalId> for LogicalId {
+    fn from(id: &LogicalId) -> Self {
+        id.clone()
+    }
+}
+
+/// A logical ID, or a string converted into one by `TryFrom`, as taken by the template APIs.
+/// Strings are validated rather than sanitized, so they can be looked up as they are, failing
+/// with `Error::InvalidLogicalId` if they aren't a logical ID.
+pub trait IntoLogicalId {
+    fn into_logical_id(self) -> Result<LogicalId>;
+}
+
+impl<T> IntoLogicalId for T
+where
+    T: TryInto<LogicalId>,
+    Error: From<T::Error>,
+{
+    fn into_logical_id(self) -> Result<LogicalId> {
+        Ok(self.try_into()?)
+    }
+}
+
+/// Parsed logical IDs are validated, not sanitized, so they keep referring to the same thing
+impl<'de> Deserialize<'de> for LogicalId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        Self::new(String::deserialize(deserializer)?).map_err(de::Error::custom)
+    }
+}
+
+impl From<LogicalId> for String {
+    fn from(id: LogicalId) -> Self {
+        id.0
+    }
+}
+
+impl Deref for LogicalId {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for LogicalId {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Borrow<str> for LogicalId {
+    fn borrow(&self) -> &str {
+        &self.0
+    }
+}
+
+impl PartialEq<str> for LogicalId {
+    fn eq(&self, other: &str) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&str> for LogicalId {
+    fn eq(&self, other: &&str) -> bool {
+        self.0 == *other
+    }
+}
+
+impl fmt::Display for LogicalId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
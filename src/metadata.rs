@@ -0,0 +1,62 @@
+use crate::Result;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// A typed entry of the `Metadata` of a template or resource, stored under `KEY`, ie. the
+/// fingerprint or provenance information of the tool which generated the template.
+pub trait Extension: Serialize + DeserializeOwned {
+    /// Key of the entry, prefixing it with the name of the tool avoids clashes, ie. `MyTool::Provenance`
+    const KEY: &'static str;
+}
+
+/// The `Metadata` section of a template or resource
+///
+/// Entries are kept as given, so metadata of other tools, ie. `AWS::CloudFormation::Init`,
+/// survives parsing and serializing the template again.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct Metadata(BTreeMap<String, Value>);
+
+impl Metadata {
+    pub fn get(&self, key: &str) -> Option<&Value> {
+        self.0.get(key)
+    }
+
+    /// Insert any serializable `value` under `key`, replacing an existing entry
+    pub fn insert(&mut self, key: impl Into<String>, value: impl Serialize) -> Result<()> {
+        self.0.insert(key.into(), serde_json::to_value(value)?);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        self.0.remove(key)
+    }
+
+    /// The entry of extension `E`, `None` if there's no entry under `E::KEY`
+    pub fn extension<E: Extension>(&self) -> Result<Option<E>> {
+        match self.0.get(E::KEY) {
+            Some(value) => Ok(Some(serde_json::from_value(value.clone())?)),
+            None => Ok(None),
+        }
+    }
+
+    /// Store `extension` under `E::KEY`, replacing an existing entry
+    pub fn set_extension<E: Extension>(&mut self, extension: &E) -> Result<()> {
+        self.insert(E::KEY, extension)
+    }
+
+    /// Entries ordered by key
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.0.iter().map(|(key, value)| (key.as_str(), value))
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
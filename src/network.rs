@@ -0,0 +1,215 @@
+use crate::types::AWS::EC2::Subnet::SubnetBuilder;
+use crate::{Error, Expr, Intrinsic, Result, Template};
+use std::convert::TryFrom;
+use std::fmt;
+use std::net::Ipv4Addr;
+use std::str::FromStr;
+
+/// Number of availability zones of `region` open to new accounts at the time of writing,
+/// older accounts may see more, and `None` for regions not known to the crate
+pub fn az_count(region: &str) -> Option<u32> {
+    let count = match region {
+        "us-east-1" => 6,
+        "us-west-2" => 4,
+        "ap-northeast-2" => 4,
+        "us-west-1" | "cn-north-1" => 2,
+        "us-east-2" | "af-south-1" | "ap-east-1" | "ap-south-1" | "ap-northeast-1"
+        | "ap-northeast-3" | "ap-southeast-1" | "ap-southeast-2" | "ca-central-1"
+        | "eu-central-1" | "eu-west-1" | "eu-west-2" | "eu-west-3" | "eu-north-1"
+        | "eu-south-1" | "me-south-1" | "sa-east-1" | "cn-northwest-1" | "us-gov-west-1"
+        | "us-gov-east-1" => 3,
+        _ => return None,
+    };
+    Some(count)
+}
+
+/// An IPv4 CIDR block, ie. `10.0.0.0/16`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Cidr {
+    address: u32,
+    prefix: u8,
+}
+
+impl Cidr {
+    pub fn address(&self) -> Ipv4Addr {
+        Ipv4Addr::from(self.address)
+    }
+
+    pub fn prefix(&self) -> u8 {
+        self.prefix
+    }
+
+    fn size(&self) -> u64 {
+        1 << (32 - self.prefix)
+    }
+
+    fn end(&self) -> u64 {
+        self.address as u64 + self.size()
+    }
+
+    /// Whether the two blocks share any address
+    pub fn overlaps(&self, other: &Cidr) -> bool {
+        (self.address as u64) < other.end() && (other.address as u64) < self.end()
+    }
+}
+
+impl FromStr for Cidr {
+    type Err = Error;
+
+    fn from_str(cidr: &str) -> Result<Self> {
+        let invalid = || Error::InvalidCidr(cidr.to_string());
+        let (address, prefix) = cidr.split_once('/').ok_or_else(invalid)?;
+        let address = u32::from(address.parse::<Ipv4Addr>().map_err(|_| invalid())?);
+        let prefix = prefix.parse::<u8>().map_err(|_| invalid())?;
+        if prefix > 32 || (prefix < 32 && address & (u32::MAX >> prefix) != 0) {
+            return Err(invalid());
+        }
+        Ok(Self { address, prefix })
+    }
+}
+
+impl fmt::Display for Cidr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.address(), self.prefix)
+    }
+}
+
+/// A subnet of a `SubnetPlan`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PlannedSubnet {
+    /// Logical ID of the subnet, the tier name followed by the AZ index, ie. `PublicSubnet0`
+    pub logical_id: String,
+    pub cidr: Cidr,
+    /// Index into the region's availability zones, as returned by `Fn::GetAZs`
+    pub az: u32,
+    /// Whether instances get a public IP on launch
+    pub public: bool,
+}
+
+#[derive(Clone, Debug)]
+struct Tier {
+    name: String,
+    prefix: u8,
+    public: bool,
+}
+
+/// Splits the CIDR block of a VPC into non-overlapping subnets, one per availability zone for
+/// each tier, ie. a public `/24` and a private `/20` in each of three AZs.
+///
+/// Larger subnets are placed first, so smaller ones fill the remaining space without gaps.
+/// Subnets are placed in the AZs by index through `Fn::Select` and `Fn::GetAZs`, so the
+/// template stays region agnostic as long as the region has enough AZs, see `az_count`.
+#[derive(Clone, Debug)]
+pub struct SubnetPlan {
+    cidr: Cidr,
+    azs: u32,
+    tiers: Vec<Tier>,
+}
+
+impl SubnetPlan {
+    /// Plan subnets of the VPC block `cidr` across `azs` availability zones
+    pub fn new(cidr: &str, azs: u32) -> Result<Self> {
+        Ok(Self {
+            cidr: cidr.parse()?,
+            azs,
+            tiers: vec![],
+        })
+    }
+
+    /// Add a tier of public subnets of size `/prefix`, named `<name>Subnet<az>`
+    pub fn public(mut self, name: impl Into<String>, prefix: u8) -> Self {
+        self.tiers.push(Tier {
+            name: name.into(),
+            prefix,
+            public: true,
+        });
+        self
+    }
+
+    /// Add a tier of private subnets of size `/prefix`, named `<name>Subnet<az>`
+    pub fn private(mut self, name: impl Into<String>, prefix: u8) -> Self {
+        self.tiers.push(Tier {
+            name: name.into(),
+            prefix,
+            public: false,
+        });
+        self
+    }
+
+    /// The subnets of every tier, in the order the tiers were added, then by AZ
+    pub fn subnets(&self) -> Result<Vec<PlannedSubnet>> {
+        let mut order = (0..self.tiers.len()).collect::<Vec<usize>>();
+        order.sort_by_key(|idx| self.tiers[*idx].prefix);
+
+        let mut next = self.cidr.address as u64;
+        let mut cidrs = vec![vec![]; self.tiers.len()];
+        for idx in order {
+            let prefix = self.tiers[idx].prefix;
+            if prefix < self.cidr.prefix || prefix > 32 {
+                return Err(Error::InsufficientAddressSpace(format!(
+                    "/{} subnets don't fit into {}",
+                    prefix, self.cidr
+                )));
+            }
+            for _ in 0..self.azs {
+                let address = u32::try_from(next).map_err(|_| self.exhausted())?;
+                let cidr = Cidr { address, prefix };
+                if cidr.end() > self.cidr.end() {
+                    return Err(self.exhausted());
+                }
+                cidrs[idx].push(cidr);
+                next = cidr.end();
+            }
+        }
+
+        Ok(self
+            .tiers
+            .iter()
+            .zip(cidrs)
+            .flat_map(|(tier, cidrs)| {
+                cidrs
+                    .into_iter()
+                    .zip(0..)
+                    .map(move |(cidr, az)| PlannedSubnet {
+                        logical_id: format!("{}Subnet{}", tier.name, az),
+                        cidr,
+                        az,
+                        public: tier.public,
+                    })
+            })
+            .collect())
+    }
+
+    /// Add the planned `AWS::EC2::Subnet` resources to `template`, in the VPC `vpc_id`,
+    /// ie. `Intrinsic::reference("Vpc")?`
+    pub fn add_to(
+        &self,
+        template: &mut Template,
+        vpc_id: impl Into<Expr<String>>,
+    ) -> Result<Vec<PlannedSubnet>> {
+        let subnets = self.subnets()?;
+        let vpc_id = vpc_id.into();
+        for subnet in subnets.iter() {
+            let mut builder = SubnetBuilder::default();
+            builder
+                .VpcId(vpc_id.clone())
+                .CidrBlock(subnet.cidr.to_string())
+                .AvailabilityZone(Intrinsic::select(subnet.az, Intrinsic::get_azs("")));
+            if subnet.public {
+                builder.MapPublicIpOnLaunch(true);
+            }
+            let resource = builder.build().expect("All required properties are set");
+            template.add_resource(subnet.logical_id.clone(), resource)?;
+        }
+        Ok(subnets)
+    }
+
+    fn exhausted(&self) -> Error {
+        Error::InsufficientAddressSpace(format!(
+            "{} subnets per AZ across {} AZs don't fit into {}",
+            self.tiers.len(),
+            self.azs,
+            self.cidr
+        ))
+    }
+}
@@ -0,0 +1,280 @@
+//! Types of template parameters, so a parameter can only be referenced by properties taking
+//! the kind of value it holds, ie. an `AWS::SSM::Parameter::Value<AWS::EC2::Image::Id>` by an
+//! `ImageId` but not by a `MaxSize`.
+//!
+//! ```
+//! # use caroline::parameter_types::{ImageId, SsmValue};
+//! # use caroline::types::AWS::EC2::Instance::InstanceBuilder;
+//! # use caroline::{Parameter, Template};
+//! let mut template = Template::new();
+//! let mut ami = Parameter::default();
+//! ami.default = Some("/aws/service/ami-amazon-linux-latest/al2023-ami-minimal-arm64".into());
+//! let ami = template
+//!     .add_typed_parameter::<SsmValue<ImageId>>("Ami", ami)
+//!     .unwrap();
+//! let instance = InstanceBuilder::default().ImageId(ami).build().unwrap();
+//! ```
+
+use crate::{Error, Expr, IntoLogicalId, Intrinsic, Parameter, Result, Template};
+use serde_json::Value;
+use std::marker::PhantomData;
+
+/// Type of a template parameter, ie. `String` or `AWS::EC2::VPC::Id`
+pub trait ParameterType {
+    /// The `Type` of the parameter as given in templates
+    fn type_name() -> String;
+}
+
+/// A property value type, ie. `String`, able to take the value of parameters of type `P`
+pub trait FromParameter<P: ParameterType> {}
+
+/// Types of parameters holding a list of values, which properties take one at a time
+pub trait ListParameter: ParameterType {
+    type Item: ParameterType;
+}
+
+/// Types which can be stored in Systems Manager and referenced through `SsmValue`
+pub trait SsmParameterType: ParameterType {}
+
+/// A `String` parameter
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Text;
+
+/// A `Number` parameter, given as an integer or a float
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Number;
+
+/// A `CommaDelimitedList` parameter, a list of strings given as `a,b,c`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CommaDelimitedList;
+
+/// A `List<...>` parameter of numbers or AWS-specific values, ie. `List<AWS::EC2::Subnet::Id>`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct List<P>(PhantomData<P>);
+
+/// An `AWS::SSM::Parameter::Value<...>` parameter, the value of the Systems Manager parameter
+/// whose name is given for the parameter, ie. `SsmValue<ImageId>` for the latest AMI
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SsmValue<P>(PhantomData<P>);
+
+/// An `AWS::SSM::Parameter::Name` parameter, the name of a Systems Manager parameter
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SsmName;
+
+impl ParameterType for Text {
+    fn type_name() -> String {
+        "String".to_string()
+    }
+}
+
+impl ParameterType for Number {
+    fn type_name() -> String {
+        "Number".to_string()
+    }
+}
+
+impl ParameterType for CommaDelimitedList {
+    fn type_name() -> String {
+        "CommaDelimitedList".to_string()
+    }
+}
+
+impl<P: ParameterType> ParameterType for List<P> {
+    fn type_name() -> String {
+        format!("List<{}>", P::type_name())
+    }
+}
+
+impl<P: SsmParameterType> ParameterType for SsmValue<P> {
+    fn type_name() -> String {
+        format!("AWS::SSM::Parameter::Value<{}>", P::type_name())
+    }
+}
+
+impl ParameterType for SsmName {
+    fn type_name() -> String {
+        "AWS::SSM::Parameter::Name".to_string()
+    }
+}
+
+impl ListParameter for CommaDelimitedList {
+    type Item = Text;
+}
+
+impl<P: ParameterType> ListParameter for List<P> {
+    type Item = P;
+}
+
+impl<P: ListParameter + SsmParameterType> ListParameter for SsmValue<P> {
+    type Item = P::Item;
+}
+
+impl SsmParameterType for Text {}
+impl SsmParameterType for CommaDelimitedList {}
+impl<P: SsmParameterType> SsmParameterType for List<P> {}
+
+impl FromParameter<Text> for String {}
+impl FromParameter<SsmName> for String {}
+// `Ref` of a `Number` parameter is a string, which string properties of numbers take
+impl FromParameter<Number> for String {}
+impl FromParameter<Number> for i32 {}
+impl FromParameter<Number> for u32 {}
+impl FromParameter<Number> for u64 {}
+impl FromParameter<Number> for f32 {}
+impl<P: SsmParameterType, T: FromParameter<P>> FromParameter<SsmValue<P>> for T {}
+
+macro_rules! aws_parameter_types {
+    ($($(#[$doc:meta])* $name:ident => $type_name:literal),*) => {
+        $(
+            $(#[$doc])*
+            #[derive(Clone, Copy, Debug, PartialEq, Eq)]
+            pub struct $name;
+
+            impl ParameterType for $name {
+                fn type_name() -> String {
+                    $type_name.to_string()
+                }
+            }
+
+            impl SsmParameterType for $name {}
+            impl FromParameter<$name> for String {}
+        )*
+    };
+}
+
+aws_parameter_types!(
+    /// An Availability Zone, ie. `us-west-2a`
+    AvailabilityZoneName => "AWS::EC2::AvailabilityZone::Name",
+    /// The ID of an Amazon Machine Image, ie. `ami-0ff8a91507f77f867`
+    ImageId => "AWS::EC2::Image::Id",
+    InstanceId => "AWS::EC2::Instance::Id",
+    /// The name of an EC2 key pair
+    KeyPairName => "AWS::EC2::KeyPair::KeyName",
+    SecurityGroupName => "AWS::EC2::SecurityGroup::GroupName",
+    SecurityGroupId => "AWS::EC2::SecurityGroup::Id",
+    SubnetId => "AWS::EC2::Subnet::Id",
+    VolumeId => "AWS::EC2::Volume::Id",
+    VpcId => "AWS::EC2::VPC::Id",
+    HostedZoneId => "AWS::Route53::HostedZone::Id"
+);
+
+/// A `Ref` to a parameter of type `P`, which properties only take if their values fit it
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParameterRef<P> {
+    logical_id: String,
+    type_: PhantomData<P>,
+}
+
+impl<P: ParameterType> ParameterRef<P> {
+    /// Refer to the parameter `logical_id`, see `Template::typed_parameter` to check it's of
+    /// type `P`
+    pub fn new(logical_id: impl IntoLogicalId) -> Result<Self> {
+        Ok(Self {
+            logical_id: logical_id.into_logical_id()?.into_string(),
+            type_: PhantomData,
+        })
+    }
+
+    pub fn logical_id(&self) -> &str {
+        &self.logical_id
+    }
+}
+
+impl<P: ListParameter> ParameterRef<P> {
+    /// `Fn::Select` of the item at `index` of the list
+    pub fn select<T: FromParameter<P::Item>>(&self, index: u32) -> Expr<T> {
+        Intrinsic::select(index, Intrinsic::Ref(self.logical_id.clone())).into()
+    }
+
+    /// `Fn::Join` of the items of the list separated by `delimiter`
+    pub fn join(&self, delimiter: impl Into<String>) -> Expr<String> {
+        let list = Value::from(Intrinsic::Ref(self.logical_id.clone()));
+        Intrinsic::join(delimiter, vec![list]).into()
+    }
+}
+
+impl<P: ParameterType> From<ParameterRef<P>> for Intrinsic {
+    fn from(parameter: ParameterRef<P>) -> Self {
+        Intrinsic::Ref(parameter.logical_id)
+    }
+}
+
+impl<P: ParameterType, T: FromParameter<P>> From<ParameterRef<P>> for Expr<T> {
+    fn from(parameter: ParameterRef<P>) -> Self {
+        Expr::Intrinsic(parameter.into())
+    }
+}
+
+/// A dynamic reference to a `SecureString` of Systems Manager, resolved when the stack is
+/// deployed, ie. `{{resolve:ssm-secure:/db/password:3}}`.
+///
+/// Parameters can't be of type `SecureString`, and only a few properties, like the
+/// `MasterUserPassword` of a database, resolve secure strings.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SecureString {
+    name: String,
+    version: Option<u32>,
+}
+
+impl SecureString {
+    /// The latest version of the parameter `name`
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            version: None,
+        }
+    }
+
+    /// A specific version of the parameter, rather than the latest one
+    pub fn version(mut self, version: u32) -> Self {
+        self.version = Some(version);
+        self
+    }
+}
+
+impl From<SecureString> for Expr<String> {
+    fn from(secure_string: SecureString) -> Self {
+        let reference = match secure_string.version {
+            Some(version) => format!(
+                "{{{{resolve:ssm-secure:{}:{}}}}}",
+                secure_string.name, version
+            ),
+            None => format!("{{{{resolve:ssm-secure:{}}}}}", secure_string.name),
+        };
+        Expr::Literal(reference)
+    }
+}
+
+impl Template {
+    /// Add `parameter` under `logical_id` with the type `P`, returning a reference to it
+    pub fn add_typed_parameter<P: ParameterType>(
+        &mut self,
+        logical_id: impl IntoLogicalId,
+        parameter: Parameter,
+    ) -> Result<ParameterRef<P>> {
+        let logical_id = logical_id.into_logical_id()?;
+        let parameter = Parameter {
+            type_: P::type_name(),
+            ..parameter
+        };
+        self.add_parameter(&logical_id, parameter)?;
+        ParameterRef::new(logical_id)
+    }
+
+    /// A reference to the parameter `logical_id`, ie. of a parsed template, failing with
+    /// `Error::TypeMismatch` unless it's of type `P`
+    pub fn typed_parameter<P: ParameterType>(&self, logical_id: &str) -> Result<ParameterRef<P>> {
+        let (_, parameter) = self
+            .parameters()
+            .find(|(id, _)| *id == logical_id)
+            .ok_or_else(|| Error::UnknownLogicalId(logical_id.to_string()))?;
+        match parameter.type_ == P::type_name() {
+            true => ParameterRef::new(logical_id),
+            false => Err(Error::TypeMismatch {
+                logical_id: logical_id.to_string(),
+                expected: P::type_name(),
+                found: parameter.type_.clone(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,400 @@
+use crate::cycles::strongly_connected;
+use crate::types::AWS::CloudFormation::Stack::StackBuilder;
+use crate::visit::{walk_references, References, Target};
+use crate::{Error, Intrinsic, Output, Parameter, Result, Template};
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+/// Most resources CloudFormation accepts in a single template
+pub const MAX_RESOURCES: usize = 500;
+
+/// A template split by `Template::partition` into a root template creating nested stacks
+#[derive(Clone, Debug, PartialEq)]
+pub struct Partition {
+    /// Parameters, conditions, mappings and outputs of the original template, along with an
+    /// `AWS::CloudFormation::Stack` creating each of the nested templates
+    pub root: Template,
+    /// The nested templates, by the logical ID of their stack, ie. `NestedStack0`
+    pub nested: BTreeMap<String, Template>,
+}
+
+impl Template {
+    /// Split the resources of the template into nested stacks of at most `max_resources`
+    /// resources each, ie. `MAX_RESOURCES`, created by a root template. Templates which
+    /// already fit are returned as the root, without nested stacks.
+    ///
+    /// Resources depending on each other are kept in the same stack where they fit, and always
+    /// are if they form a dependency cycle, failing with `Error::OversizedCycle` if it has more
+    /// than `max_resources` resources. Nested stacks only depend on stacks created before
+    /// them, ie. `NestedStack1` on `NestedStack0`. References to resources of another
+    /// stack are passed as outputs of that stack and parameters of the referencing one, the
+    /// parameters, conditions and mappings a nested template uses are passed or copied to it.
+    /// `template_url` gives the URL a nested template is uploaded to, by its logical ID.
+    ///
+    /// Values are passed between stacks as `String` parameters, so references to attributes
+    /// returning lists have to be joined into a string first.
+    ///
+    /// # Panics
+    ///
+    /// If `max_resources` is zero
+    pub fn partition(
+        &self,
+        max_resources: usize,
+        template_url: impl Fn(&str) -> String,
+    ) -> Result<Partition> {
+        assert!(max_resources > 0, "Nested stacks need room for a resource");
+        if self.len() <= max_resources {
+            return Ok(Partition {
+                root: self.clone(),
+                nested: BTreeMap::new(),
+            });
+        }
+
+        let stacks = assign(&self.dependencies(), max_resources)?;
+        let stack_of = stacks
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, ids)| ids.iter().map(move |id| (id.to_string(), idx)))
+            .collect::<HashMap<String, usize>>();
+        let names = (0..stacks.len()).map(stack_name).collect::<Vec<String>>();
+
+        let mut nested = vec![Template::new(); stacks.len()];
+        let mut exports = vec![BTreeMap::new(); stacks.len()];
+        let mut stack_parameters = vec![BTreeMap::new(); stacks.len()];
+        let mut stack_dependencies = vec![BTreeSet::new(); stacks.len()];
+        let mut import_names = ImportNames::new(self);
+        for (idx, ids) in stacks.iter().enumerate() {
+            let mut imports = Imports::new(self, &stack_of, &mut import_names, Some(idx));
+            for id in ids {
+                let mut entry = self.get(id).expect("Assigned resources exist").clone();
+                imports.rewrite(&mut entry.properties);
+                entry
+                    .depends_on
+                    .retain(|dependency| match stack_of.get(dependency.as_str()) {
+                        Some(stack) if *stack != idx => {
+                            stack_dependencies[idx].insert(*stack);
+                            false
+                        }
+                        _ => true,
+                    });
+                if let Some(condition) = entry.condition.as_ref() {
+                    imports.conditions.insert(condition.clone());
+                }
+                nested[idx].insert_resource(id.to_string(), entry)?;
+            }
+
+            // Conditions may use other conditions, parameters and mappings in turn
+            let mut copied = BTreeSet::new();
+            while let Some(name) = imports.conditions.difference(&copied).next().cloned() {
+                let mut condition = self
+                    .conditions()
+                    .find(|(condition, _)| *condition == name)
+                    .map(|(_, condition)| condition.clone())
+                    .unwrap_or(Value::Null);
+                imports.rewrite(&mut condition);
+                nested[idx].add_condition(name.clone(), condition)?;
+                copied.insert(name);
+            }
+            for (name, mapping) in self.mappings() {
+                if imports.mappings.contains(name) {
+                    nested[idx].add_mapping(name, mapping.clone())?;
+                }
+            }
+            for (name, parameter) in self.parameters() {
+                if imports.parameters.contains(name) {
+                    nested[idx].add_parameter(name, parameter.clone())?;
+                    stack_parameters[idx]
+                        .insert(name.to_string(), pass_parameter(name, &parameter.type_));
+                }
+            }
+            for (name, (stack, value)) in imports.imports {
+                let mut parameter = Parameter::new("String");
+                parameter.description = Some(format!("Output {} of {}", name, names[stack]));
+                nested[idx].add_parameter(name.as_str(), parameter)?;
+                let output = Intrinsic::get_att(&names[stack], format!("Outputs.{}", name))?;
+                stack_parameters[idx].insert(name.clone(), output.into());
+                exports[stack].insert(name, value);
+            }
+        }
+
+        // The root keeps everything but the resources, which its outputs now get from the stacks
+        let mut root = self.clone();
+        for id in stack_of.keys() {
+            root.remove(id);
+        }
+        let mut imports = Imports::new(self, &stack_of, &mut import_names, None);
+        for (logical_id, _) in self.outputs() {
+            let output = root.output_mut(logical_id).expect("Outputs are kept");
+            imports.rewrite(&mut output.value);
+            if let Some(export) = output.export.as_mut() {
+                imports.rewrite(&mut export.name);
+            }
+        }
+        for (name, (stack, value)) in imports.imports {
+            exports[stack].insert(name, value);
+        }
+
+        for (idx, name) in names.iter().enumerate() {
+            for (output, value) in exports[idx].iter() {
+                nested[idx].add_output(output.as_str(), Output::new(value.clone()))?;
+            }
+            let stack = StackBuilder::default()
+                .TemplateURL(template_url(name))
+                .build()
+                .expect("All required properties are set");
+            let entry = root.add_resource(name.as_str(), stack)?;
+            // Set as JSON, as joined list parameters aren't expressible as `Expr<String>`
+            if !stack_parameters[idx].is_empty() {
+                entry.properties["Parameters"] =
+                    Value::Object(stack_parameters[idx].clone().into_iter().collect());
+            }
+            for dependency in stack_dependencies[idx].iter() {
+                entry.depends_on(&names[*dependency])?;
+            }
+        }
+
+        Ok(Partition {
+            root,
+            nested: names.into_iter().zip(nested).collect(),
+        })
+    }
+}
+
+/// Resources grouped into stacks of at most `max` resources, the resources of a stack only
+/// depending on those of the same or earlier stacks. Resources connected by dependencies are
+/// kept together where they fit, larger groups are split in dependency order, but never
+/// within a dependency cycle, failing with `Error::OversizedCycle` if one doesn't fit.
+fn assign<'a>(
+    dependencies: &BTreeMap<&'a str, BTreeSet<&'a str>>,
+    max: usize,
+) -> Result<Vec<Vec<&'a str>>> {
+    // Dependency cycles, and the other resources on their own, in dependency order
+    let units = strongly_connected(dependencies);
+    if let Some(cycle) = units.iter().find(|unit| unit.len() > max) {
+        let mut cycle = cycle
+            .iter()
+            .map(|id| id.to_string())
+            .collect::<Vec<String>>();
+        cycle.sort_unstable();
+        return Err(Error::OversizedCycle(cycle));
+    }
+
+    // Resources connected by dependencies in either direction
+    let mut neighbours: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+    for (id, dependencies) in dependencies.iter() {
+        for dependency in dependencies.iter() {
+            neighbours.entry(id).or_default().insert(dependency);
+            neighbours.entry(dependency).or_default().insert(id);
+        }
+    }
+    let mut component_of: HashMap<&str, usize> = HashMap::new();
+    let mut components: Vec<Vec<&[&str]>> = vec![];
+    for unit in units.iter() {
+        if !component_of.contains_key(unit[0]) {
+            let mut pending = vec![unit[0]];
+            while let Some(id) = pending.pop() {
+                if component_of.insert(id, components.len()).is_none() {
+                    pending.extend(neighbours.get(id).into_iter().flatten());
+                }
+            }
+            components.push(vec![]);
+        }
+        components[component_of[unit[0]]].push(unit);
+    }
+
+    // First fit, among the stacks from the last one a chunk depends on. Full chunks of large
+    // groups always get a stack of their own.
+    let mut stacks: Vec<Vec<&str>> = vec![];
+    let mut stack_of: HashMap<&str, usize> = HashMap::new();
+    for component in components {
+        let mut chunks: Vec<Vec<&str>> = vec![];
+        for unit in component {
+            match chunks.last_mut() {
+                Some(chunk) if chunk.len() + unit.len() <= max => chunk.extend_from_slice(unit),
+                _ => chunks.push(unit.to_vec()),
+            }
+        }
+        for chunk in chunks {
+            let first = chunk
+                .iter()
+                .flat_map(|id| dependencies[id].iter())
+                .filter_map(|dependency| stack_of.get(dependency).copied())
+                .max()
+                .unwrap_or(0);
+            let idx =
+                match (first..stacks.len()).find(|idx| stacks[*idx].len() + chunk.len() <= max) {
+                    Some(idx) => idx,
+                    None => {
+                        stacks.push(vec![]);
+                        stacks.len() - 1
+                    }
+                };
+            for id in chunk {
+                stack_of.insert(id, idx);
+                stacks[idx].push(id);
+            }
+        }
+    }
+    Ok(stacks)
+}
+
+/// Logical ID of the `AWS::CloudFormation::Stack` creating the nested stack `idx`
+fn stack_name(idx: usize) -> String {
+    format!("NestedStack{}", idx)
+}
+
+/// Value a nested stack is given for the parameter `name` of the root, lists are passed as
+/// comma delimited strings
+fn pass_parameter(name: &str, type_: &str) -> Value {
+    match type_.starts_with("List<") || type_ == "CommaDelimitedList" {
+        true => json!({"Fn::Join": [",", {"Ref": name}]}),
+        false => json!({"Ref": name}),
+    }
+}
+
+/// Names of the outputs and parameters references are passed between stacks as, unique
+/// across the partition and distinct from the parameters and resources of the template
+struct ImportNames {
+    names: BTreeMap<(String, Option<String>), String>,
+    taken: BTreeSet<String>,
+}
+
+impl ImportNames {
+    fn new(template: &Template) -> Self {
+        let taken = template
+            .parameters()
+            .map(|(name, _)| name)
+            .chain(template.resources().map(|(id, _)| id))
+            .map(str::to_string)
+            .collect();
+        Self {
+            names: BTreeMap::new(),
+            taken,
+        }
+    }
+
+    /// Name a reference to `attribute` of `logical_id` is passed as. A `Ref` keeps the logical
+    /// ID, attributes are appended to it, ie. `BucketArn` for `Bucket.Arn`, numbered from 2 if
+    /// that's taken, ie. by a resource `BucketArn`.
+    fn name(&mut self, logical_id: &str, attribute: Option<&str>) -> String {
+        let attribute = match attribute {
+            Some(attribute) => attribute,
+            None => return logical_id.to_string(),
+        };
+        let key = (logical_id.to_string(), Some(attribute.to_string()));
+        if let Some(name) = self.names.get(&key) {
+            return name.clone();
+        }
+        let base = format!(
+            "{}{}",
+            logical_id,
+            attribute.replace(|c: char| !c.is_ascii_alphanumeric(), "")
+        );
+        let name = std::iter::once(base.clone())
+            .chain((2..).map(|idx| format!("{}{}", base, idx)))
+            .find(|name| !self.taken.contains(name))
+            .expect("Some numbered name is free");
+        self.taken.insert(name.clone());
+        self.names.insert(key, name.clone());
+        name
+    }
+}
+
+/// What the values of a stack take from the root and other stacks, and how references to
+/// other stacks are rewritten
+struct Imports<'a> {
+    template: &'a Template,
+    stack_of: &'a HashMap<String, usize>,
+    names: &'a mut ImportNames,
+    /// Stack the values belong to, `None` for the root
+    stack: Option<usize>,
+    /// Outputs of other stacks used, as the stack and the value it outputs
+    imports: BTreeMap<String, (usize, Value)>,
+    parameters: BTreeSet<String>,
+    conditions: BTreeSet<String>,
+    mappings: BTreeSet<String>,
+}
+
+impl<'a> Imports<'a> {
+    fn new(
+        template: &'a Template,
+        stack_of: &'a HashMap<String, usize>,
+        names: &'a mut ImportNames,
+        stack: Option<usize>,
+    ) -> Self {
+        Self {
+            template,
+            stack_of,
+            names,
+            stack,
+            imports: BTreeMap::new(),
+            parameters: BTreeSet::new(),
+            conditions: BTreeSet::new(),
+            mappings: BTreeSet::new(),
+        }
+    }
+
+    /// Name of the output a reference to `attribute` of `logical_id` is passed as, if it's a
+    /// resource of another stack. Parameters of the root are noted as used.
+    fn import(&mut self, logical_id: &str, attribute: Option<&str>) -> Option<(usize, String)> {
+        let stack = match self.stack_of.get(logical_id) {
+            Some(stack) if Some(*stack) != self.stack => *stack,
+            Some(_) => return None,
+            None => {
+                if self
+                    .template
+                    .parameters()
+                    .any(|(name, _)| name == logical_id)
+                {
+                    self.parameters.insert(logical_id.to_string());
+                }
+                return None;
+            }
+        };
+        let name = self.names.name(logical_id, attribute);
+        let value = match attribute {
+            Some(attribute) => json!({"Fn::GetAtt": [logical_id, attribute]}),
+            None => json!({"Ref": logical_id}),
+        };
+        self.imports.insert(name.clone(), (stack, value));
+        Some((stack, name))
+    }
+
+    /// How an imported output is referred to, a parameter within nested stacks and the
+    /// output of the stack within the root
+    fn reference(&self, stack: usize, name: &str) -> Target {
+        match self.stack {
+            Some(_) => Target {
+                logical_id: name.to_string(),
+                attribute: None,
+            },
+            None => Target {
+                logical_id: stack_name(stack),
+                attribute: Some(format!("Outputs.{}", name)),
+            },
+        }
+    }
+
+    fn rewrite(&mut self, value: &mut Value) {
+        walk_references(value, self);
+    }
+}
+
+/// References to resources of other stacks are replaced, the parameters, conditions and
+/// mappings used are noted
+impl References for Imports<'_> {
+    fn target(&mut self, logical_id: &str, attribute: Option<&str>) -> Option<Target> {
+        let (stack, name) = self.import(logical_id, attribute)?;
+        Some(self.reference(stack, &name))
+    }
+
+    fn condition(&mut self, name: &str) -> Option<String> {
+        self.conditions.insert(name.to_string());
+        None
+    }
+
+    fn mapping(&mut self, name: &str) -> Option<String> {
+        self.mappings.insert(name.to_string());
+        None
+    }
+}
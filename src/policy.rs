@@ -0,0 +1,183 @@
+use crate::Expr;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::{Map, Value};
+
+/// Version of the policy language written by `PolicyDocument::new`
+const POLICY_VERSION: &str = "2012-10-17";
+
+/// Whether a `Statement` allows or denies its actions
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Effect {
+    Allow,
+    Deny,
+}
+
+/// An IAM policy document, ie. the `InlinePolicy` of an `AWS::SSO::PermissionSet` or the
+/// `PolicyDocument` of an `AWS::SQS::QueuePolicy` or `AWS::SNS::TopicPolicy`
+///
+/// ```
+/// # use caroline::{PolicyDocument, Statement};
+/// let policy = PolicyDocument::new().statement(
+///     Statement::allow()
+///         .action("s3:GetObject")
+///         .resource("arn:aws:s3:::assets/*"),
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PolicyDocument {
+    #[serde(rename = "Version", skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(rename = "Id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "Statement", deserialize_with = "one_or_many")]
+    pub statement: Vec<Statement>,
+}
+
+impl PolicyDocument {
+    /// Create a new `PolicyDocument` of the current policy language version, without statements
+    pub fn new() -> Self {
+        Self {
+            version: Some(POLICY_VERSION.to_string()),
+            id: None,
+            statement: vec![],
+        }
+    }
+
+    pub fn statement(mut self, statement: Statement) -> Self {
+        self.statement.push(statement);
+        self
+    }
+}
+
+impl Default for PolicyDocument {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A statement of a `PolicyDocument`, allowing or denying actions on resources
+///
+/// `Action`, `Resource` and their `Not` counterparts may also be given as a single value
+/// when parsed, but are always written as lists.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Statement {
+    #[serde(rename = "Sid", skip_serializing_if = "Option::is_none")]
+    pub sid: Option<String>,
+    #[serde(rename = "Effect")]
+    pub effect: Effect,
+    /// Who the statement applies to, ie. `{"Service": "lambda.amazonaws.com"}`, only part of
+    /// resource-based policies
+    #[serde(rename = "Principal", skip_serializing_if = "Option::is_none")]
+    pub principal: Option<Value>,
+    #[serde(rename = "NotPrincipal", skip_serializing_if = "Option::is_none")]
+    pub not_principal: Option<Value>,
+    #[serde(
+        rename = "Action",
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "one_or_many"
+    )]
+    pub action: Vec<String>,
+    #[serde(
+        rename = "NotAction",
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "one_or_many"
+    )]
+    pub not_action: Vec<String>,
+    #[serde(
+        rename = "Resource",
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "one_or_many"
+    )]
+    pub resource: Vec<Expr<String>>,
+    #[serde(
+        rename = "NotResource",
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "one_or_many"
+    )]
+    pub not_resource: Vec<Expr<String>>,
+    /// Values of condition keys by operator, ie. `{"Bool": {"aws:SecureTransport": "false"}}`
+    #[serde(rename = "Condition", default, skip_serializing_if = "Map::is_empty")]
+    pub condition: Map<String, Value>,
+}
+
+impl Statement {
+    pub fn new(effect: Effect) -> Self {
+        Self {
+            sid: None,
+            effect,
+            principal: None,
+            not_principal: None,
+            action: vec![],
+            not_action: vec![],
+            resource: vec![],
+            not_resource: vec![],
+            condition: Map::new(),
+        }
+    }
+
+    /// A statement allowing its actions
+    pub fn allow() -> Self {
+        Self::new(Effect::Allow)
+    }
+
+    /// A statement denying its actions
+    pub fn deny() -> Self {
+        Self::new(Effect::Deny)
+    }
+
+    pub fn sid(mut self, sid: impl Into<String>) -> Self {
+        self.sid = Some(sid.into());
+        self
+    }
+
+    pub fn principal(mut self, principal: impl Into<Value>) -> Self {
+        self.principal = Some(principal.into());
+        self
+    }
+
+    /// Add an action, ie. `s3:GetObject` or `s3:*`
+    pub fn action(mut self, action: impl Into<String>) -> Self {
+        self.action.push(action.into());
+        self
+    }
+
+    /// Add a resource, ie. an ARN or an `Fn::GetAtt` of one
+    pub fn resource(mut self, resource: impl Into<Expr<String>>) -> Self {
+        self.resource.push(resource.into());
+        self
+    }
+
+    /// Require the condition key `key` to match `value` by `operator`, ie. `StringEquals`
+    pub fn condition(mut self, operator: &str, key: &str, value: impl Into<Value>) -> Self {
+        let keys = self
+            .condition
+            .entry(operator)
+            .or_insert_with(|| Value::Object(Map::new()));
+        if let Value::Object(keys) = keys {
+            keys.insert(key.to_string(), value.into());
+        }
+        self
+    }
+}
+
+/// Policy elements may be given as either a single value or a list of them
+fn one_or_many<'de, D, T>(deserializer: D) -> std::result::Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        Many(Vec<T>),
+        One(T),
+    }
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::Many(values) => values,
+        OneOrMany::One(value) => vec![value],
+    })
+}
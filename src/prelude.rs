@@ -0,0 +1,26 @@
+//! The types most templates need, along with a module for each enabled service re-exporting
+//! its resources and builders under shorter names.
+//!
+//! Resources are found directly within their service, ie. `ec2::LaunchTemplate`, and their
+//! property types within a module named after the resource, with the resource name dropped
+//! from the front, ie. `ec2::launch_template::Data` for `LaunchTemplateData`. Property types
+//! are also available under their full name, `ec2::launch_template::LaunchTemplateData`.
+//!
+//! ```
+//! use caroline::prelude::*;
+//!
+//! let bucket = s3::BucketBuilder::default()
+//!     .BucketName("assets")
+//!     .build()
+//!     .unwrap();
+//! let mut template = Template::new();
+//! template.add_resource("Assets", bucket).unwrap();
+//! ```
+
+// Set by the build script when the features enable at least one service
+#[cfg(caroline_services)]
+pub use crate::aws::preludes::*;
+pub use crate::types::Tag::{Tag, TagBuilder};
+pub use crate::{
+    DebugUnredacted, Expr, Intrinsic, LogicalId, Output, Parameter, Resource, Template,
+};
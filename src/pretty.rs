@@ -0,0 +1,293 @@
+use crate::{Resource, Template};
+use serde_json::{Map, Value};
+use std::collections::BTreeSet;
+use std::fmt;
+
+/// Renders templates and resources as an aligned key/value tree for previews and debugging.
+///
+/// Long strings are truncated and intrinsic functions are shown in their YAML short form,
+/// ie. `!Ref MyVpc`, with references to logical IDs the template doesn't define flagged.
+/// Use `{:#}` when formatting to show strings in full.
+#[derive(Clone, Debug)]
+pub struct PrettyPrinter {
+    max_string_len: usize,
+    indent: usize,
+}
+
+impl Default for PrettyPrinter {
+    fn default() -> Self {
+        Self {
+            max_string_len: 60,
+            indent: 2,
+        }
+    }
+}
+
+/// Logical IDs references can resolve to, `None` when rendering outside of a template
+type Known<'a> = Option<&'a BTreeSet<&'a str>>;
+
+impl PrettyPrinter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Strings longer than this many characters are truncated
+    pub fn max_string_len(mut self, max_string_len: usize) -> Self {
+        self.max_string_len = max_string_len;
+        self
+    }
+
+    /// Number of spaces nested entries are indented by
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// Render a whole template
+    pub fn template(&self, template: &Template) -> String {
+        let known = template
+            .parameters()
+            .map(|(id, _)| id)
+            .chain(template.resources().map(|(id, _)| id))
+            .collect::<BTreeSet<&str>>();
+        let value = serde_json::to_value(template).expect("Templates are always serializable");
+        let root = value.as_object().cloned().unwrap_or_default();
+        let width = key_width(&root);
+
+        let mut lines = vec![];
+        for (key, section) in root.iter() {
+            if key != "Resources" {
+                lines.extend(self.entry_lines(key, section, width, Some(&known)));
+                continue;
+            }
+            lines.push(key.clone());
+            let resources = section.as_object().cloned().unwrap_or_default();
+            for (logical_id, resource) in resources.iter() {
+                let type_ = resource["Type"].as_str().unwrap_or_default();
+                lines.push(self.indented(format!("{}  {}", logical_id, type_), 1));
+                lines.extend(
+                    self.resource_lines(resource, Some(&known))
+                        .into_iter()
+                        .map(|line| self.indented(line, 2)),
+                );
+            }
+        }
+        lines.join("\n")
+    }
+
+    /// Render a single resource, headed by its type
+    pub fn resource<R: Resource>(&self, resource: &R) -> String {
+        let properties = serde_json::to_value(resource).expect("Resources are always serializable");
+        let mut lines = vec![R::TYPE.to_string()];
+        if let Value::Object(map) = &properties {
+            lines.extend(
+                self.object_lines(map, None)
+                    .into_iter()
+                    .map(|line| self.indented(line, 1)),
+            );
+        }
+        lines.join("\n")
+    }
+
+    /// Properties of a resource followed by its other attributes, ie. `DependsOn`
+    fn resource_lines(&self, resource: &Value, known: Known) -> Vec<String> {
+        let mut lines = vec![];
+        if let Some(Value::Object(properties)) = resource.get("Properties") {
+            lines.extend(self.object_lines(properties, known));
+        }
+        let attributes = resource
+            .as_object()
+            .map(|map| {
+                map.iter()
+                    .filter(|(key, _)| *key != "Type" && *key != "Properties")
+                    .map(|(key, value)| (key.clone(), value.clone()))
+                    .collect::<Map<String, Value>>()
+            })
+            .unwrap_or_default();
+        lines.extend(self.object_lines(&attributes, known));
+        lines
+    }
+
+    fn object_lines(&self, map: &Map<String, Value>, known: Known) -> Vec<String> {
+        let width = key_width(map);
+        map.iter()
+            .flat_map(|(key, value)| self.entry_lines(key, value, width, known))
+            .collect()
+    }
+
+    fn entry_lines(&self, key: &str, value: &Value, width: usize, known: Known) -> Vec<String> {
+        match self.block_lines(value, known) {
+            Some(children) => std::iter::once(key.to_string())
+                .chain(children.into_iter().map(|line| self.indented(line, 1)))
+                .collect(),
+            None => vec![format!(
+                "{:width$}  {}",
+                key,
+                self.scalar(value, known),
+                width = width
+            )],
+        }
+    }
+
+    /// Lines of a value which needs nesting, `None` if it fits on a single line
+    fn block_lines(&self, value: &Value, known: Known) -> Option<Vec<String>> {
+        match value {
+            Value::Object(map) if !map.is_empty() && intrinsic(map).is_none() => {
+                Some(self.object_lines(map, known))
+            }
+            Value::Array(items)
+                if items
+                    .iter()
+                    .any(|item| self.block_lines(item, known).is_some()) =>
+            {
+                Some(
+                    items
+                        .iter()
+                        .flat_map(|item| self.list_item_lines(item, known))
+                        .collect(),
+                )
+            }
+            _ => None,
+        }
+    }
+
+    fn list_item_lines(&self, item: &Value, known: Known) -> Vec<String> {
+        match self.block_lines(item, known) {
+            Some(lines) => lines
+                .into_iter()
+                .enumerate()
+                .map(|(idx, line)| match idx {
+                    0 => format!("- {}", line),
+                    _ => format!("  {}", line),
+                })
+                .collect(),
+            None => vec![format!("- {}", self.scalar(item, known))],
+        }
+    }
+
+    /// A value on the right hand side of a key, strings are shown unquoted
+    fn scalar(&self, value: &Value, known: Known) -> String {
+        match value {
+            Value::String(s) if !s.is_empty() => self.truncate(s),
+            _ => self.inline(value, known),
+        }
+    }
+
+    /// A value nested within a single line, ie. a list item or intrinsic function argument
+    fn inline(&self, value: &Value, known: Known) -> String {
+        match value {
+            Value::String(s) => format!("{:?}", self.truncate(s)),
+            Value::Array(items) => format!(
+                "[{}]",
+                items
+                    .iter()
+                    .map(|item| self.inline(item, known))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
+            Value::Object(map) => match intrinsic(map) {
+                Some((name, args)) => self.intrinsic(name, args, known),
+                None => format!(
+                    "{{{}}}",
+                    map.iter()
+                        .map(|(key, value)| format!("{}: {}", key, self.inline(value, known)))
+                        .collect::<Vec<String>>()
+                        .join(", ")
+                ),
+            },
+            other => other.to_string(),
+        }
+    }
+
+    fn intrinsic(&self, name: &str, args: &Value, known: Known) -> String {
+        match (name, args) {
+            ("Ref", Value::String(logical_id)) => {
+                format!("!Ref {}{}", logical_id, unresolved(logical_id, known))
+            }
+            ("Fn::GetAtt", Value::Array(parts)) if parts.len() == 2 => {
+                let logical_id = parts[0].as_str().unwrap_or_default();
+                let attribute = match &parts[1] {
+                    Value::String(attribute) => attribute.clone(),
+                    other => self.inline(other, known),
+                };
+                format!(
+                    "!GetAtt {}.{}{}",
+                    logical_id,
+                    attribute,
+                    unresolved(logical_id, known)
+                )
+            }
+            ("Fn::GetAtt", Value::String(path)) => {
+                let logical_id = path.split('.').next().unwrap_or_default();
+                format!("!GetAtt {}{}", path, unresolved(logical_id, known))
+            }
+            _ => format!(
+                "!{} {}",
+                name.trim_start_matches("Fn::"),
+                self.inline(args, known)
+            ),
+        }
+    }
+
+    fn truncate(&self, s: &str) -> String {
+        let first_line = s.lines().next().unwrap_or_default();
+        if first_line.chars().count() > self.max_string_len {
+            let kept = first_line
+                .chars()
+                .take(self.max_string_len.saturating_sub(1))
+                .collect::<String>();
+            format!("{}…", kept)
+        } else if first_line.len() < s.trim_end().len() {
+            format!("{} …", first_line)
+        } else {
+            s.to_string()
+        }
+    }
+
+    fn indented(&self, line: String, depth: usize) -> String {
+        format!("{}{}", " ".repeat(self.indent * depth), line)
+    }
+}
+
+/// Name and arguments of an intrinsic function, given an object like `{"Ref": "MyVpc"}`
+fn intrinsic(map: &Map<String, Value>) -> Option<(&str, &Value)> {
+    match map.iter().next() {
+        Some((name, args)) if map.len() == 1 && (name == "Ref" || name.starts_with("Fn::")) => {
+            Some((name.as_str(), args))
+        }
+        _ => None,
+    }
+}
+
+/// Marker for references to logical IDs the template doesn't define, pseudo parameters aside
+fn unresolved(logical_id: &str, known: Known) -> &'static str {
+    match known {
+        Some(known) if !logical_id.starts_with("AWS::") && !known.contains(logical_id) => {
+            " <unresolved>"
+        }
+        _ => "",
+    }
+}
+
+fn key_width(map: &Map<String, Value>) -> usize {
+    map.keys().map(|key| key.chars().count()).max().unwrap_or(0)
+}
+
+/// The printer used by `Display` implementations, `{:#}` disables truncation
+fn printer(f: &fmt::Formatter) -> PrettyPrinter {
+    match f.alternate() {
+        true => PrettyPrinter::new().max_string_len(usize::MAX),
+        false => PrettyPrinter::new(),
+    }
+}
+
+impl fmt::Display for Template {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&printer(f).template(self))
+    }
+}
+
+/// `Display` of generated resource types
+pub(crate) fn fmt_resource<R: Resource>(resource: &R, f: &mut fmt::Formatter) -> fmt::Result {
+    f.write_str(&printer(f).resource(resource))
+}
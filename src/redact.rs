@@ -0,0 +1,83 @@
+use std::cell::Cell;
+use std::fmt;
+
+thread_local! {
+    /// Depth of `Unredacted` values being formatted on this thread
+    static UNREDACTED: Cell<usize> = const { Cell::new(0) };
+}
+
+// The items below are only used by the generated `Debug` of types with sensitive properties,
+// which the enabled services may not have any of, ie. with only the `s3` feature.
+
+/// Whether sensitive values are redacted, unless formatted through `debug_unredacted`
+#[allow(dead_code)]
+pub(crate) fn redacting() -> bool {
+    UNREDACTED.with(|depth| depth.get() == 0)
+}
+
+/// Stand-in for a sensitive value within `Debug` output
+#[allow(dead_code)]
+struct Redacted;
+
+impl fmt::Debug for Redacted {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("<redacted>")
+    }
+}
+
+/// `Debug` of a required sensitive field
+#[allow(dead_code)]
+pub(crate) fn sensitive<'a, T: fmt::Debug + 'a>(value: &'a T) -> &'a dyn fmt::Debug {
+    match redacting() {
+        true => &Redacted,
+        false => value,
+    }
+}
+
+/// `Debug` of an optional sensitive field, showing whether it's set
+#[allow(dead_code)]
+pub(crate) fn sensitive_option<'a, T: fmt::Debug + 'a>(
+    value: &'a Option<T>,
+) -> Option<&'a dyn fmt::Debug> {
+    value.as_ref().map(|value| sensitive(value))
+}
+
+/// Formats a value with `Debug` including its sensitive properties, see `debug_unredacted`
+pub struct Unredacted<'a, T: ?Sized>(&'a T);
+
+impl<T: fmt::Debug + ?Sized> fmt::Debug for Unredacted<'_, T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Restored by the guard even if formatting panics
+        struct Guard;
+        impl Drop for Guard {
+            fn drop(&mut self) {
+                UNREDACTED.with(|depth| depth.set(depth.get() - 1));
+            }
+        }
+        UNREDACTED.with(|depth| depth.set(depth.get() + 1));
+        let _guard = Guard;
+        self.0.fmt(f)
+    }
+}
+
+/// The `Debug` output of generated types shows sensitive properties, ie. passwords, as
+/// `<redacted>`, so they can be logged safely. `debug_unredacted` opts out of it.
+///
+/// ```
+/// # use caroline::types::AWS::RDS::DBInstance::DBInstanceBuilder;
+/// # use caroline::DebugUnredacted;
+/// let database = DBInstanceBuilder::default()
+///     .DBInstanceClass("db.t3.micro")
+///     .MasterUserPassword("hunter2")
+///     .build()
+///     .unwrap();
+/// assert!(!format!("{:?}", database).contains("hunter2"));
+/// assert!(format!("{:?}", database.debug_unredacted()).contains("hunter2"));
+/// ```
+pub trait DebugUnredacted: fmt::Debug {
+    fn debug_unredacted(&self) -> Unredacted<'_, Self> {
+        Unredacted(self)
+    }
+}
+
+impl<T: fmt::Debug + ?Sized> DebugUnredacted for T {}
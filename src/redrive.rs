@@ -0,0 +1,85 @@
+use crate::Expr;
+use serde::{Deserialize, Serialize};
+
+/// Where an `AWS::SQS::Queue` moves messages it failed to process, or an
+/// `AWS::SNS::Subscription` those it failed to deliver, their `RedrivePolicy`
+///
+/// ```
+/// # use caroline::{Intrinsic, RedrivePolicy};
+/// let policy = RedrivePolicy::new(Intrinsic::get_att("OrdersDeadLetters", "Arn").unwrap(), 5);
+/// ```
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedrivePolicy {
+    /// ARN of the dead-letter queue
+    pub dead_letter_target_arn: Expr<String>,
+    /// Number of times a message is received before it's moved to the dead-letter queue,
+    /// required by SQS queues, not taken by SNS subscriptions
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_receive_count: Option<u32>,
+}
+
+impl RedrivePolicy {
+    /// Policy of an SQS queue
+    pub fn new(dead_letter_target_arn: impl Into<Expr<String>>, max_receive_count: u32) -> Self {
+        Self {
+            dead_letter_target_arn: dead_letter_target_arn.into(),
+            max_receive_count: Some(max_receive_count),
+        }
+    }
+
+    /// Policy of an SNS subscription
+    pub fn dead_letter_target(dead_letter_target_arn: impl Into<Expr<String>>) -> Self {
+        Self {
+            dead_letter_target_arn: dead_letter_target_arn.into(),
+            max_receive_count: None,
+        }
+    }
+}
+
+/// Which queues may use an `AWS::SQS::Queue` as their dead-letter queue
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum RedrivePermission {
+    AllowAll,
+    DenyAll,
+    /// Only the `source_queue_arns` of the `RedriveAllowPolicy`
+    ByQueue,
+}
+
+/// The `RedriveAllowPolicy` of an `AWS::SQS::Queue`, restricting which source queues may
+/// move their failed messages to it
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RedriveAllowPolicy {
+    pub redrive_permission: RedrivePermission,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub source_queue_arns: Vec<Expr<String>>,
+}
+
+impl RedriveAllowPolicy {
+    /// Any queue of the account may use this queue as dead-letter queue
+    pub fn allow_all() -> Self {
+        Self {
+            redrive_permission: RedrivePermission::AllowAll,
+            source_queue_arns: vec![],
+        }
+    }
+
+    /// No queue may use this queue as dead-letter queue
+    pub fn deny_all() -> Self {
+        Self {
+            redrive_permission: RedrivePermission::DenyAll,
+            source_queue_arns: vec![],
+        }
+    }
+
+    /// Only the queues of `source_queue_arns` may use this queue as dead-letter queue, at most
+    /// 10 of them
+    pub fn by_queue<T: Into<Expr<String>>>(source_queue_arns: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            redrive_permission: RedrivePermission::ByQueue,
+            source_queue_arns: source_queue_arns.into_iter().map(Into::into).collect(),
+        }
+    }
+}
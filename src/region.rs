@@ -0,0 +1,95 @@
+use crate::{Error, Region, Result};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+impl FromStr for Region {
+    type Err = Error;
+
+    fn from_str(code: &str) -> Result<Self> {
+        Region::ALL
+            .iter()
+            .copied()
+            .find(|region| region.code() == code)
+            .ok_or_else(|| Error::InvalidRegion(code.to_string()))
+    }
+}
+
+impl fmt::Display for Region {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.code())
+    }
+}
+
+/// An availability zone, named after its region followed by a letter, ie. `eu-west-1a`
+///
+/// Zone letters are mapped to physical zones per account, so only the format is checked.
+/// Local and Wavelength Zones, ie. `us-west-2-lax-1a`, aren't availability zones in this sense.
+///
+/// ```
+/// # use caroline::{AvailabilityZone, Region};
+/// let zone: AvailabilityZone = "eu-west-1a".parse().unwrap();
+/// assert_eq!(zone, AvailabilityZone::new(Region::EuWest1, 'a').unwrap());
+/// assert!("eu-west-1".parse::<AvailabilityZone>().is_err());
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct AvailabilityZone {
+    region: Region,
+    zone: char,
+}
+
+impl AvailabilityZone {
+    /// The zone `zone` of `region`, failing unless it's a lowercase ASCII letter
+    pub fn new(region: Region, zone: char) -> Result<Self> {
+        match zone.is_ascii_lowercase() {
+            true => Ok(Self { region, zone }),
+            false => Err(Error::InvalidAvailabilityZone(format!(
+                "{}{}",
+                region.code(),
+                zone
+            ))),
+        }
+    }
+
+    pub fn region(&self) -> Region {
+        self.region
+    }
+
+    /// Letter of the zone within its region, ie. `a`
+    pub fn zone(&self) -> char {
+        self.zone
+    }
+}
+
+impl FromStr for AvailabilityZone {
+    type Err = Error;
+
+    fn from_str(name: &str) -> Result<Self> {
+        let invalid = || Error::InvalidAvailabilityZone(name.to_string());
+        let zone = name.chars().last().ok_or_else(invalid)?;
+        let region = name[..name.len() - zone.len_utf8()]
+            .parse::<Region>()
+            .map_err(|_| invalid())?;
+        Self::new(region, zone).map_err(|_| invalid())
+    }
+}
+
+impl fmt::Display for AvailabilityZone {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}{}", self.region, self.zone)
+    }
+}
+
+impl Serialize for AvailabilityZone {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AvailabilityZone {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
@@ -0,0 +1,239 @@
+//! Runtime lookup of the resource types compiled into the crate and their properties.
+
+use crate::visit::is_intrinsic;
+use crate::{Resource, Template};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+use std::sync::OnceLock;
+
+/// How CloudFormation updates a resource when a property changes, as given by the resource
+/// specification
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum UpdateType {
+    /// Updated in place, without interrupting the resource
+    Mutable,
+    /// Replaced by a new physical resource
+    Immutable,
+    /// Depends on the change, some lead to a replacement
+    Conditional,
+}
+
+/// Metadata of a property of a generated type, see the `PROPERTIES` constant of each type
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PropertyInfo {
+    /// Name of the property as used in templates, ie. `CidrBlock`
+    pub name: &'static str,
+    pub required: bool,
+    pub update_type: UpdateType,
+    pub kind: PropertyKind,
+    /// Whether the value is a secret, ie. a password, which shouldn't be given in plain text
+    pub sensitive: bool,
+}
+
+/// Properties of a nested property type, resolved lazily as property types may be recursive
+pub type Properties = fn() -> &'static [PropertyInfo];
+
+/// Shape of a property's value, with the properties of the type it contains, if any
+#[derive(Clone, Copy, Debug)]
+pub enum PropertyKind {
+    /// A string, number, boolean or arbitrary JSON
+    Primitive,
+    Struct(Properties),
+    List(Option<Properties>),
+    Map(Option<Properties>),
+}
+
+impl PropertyKind {
+    /// Properties of the contained property type, `None` for primitives and lists or maps of them
+    pub fn properties(&self) -> Option<&'static [PropertyInfo]> {
+        match self {
+            PropertyKind::Struct(properties) => Some(properties()),
+            PropertyKind::List(properties) | PropertyKind::Map(properties) => {
+                properties.map(|properties| properties())
+            }
+            PropertyKind::Primitive => None,
+        }
+    }
+}
+
+// Function pointers don't compare reliably, the properties they resolve to do
+impl PartialEq for PropertyKind {
+    fn eq(&self, other: &Self) -> bool {
+        let same_properties = match (self.properties(), other.properties()) {
+            (Some(a), Some(b)) => std::ptr::eq(a, b),
+            (a, b) => a.is_none() && b.is_none(),
+        };
+        std::mem::discriminant(self) == std::mem::discriminant(other) && same_properties
+    }
+}
+
+impl Eq for PropertyKind {}
+
+/// Properties of a resource parsed into its generated type and serialized back, failing unless
+/// they fit it
+pub(crate) type Typed = fn(Value) -> serde_json::Result<Value>;
+
+pub(crate) fn typed<R: Resource>(properties: Value) -> serde_json::Result<Value> {
+    serde_json::to_value(serde_json::from_value::<R>(properties)?)
+}
+
+/// A resource type of the generated code, with its properties, import identifiers and how to
+/// parse its properties into its type
+pub(crate) type Registration = (
+    &'static str,
+    &'static [PropertyInfo],
+    &'static [&'static str],
+    Typed,
+);
+
+type Registry = BTreeMap<&'static str, (&'static [PropertyInfo], &'static [&'static str], Typed)>;
+
+fn registry() -> &'static Registry {
+    static REGISTRY: OnceLock<Registry> = OnceLock::new();
+    REGISTRY.get_or_init(|| {
+        crate::aws::registered_resources()
+            .into_iter()
+            .map(|(type_name, properties, identifiers, typed)| {
+                (type_name, (properties, identifiers, typed))
+            })
+            .collect()
+    })
+}
+
+/// Resource types of the enabled features, ie. `AWS::EC2::VPC`, in alphabetical order
+pub fn resource_types() -> impl Iterator<Item = &'static str> {
+    registry().keys().copied()
+}
+
+/// Properties of `resource_type`, `None` if it isn't a known resource type
+pub fn properties(resource_type: &str) -> Option<&'static [PropertyInfo]> {
+    registry()
+        .get(resource_type)
+        .map(|(properties, _, _)| *properties)
+}
+
+/// The property `name` of `resource_type`
+pub fn property(resource_type: &str, name: &str) -> Option<&'static PropertyInfo> {
+    properties(resource_type)?
+        .iter()
+        .find(|property| property.name == name)
+}
+
+/// Names identifying an existing resource of `resource_type` when importing it into a stack,
+/// ie. `BucketName`, `None` if it isn't a known resource type or can't be imported
+pub fn identifiers(resource_type: &str) -> Option<&'static [&'static str]> {
+    registry()
+        .get(resource_type)
+        .map(|(_, identifiers, _)| *identifiers)
+        .filter(|identifiers| !identifiers.is_empty())
+}
+
+/// `properties` of a `resource_type` parsed into its generated type and serialized back, `None`
+/// if it isn't a known resource type
+pub(crate) fn typed_properties(
+    resource_type: &str,
+    properties: Value,
+) -> Option<serde_json::Result<Value>> {
+    registry()
+        .get(resource_type)
+        .map(|(_, _, typed)| typed(properties))
+}
+
+/// Call `f` with the path, ie. `Database.MasterUserPassword`, value and `PropertyInfo` of every
+/// property of the resources of known types, `None` for properties the registry doesn't know
+/// of. The property types within a value are descended into if `f` returns `true`, values
+/// computed by intrinsic functions aren't.
+pub(crate) fn walk_properties(
+    template: &Template,
+    f: &mut dyn FnMut(&str, &Value, Option<&'static PropertyInfo>) -> bool,
+) {
+    for (logical_id, entry) in template.resources() {
+        if let (Some(properties), Value::Object(values)) =
+            (properties(&entry.type_), &entry.properties)
+        {
+            walk_values(logical_id, values, properties, f);
+        }
+    }
+}
+
+fn walk_values(
+    path: &str,
+    values: &Map<String, Value>,
+    properties: &'static [PropertyInfo],
+    f: &mut dyn FnMut(&str, &Value, Option<&'static PropertyInfo>) -> bool,
+) {
+    for (name, value) in values.iter() {
+        let path = format!("{}.{}", path, name);
+        let property = properties.iter().find(|property| property.name == name);
+        let properties = match property {
+            Some(property) if f(&path, value, Some(property)) => property.kind.properties(),
+            Some(_) => None,
+            None => {
+                f(&path, value, None);
+                None
+            }
+        };
+        let (kind, properties) = match (property, properties) {
+            (Some(property), Some(properties)) => (property.kind, properties),
+            _ => continue,
+        };
+        let mut nested = |path: String, item: &Value| {
+            if let Value::Object(values) = item {
+                if !is_intrinsic(values) {
+                    walk_values(&path, values, properties, f);
+                }
+            }
+        };
+        match (kind, value) {
+            (PropertyKind::Struct(_), _) => nested(path, value),
+            (PropertyKind::List(_), Value::Array(items)) => {
+                for (idx, item) in items.iter().enumerate() {
+                    nested(format!("{}[{}]", path, idx), item);
+                }
+            }
+            (PropertyKind::Map(_), Value::Object(entries)) if !is_intrinsic(entries) => {
+                for (key, item) in entries.iter() {
+                    nested(format!("{}.{}", path, key), item);
+                }
+            }
+            _ => (),
+        }
+    }
+}
+
+/// How much of a service the generated types cover, also documented on its module within
+/// `crate::types`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ServiceCoverage {
+    /// Namespace of the service, ie. `AWS::EC2`
+    pub service: &'static str,
+    pub resources: usize,
+    pub property_types: usize,
+    /// Properties of the resources, leaving out those of their property types
+    pub properties: usize,
+    /// Types and properties the crate adds on top of the bundled resource specification,
+    /// ie. `AWS::SQS::Queue.RedriveAllowPolicy`
+    pub added: &'static [&'static str],
+    /// Properties of arbitrary JSON the generated types don't model, kept as an untyped
+    /// `serde_json::Value`, ie. `AWS::IAM::Role.AssumeRolePolicyDocument`
+    pub untyped: &'static [&'static str],
+    /// Types and properties of the bundled resource specification the generated types lack
+    pub missing: &'static [&'static str],
+}
+
+fn coverage() -> &'static [ServiceCoverage] {
+    static COVERAGE: OnceLock<Vec<ServiceCoverage>> = OnceLock::new();
+    COVERAGE.get_or_init(crate::aws::registered_services)
+}
+
+/// Coverage of the services of the enabled features, in alphabetical order
+pub fn services() -> impl Iterator<Item = &'static ServiceCoverage> {
+    coverage().iter()
+}
+
+/// Coverage of `service`, ie. `AWS::EC2`, `None` if none of its types are compiled in
+pub fn service(service: &str) -> Option<&'static ServiceCoverage> {
+    coverage()
+        .iter()
+        .find(|coverage| coverage.service == service)
+}
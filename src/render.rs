@@ -0,0 +1,100 @@
+use crate::template::FORMAT_VERSION;
+use crate::{registry, Error, ResourceEntry, Result, Template};
+use serde_json::{Map, Value};
+
+/// Render the template of a declarative `spec` as JSON, so callers outside of Rust, ie.
+/// JavaScript or Python through WebAssembly, build templates with the checks of the crate.
+///
+/// The spec is a template whose resources give their properties under their type name, next
+/// to attributes such as `DependsOn`. Its other sections are taken as they are.
+///
+/// ```
+/// let template = caroline::render(
+///     r#"{
+///         "Description": "Static assets",
+///         "Resources": {
+///             "Assets": {"AWS::S3::Bucket": {"BucketName": "assets"}},
+///             "Logs": {"AWS::S3::Bucket": {}, "DeletionPolicy": "Retain"}
+///         }
+///     }"#,
+/// )
+/// .unwrap();
+/// assert!(template.contains(r#""Type": "AWS::S3::Bucket""#));
+/// assert!(caroline::render(r#"{"Resources": {"Assets": {"AWS::S3::Bukcet": {}}}}"#).is_err());
+/// ```
+///
+/// Properties have to fit the generated type of their resource, and the template mustn't have
+/// unknown properties or issues found by `Template::validate`, failing with
+/// `Error::InvalidSpec` listing all of them otherwise.
+pub fn render(spec: &str) -> Result<String> {
+    let mut spec: Map<String, Value> = serde_json::from_str(spec)?;
+    let resources = match spec.remove("Resources") {
+        Some(Value::Object(resources)) => resources,
+        None => Map::new(),
+        Some(_) => {
+            return Err(Error::InvalidSpec(vec![
+                "Resources: expected an object".to_string()
+            ]))
+        }
+    };
+    spec.entry("AWSTemplateFormatVersion")
+        .or_insert_with(|| FORMAT_VERSION.into());
+    let mut template: Template = serde_json::from_value(Value::Object(spec))?;
+
+    let mut problems = vec![];
+    for (logical_id, resource) in resources {
+        match entry(resource) {
+            Ok(entry) => {
+                if let Err(err) = template.insert_resource(logical_id.as_str(), entry) {
+                    problems.push(err.to_string());
+                }
+            }
+            Err(problem) => problems.push(format!("{}: {}", logical_id, problem)),
+        }
+    }
+    problems.extend(
+        template
+            .unknown_properties()
+            .into_iter()
+            .map(|path| format!("{}: unknown property", path)),
+    );
+    problems.extend(template.validate().iter().map(|issue| issue.to_string()));
+    match problems.is_empty() {
+        true => template.to_json(),
+        false => Err(Error::InvalidSpec(problems)),
+    }
+}
+
+/// Entry of a resource of the spec, with its properties parsed into its generated type
+fn entry(resource: Value) -> std::result::Result<ResourceEntry, String> {
+    let mut attributes = match resource {
+        Value::Object(attributes) => attributes,
+        _ => return Err("expected an object".to_string()),
+    };
+    // Attributes are plain names, type names are namespaced
+    let type_names = attributes
+        .keys()
+        .filter(|key| key.contains("::"))
+        .cloned()
+        .collect::<Vec<String>>();
+    let type_name = match type_names.as_slice() {
+        [type_name] => type_name.clone(),
+        [] => return Err("no resource type".to_string()),
+        _ => {
+            return Err(format!(
+                "more than one resource type: {}",
+                type_names.join(", ")
+            ))
+        }
+    };
+    let properties = match attributes.remove(&type_name) {
+        Some(Value::Null) | None => Value::Object(Map::new()),
+        Some(properties) => properties,
+    };
+    let properties = registry::typed_properties(&type_name, properties)
+        .ok_or_else(|| format!("unknown resource type {}", type_name))?
+        .map_err(|err| err.to_string())?;
+    attributes.insert("Type".to_string(), Value::String(type_name));
+    attributes.insert("Properties".to_string(), properties);
+    serde_json::from_value(Value::Object(attributes)).map_err(|err| err.to_string())
+}
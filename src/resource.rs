@@ -0,0 +1,113 @@
+use crate::{registry, Error, IntoLogicalId, Intrinsic, Metadata, ResourceEntry, Result, Template};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+use std::marker::PhantomData;
+
+/// Implemented by every generated resource type, ie. `AWS::EC2::VPC`
+pub trait Resource: Serialize + DeserializeOwned {
+    /// The CloudFormation type name of this resource
+    const TYPE: &'static str;
+
+    /// Names identifying an existing resource of this type when importing it into a stack,
+    /// ie. `BucketName`, empty if the type can't be imported
+    const IDENTIFIERS: &'static [&'static str] = &[];
+}
+
+/// A resource of a type the crate doesn't model, ie. the third-party registry type
+/// `MongoDB::Atlas::Cluster` or a custom resource, with its properties kept as given.
+///
+/// Templates keep these like any other resource, so they're serialized as parsed and their
+/// references are validated, see `Template::unknown_resources`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnknownResource {
+    /// The CloudFormation type name, ie. `MongoDB::Atlas::Cluster`
+    pub type_name: String,
+    pub properties: Value,
+}
+
+impl UnknownResource {
+    pub fn new(type_name: impl Into<String>, properties: impl Into<Value>) -> Self {
+        Self {
+            type_name: type_name.into(),
+            properties: properties.into(),
+        }
+    }
+
+    /// Whether `type_name` is a resource type of the enabled features
+    pub fn is_known(type_name: &str) -> bool {
+        registry::properties(type_name).is_some()
+    }
+}
+
+impl From<UnknownResource> for ResourceEntry {
+    fn from(resource: UnknownResource) -> Self {
+        Self {
+            type_: resource.type_name,
+            properties: resource.properties,
+            depends_on: vec![],
+            condition: None,
+            deletion_policy: None,
+            update_replace_policy: None,
+            metadata: Metadata::default(),
+        }
+    }
+}
+
+/// A `Ref` to a resource of type `R`, which properties only take if they hold the ID of such a
+/// resource, ie. a `TypedRef<Host>` for the `HostId` of a placement
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TypedRef<R> {
+    logical_id: String,
+    type_: PhantomData<R>,
+}
+
+impl<R: Resource> TypedRef<R> {
+    /// Refer to the resource `logical_id`, see `Template::typed_resource` to check it's of
+    /// type `R`
+    pub fn new(logical_id: impl IntoLogicalId) -> Result<Self> {
+        Ok(Self {
+            logical_id: logical_id.into_logical_id()?.into_string(),
+            type_: PhantomData,
+        })
+    }
+
+    pub fn logical_id(&self) -> &str {
+        &self.logical_id
+    }
+}
+
+impl<R: Resource> From<TypedRef<R>> for Intrinsic {
+    fn from(resource: TypedRef<R>) -> Self {
+        Intrinsic::Ref(resource.logical_id)
+    }
+}
+
+impl Template {
+    /// Add `resource` under `logical_id`, returning a reference to it
+    pub fn add_typed_resource<R: Resource>(
+        &mut self,
+        logical_id: impl IntoLogicalId,
+        resource: R,
+    ) -> Result<TypedRef<R>> {
+        let logical_id = logical_id.into_logical_id()?;
+        self.add_resource(&logical_id, resource)?;
+        TypedRef::new(logical_id)
+    }
+
+    /// A reference to the resource `logical_id`, ie. of a parsed template, failing with
+    /// `Error::TypeMismatch` unless it's of type `R`
+    pub fn typed_resource<R: Resource>(&self, logical_id: &str) -> Result<TypedRef<R>> {
+        let entry = self
+            .get(logical_id)
+            .ok_or_else(|| Error::UnknownLogicalId(logical_id.to_string()))?;
+        match entry.type_ == R::TYPE {
+            true => TypedRef::new(logical_id),
+            false => Err(Error::TypeMismatch {
+                logical_id: logical_id.to_string(),
+                expected: R::TYPE.to_string(),
+                found: entry.type_.clone(),
+            }),
+        }
+    }
+}
@@ -0,0 +1,52 @@
+use crate::visit::{self, PropertyPath, Segment};
+use crate::Template;
+use serde_json::Value;
+use std::collections::BTreeSet;
+
+impl Template {
+    /// Modify property values of every resource in place, ie. for bulk refactors of parsed
+    /// templates. `f` is called with the path and value of every primitive or intrinsic
+    /// function, as visited by `TemplateRef::walk`, paths starting with the logical ID.
+    ///
+    /// Returns the logical IDs of the resources which were changed, resources left untouched
+    /// stay shared with clones of the template.
+    ///
+    /// ```
+    /// # use caroline::{Intrinsic, Template};
+    /// # let mut template = Template::new();
+    /// template.rewrite(|path, value| {
+    ///     if path.matches("**.ImageId") {
+    ///         *value = Intrinsic::Ref("ImageId".into()).into();
+    ///     }
+    /// });
+    /// ```
+    pub fn rewrite<F>(&mut self, mut f: F) -> BTreeSet<String>
+    where
+        F: FnMut(&PropertyPath<'_>, &mut Value),
+    {
+        let mut rewritten = BTreeSet::new();
+        let resources = self
+            .resources()
+            .map(|(id, entry)| (id.to_string(), entry.properties.clone()))
+            .collect::<Vec<(String, Value)>>();
+        for (logical_id, mut properties) in resources {
+            let mut changed = false;
+            let mut path = PropertyPath::new();
+            path.push(Segment::Key(&logical_id));
+            if properties.is_object() {
+                visit::walk_mut(&mut path, &mut properties, &mut |path, value| {
+                    let before = value.clone();
+                    f(path, value);
+                    changed |= *value != before;
+                });
+            }
+            if changed {
+                if let Some(entry) = self.get_mut(&logical_id) {
+                    entry.properties = properties;
+                }
+                rewritten.insert(logical_id);
+            }
+        }
+        rewritten
+    }
+}
@@ -0,0 +1,293 @@
+use crate::{Error, Result};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
+use std::str::FromStr;
+
+const MONTHS: [&str; 12] = [
+    "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+];
+const DAYS: [&str; 7] = ["SUN", "MON", "TUE", "WED", "THU", "FRI", "SAT"];
+
+/// Unit of the interval of a `rate(...)` schedule
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RateUnit {
+    Minutes,
+    Hours,
+    Days,
+}
+
+impl RateUnit {
+    fn as_str(&self, value: u32) -> &'static str {
+        match (self, value) {
+            (RateUnit::Minutes, 1) => "minute",
+            (RateUnit::Minutes, _) => "minutes",
+            (RateUnit::Hours, 1) => "hour",
+            (RateUnit::Hours, _) => "hours",
+            (RateUnit::Days, 1) => "day",
+            (RateUnit::Days, _) => "days",
+        }
+    }
+}
+
+/// When a scheduled task runs, serialized as the expression CloudFormation expects, ie.
+/// `rate(30 minutes)`, `cron(0 2 ? * SUN *)` or `at(2030-01-01T09:00:00)`.
+///
+/// Used by SSM associations and maintenance windows, EventBridge rules and schedules, Backup
+/// plans, Image Builder pipelines, Application Auto Scaling scheduled actions, Timestream
+/// scheduled queries and IoT Analytics datasets, while EC2 Auto Scaling takes the unix form of
+/// cron expressions, see `Recurrence`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ScheduleExpression {
+    /// Every `value` units of time
+    Rate(u32, RateUnit),
+    /// The six fields of a cron expression, minutes, hours, day of month, month,
+    /// day of week and year, ie. `0 2 ? * SUN *`
+    Cron(String),
+    /// Once, at a date and time formatted `yyyy-mm-ddThh:mm:ss`, not supported by every service
+    At(String),
+}
+
+/// Once a day, only there so typed resources requiring a schedule can be defaulted
+impl Default for ScheduleExpression {
+    fn default() -> Self {
+        ScheduleExpression::Rate(1, RateUnit::Days)
+    }
+}
+
+impl ScheduleExpression {
+    /// Run every `value` units of time, which has to be at least one
+    pub fn rate(value: u32, unit: RateUnit) -> Result<Self> {
+        match value {
+            0 => Err(Error::InvalidSchedule(format!(
+                "rate(0 {})",
+                unit.as_str(0)
+            ))),
+            _ => Ok(ScheduleExpression::Rate(value, unit)),
+        }
+    }
+
+    /// Run at the times given by the six fields of a cron expression, ie. `0 2 ? * SUN *`.
+    ///
+    /// Fields are checked against their ranges, months and days of the week may be given by
+    /// name, ie. `JAN` and `MON`, and either the day of month or the day of week has to be `?`.
+    pub fn cron(fields: &str) -> Result<Self> {
+        let fields = fields.split_whitespace().collect::<Vec<&str>>();
+        let invalid = || Error::InvalidSchedule(format!("cron({})", fields.join(" ")));
+        let (day_of_month, day_of_week) = match fields.as_slice() {
+            [minutes, hours, day_of_month, month, day_of_week, year]
+                if field(minutes, 0, 59, &[])
+                    && field(hours, 0, 23, &[])
+                    && field(month, 1, 12, &MONTHS)
+                    && field(year, 1970, 2199, &[]) =>
+            {
+                (*day_of_month, *day_of_week)
+            }
+            _ => return Err(invalid()),
+        };
+        let days_valid = match (day_of_month, day_of_week) {
+            ("?", "?") => false,
+            ("?", day) => day_of_week_field(day),
+            (day, "?") => day_of_month_field(day),
+            _ => false,
+        };
+        match days_valid {
+            true => Ok(ScheduleExpression::Cron(fields.join(" "))),
+            false => Err(invalid()),
+        }
+    }
+
+    /// Run once at `timestamp`, formatted `yyyy-mm-ddThh:mm:ss`
+    pub fn at(timestamp: &str) -> Result<Self> {
+        let invalid = || Error::InvalidSchedule(format!("at({})", timestamp));
+        let (date, time) = timestamp.split_once('T').ok_or_else(invalid)?;
+        let date = numbers(date, '-', [4, 2, 2]).ok_or_else(invalid)?;
+        let time = numbers(time, ':', [2, 2, 2]).ok_or_else(invalid)?;
+        match (1..=12).contains(&date[1])
+            && (1..=31).contains(&date[2])
+            && time[0] <= 23
+            && time[1] <= 59
+            && time[2] <= 59
+        {
+            true => Ok(ScheduleExpression::At(timestamp.to_string())),
+            false => Err(invalid()),
+        }
+    }
+}
+
+impl FromStr for ScheduleExpression {
+    type Err = Error;
+
+    fn from_str(expression: &str) -> Result<Self> {
+        let invalid = || Error::InvalidSchedule(expression.to_string());
+        let expression = expression.trim();
+        let (kind, argument) = expression
+            .strip_suffix(')')
+            .and_then(|rest| rest.split_once('('))
+            .ok_or_else(invalid)?;
+        match kind {
+            "cron" => return Self::cron(argument).map_err(|_| invalid()),
+            "at" => return Self::at(argument).map_err(|_| invalid()),
+            "rate" => (),
+            _ => return Err(invalid()),
+        }
+        let (value, name) = argument.trim().split_once(' ').ok_or_else(invalid)?;
+        let value = value.parse::<u32>().map_err(|_| invalid())?;
+        let unit = match name.trim() {
+            "minute" | "minutes" => RateUnit::Minutes,
+            "hour" | "hours" => RateUnit::Hours,
+            "day" | "days" => RateUnit::Days,
+            _ => return Err(invalid()),
+        };
+        // CloudFormation only accepts the singular unit for a value of one, and vice versa
+        if unit.as_str(value) != name.trim() {
+            return Err(invalid());
+        }
+        Self::rate(value, unit).map_err(|_| invalid())
+    }
+}
+
+impl fmt::Display for ScheduleExpression {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScheduleExpression::Rate(value, unit) => {
+                write!(f, "rate({} {})", value, unit.as_str(*value))
+            }
+            ScheduleExpression::Cron(fields) => write!(f, "cron({})", fields),
+            ScheduleExpression::At(timestamp) => write!(f, "at({})", timestamp),
+        }
+    }
+}
+
+impl Serialize for ScheduleExpression {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for ScheduleExpression {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// When an EC2 Auto Scaling scheduled action recurs, as the five fields of a unix cron
+/// expression, minutes, hours, day of month, month and day of week, ie. `0 9 * * MON-FRI`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Recurrence(String);
+
+impl Recurrence {
+    pub fn cron(fields: &str) -> Result<Self> {
+        let fields = fields.split_whitespace().collect::<Vec<&str>>();
+        match fields.as_slice() {
+            [minutes, hours, day_of_month, month, day_of_week]
+                if field(minutes, 0, 59, &[])
+                    && field(hours, 0, 23, &[])
+                    && field(day_of_month, 1, 31, &[])
+                    && field(month, 1, 12, &MONTHS)
+                    && field(day_of_week, 0, 6, &DAYS) =>
+            {
+                Ok(Recurrence(fields.join(" ")))
+            }
+            _ => Err(Error::InvalidSchedule(fields.join(" "))),
+        }
+    }
+}
+
+impl FromStr for Recurrence {
+    type Err = Error;
+
+    fn from_str(fields: &str) -> Result<Self> {
+        Self::cron(fields)
+    }
+}
+
+impl fmt::Display for Recurrence {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl Serialize for Recurrence {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Recurrence {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(de::Error::custom)
+    }
+}
+
+/// Whether every comma separated part of a cron field is `*`, a value, or a range of values
+/// between `min` and `max`, optionally stepped, ie. `*/5` or `MON-FRI`. `names` are
+/// alternatives to the values counting up from `min`.
+fn field(field: &str, min: u32, max: u32, names: &[&str]) -> bool {
+    let value = |value: &str| match value.parse::<u32>() {
+        Ok(value) => value >= min && value <= max,
+        Err(_) => names.iter().any(|name| name.eq_ignore_ascii_case(value)),
+    };
+    field.split(',').all(|part| {
+        let (range, step) = match part.split_once('/') {
+            Some((range, step)) => (range, Some(step)),
+            None => (part, None),
+        };
+        let step_valid = step
+            .map(|step| step.parse::<u32>().map(|step| step > 0).unwrap_or(false))
+            .unwrap_or(true);
+        let range_valid = match range.split_once('-') {
+            _ if range == "*" => true,
+            Some((from, to)) => value(from) && value(to),
+            None => value(range),
+        };
+        step_valid && range_valid
+    })
+}
+
+/// Day of month field of an AWS cron expression, also allowing the last day of the month
+/// `L` and the weekday nearest to a day, ie. `15W`
+fn day_of_month_field(day: &str) -> bool {
+    match day {
+        "L" | "LW" => true,
+        _ => match day.strip_suffix('W') {
+            Some(day) => field(day, 1, 31, &[]) && !day.contains(&[',', '-', '/', '*'][..]),
+            None => field(day, 1, 31, &[]),
+        },
+    }
+}
+
+/// Day of week field of an AWS cron expression, `1` or `SUN` being Sunday, also allowing the
+/// last of a weekday of the month, ie. `6L`, and the nth weekday of the month, ie. `MON#2`
+fn day_of_week_field(day: &str) -> bool {
+    if day == "L" {
+        return true;
+    }
+    if let Some(weekday) = day.strip_suffix('L') {
+        return weekday_value(weekday);
+    }
+    match day.split_once('#') {
+        Some((weekday, nth)) => weekday_value(weekday) && matches!(nth.parse::<u32>(), Ok(1..=5)),
+        None => field(day, 1, 7, &DAYS),
+    }
+}
+
+/// The three numbers of a date or time separated by `separator`, each of `digits` digits
+fn numbers(value: &str, separator: char, digits: [usize; 3]) -> Option<Vec<u32>> {
+    let parts = value.split(separator).collect::<Vec<&str>>();
+    let valid = parts.len() == 3
+        && parts.iter().zip(digits.iter()).all(|(part, digits)| {
+            part.len() == *digits && part.chars().all(|c| c.is_ascii_digit())
+        });
+    match valid {
+        true => parts.iter().map(|part| part.parse().ok()).collect(),
+        false => None,
+    }
+}
+
+fn weekday_value(weekday: &str) -> bool {
+    !weekday.contains(&[',', '-', '/', '*'][..]) && field(weekday, 1, 7, &DAYS)
+}
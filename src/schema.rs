@@ -0,0 +1,174 @@
+use crate::visit::is_intrinsic;
+use crate::{Error, Result, Template, UnknownResource};
+use serde_json::{Map, Value};
+
+/// A CloudFormation Registry resource schema, ie. of the third-party type
+/// `MongoDB::Atlas::Cluster`, to check resources of types the crate doesn't model at runtime.
+///
+/// Types known when building the crate are better added as schema files under
+/// `definitions/registry`, or a directory given by `CAROLINE_REGISTRY_SCHEMAS`, which
+/// generates them like any AWS type.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegistrySchema {
+    type_name: String,
+    schema: Value,
+}
+
+impl RegistrySchema {
+    /// Parse a resource schema, as returned by `aws cloudformation describe-type`
+    pub fn from_json(json: &str) -> Result<Self> {
+        let schema: Value = serde_json::from_str(json)?;
+        let type_name = schema["typeName"]
+            .as_str()
+            .ok_or_else(|| Error::SchemaViolations(vec!["typeName: missing".to_string()]))?
+            .to_string();
+        Ok(Self { type_name, schema })
+    }
+
+    /// The type name the schema describes, ie. `MongoDB::Atlas::Cluster`
+    pub fn type_name(&self) -> &str {
+        &self.type_name
+    }
+
+    /// A resource of this type with `properties`, failing with `Error::SchemaViolations`
+    /// unless they conform to the schema
+    pub fn resource(&self, properties: impl Into<Value>) -> Result<UnknownResource> {
+        let properties = properties.into();
+        let violations = self.check(None, &properties);
+        match violations.is_empty() {
+            true => Ok(UnknownResource::new(self.type_name.clone(), properties)),
+            false => Err(Error::SchemaViolations(violations)),
+        }
+    }
+
+    /// Violations of the schema by `properties`, as paths and what's wrong with them,
+    /// ie. `ProviderSettings.InstanceSizeName: missing`
+    pub fn validate(&self, properties: &Value) -> Vec<String> {
+        self.check(None, properties)
+    }
+
+    /// Violations of the schema by every resource of its type within `template`, with paths
+    /// starting with the logical ID
+    pub fn validate_template(&self, template: &Template) -> Vec<String> {
+        template
+            .resources()
+            .filter(|(_, entry)| entry.type_ == self.type_name)
+            .flat_map(|(logical_id, entry)| self.check(Some(logical_id), &entry.properties))
+            .collect()
+    }
+
+    fn check(&self, logical_id: Option<&str>, properties: &Value) -> Vec<String> {
+        let mut violations = vec![];
+        let path = logical_id.unwrap_or_default();
+        // Resources without any properties leave them out altogether
+        let empty = Value::Object(Map::new());
+        let properties = match properties {
+            Value::Null => &empty,
+            properties => properties,
+        };
+        if let Value::Object(values) = properties {
+            let read_only = self.schema["readOnlyProperties"]
+                .as_array()
+                .into_iter()
+                .flatten()
+                .filter_map(|pointer| pointer.as_str()?.strip_prefix("/properties/"));
+            for name in read_only.filter(|name| values.contains_key(*name)) {
+                violations.push(format!("{}: read-only", join(path, name)));
+            }
+        }
+        self.check_value(path, &self.schema, properties, &mut violations);
+        violations
+    }
+
+    fn check_value(&self, path: &str, schema: &Value, value: &Value, violations: &mut Vec<String>) {
+        let schema = self.resolve(schema);
+        if let Value::Object(values) = value {
+            if is_intrinsic(values) {
+                return;
+            }
+        }
+        if let Some(allowed) = schema["enum"].as_array() {
+            if !allowed.contains(value) {
+                violations.push(format!(
+                    "{}: not one of {}",
+                    path,
+                    Value::from(allowed.clone())
+                ));
+            }
+        }
+        let expected = match &schema["type"] {
+            Value::String(expected) => expected.as_str(),
+            _ if schema["properties"].is_object() => "object",
+            _ => return,
+        };
+        let matches = match (expected, value) {
+            ("object", Value::Object(_)) | ("array", Value::Array(_)) => true,
+            ("string", Value::String(_)) | ("boolean", Value::Bool(_)) => true,
+            ("number", Value::Number(_)) => true,
+            ("integer", Value::Number(number)) => number.is_i64() || number.is_u64(),
+            // CloudFormation converts strings to numbers and booleans, parameters are strings
+            ("integer", Value::String(string)) => string.parse::<i64>().is_ok(),
+            ("number", Value::String(string)) => string.parse::<f64>().is_ok(),
+            ("boolean", Value::String(string)) => string == "true" || string == "false",
+            _ => false,
+        };
+        if !matches {
+            violations.push(format!("{}: expected {}", path, expected));
+            return;
+        }
+        match value {
+            Value::Object(values) => self.check_object(path, schema, values, violations),
+            Value::Array(items) => {
+                for (idx, item) in items.iter().enumerate() {
+                    let path = format!("{}[{}]", path, idx);
+                    self.check_value(&path, &schema["items"], item, violations);
+                }
+            }
+            _ => (),
+        }
+    }
+
+    fn check_object(
+        &self,
+        path: &str,
+        schema: &Value,
+        values: &Map<String, Value>,
+        violations: &mut Vec<String>,
+    ) {
+        let required = schema["required"].as_array().into_iter().flatten();
+        for name in required.filter_map(Value::as_str) {
+            if !values.contains_key(name) {
+                violations.push(format!("{}: missing", join(path, name)));
+            }
+        }
+        let properties = match schema["properties"].as_object() {
+            Some(properties) => properties,
+            None => return,
+        };
+        for (name, value) in values.iter() {
+            match properties.get(name) {
+                Some(property) => self.check_value(&join(path, name), property, value, violations),
+                None if schema["additionalProperties"] == Value::Bool(false) => {
+                    violations.push(format!("{}: unknown property", join(path, name)))
+                }
+                None => (),
+            }
+        }
+    }
+
+    /// The definition `schema` refers to through `$ref`, if any
+    fn resolve<'a>(&'a self, schema: &'a Value) -> &'a Value {
+        schema["$ref"]
+            .as_str()
+            .and_then(|pointer| pointer.strip_prefix("#/definitions/"))
+            .map(|name| self.resolve(&self.schema["definitions"][name]))
+            .unwrap_or(schema)
+    }
+}
+
+fn join(path: &str, name: &str) -> String {
+    match path.is_empty() {
+        true => name.to_string(),
+        false => format!("{}.{}", path, name),
+    }
+}
@@ -0,0 +1,88 @@
+use crate::visit::{sub_parts, SubPart};
+use crate::{registry, Template};
+use serde_json::Value;
+
+/// Dynamic references resolving secrets, `{{resolve:ssm:...}}` reads a plain parameter
+const SECRET_REFERENCES: [&str; 2] = ["{{resolve:secretsmanager:", "{{resolve:ssm-secure:"];
+
+impl Template {
+    /// Paths of sensitive properties given in plain text, ie. `Database.MasterUserPassword`.
+    ///
+    /// Secrets are expected to be dynamic references to Secrets Manager or SSM secure strings,
+    /// ie. `{{resolve:secretsmanager:db-password}}`, or references to `NoEcho` parameters. Any
+    /// other literal string or reference to another parameter is reported, also when joined
+    /// or substituted into a string. Which properties are sensitive is given by the `sensitive`
+    /// flag of their `PropertyInfo`.
+    pub fn plaintext_secrets(&self) -> Vec<String> {
+        let mut secrets = vec![];
+        registry::walk_properties(self, &mut |path, value, property| match property {
+            Some(property) if property.sensitive && self.is_plaintext(value) => {
+                secrets.push(path.to_string());
+                false
+            }
+            Some(_) => true,
+            None => false,
+        });
+        secrets
+    }
+
+    /// Whether `value` gives a secret away, rather than resolving it at deployment
+    fn is_plaintext(&self, value: &Value) -> bool {
+        let function = match value {
+            Value::String(value) => return !resolves_secret(value),
+            Value::Array(items) => return items.iter().any(|item| self.is_plaintext(item)),
+            Value::Object(values) if values.len() == 1 => values.iter().next(),
+            _ => None,
+        };
+        match function {
+            Some((name, Value::String(target))) if name == "Ref" => {
+                self.is_plaintext_parameter(target)
+            }
+            Some((name, args)) if name == "Fn::Sub" => self.is_plaintext_sub(args),
+            // The delimiter, index and condition name aren't part of the secret
+            Some((name, Value::Array(parts)))
+                if name == "Fn::Join" || name == "Fn::Select" || name == "Fn::If" =>
+            {
+                parts.iter().skip(1).any(|part| self.is_plaintext(part))
+            }
+            Some((name, arg)) if name == "Fn::Base64" => self.is_plaintext(arg),
+            _ => false,
+        }
+    }
+
+    /// Whether an `Fn::Sub` has literal text, or variables of parameters which aren't `NoEcho`
+    fn is_plaintext_sub(&self, args: &Value) -> bool {
+        let (s, variables) = match args {
+            Value::String(s) => (s, None),
+            Value::Array(parts) => match parts.as_slice() {
+                [Value::String(s)] => (s, None),
+                [Value::String(s), Value::Object(variables)] => (s, Some(variables)),
+                _ => return false,
+            },
+            _ => return false,
+        };
+        if resolves_secret(s) {
+            return false;
+        }
+        sub_parts(s).into_iter().any(|part| match part {
+            SubPart::Text(text) => !text.trim().is_empty(),
+            SubPart::Variable(variable) => {
+                match variables.and_then(|variables| variables.get(variable)) {
+                    Some(value) => self.is_plaintext(value),
+                    None => self.is_plaintext_parameter(variable),
+                }
+            }
+        })
+    }
+
+    fn is_plaintext_parameter(&self, name: &str) -> bool {
+        self.parameters()
+            .any(|(id, parameter)| id == name && parameter.no_echo != Some(true))
+    }
+}
+
+fn resolves_secret(value: &str) -> bool {
+    SECRET_REFERENCES
+        .iter()
+        .any(|reference| value.contains(reference))
+}
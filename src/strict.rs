@@ -0,0 +1,36 @@
+use crate::{registry, Error, Result, Template};
+
+/// Templates are parsed leniently by default, keeping properties the resource specification
+/// doesn't know of as given. These strict variants reject them instead, to catch typos.
+impl Template {
+    /// Parse a template from JSON, failing on unknown properties
+    pub fn from_json_strict(json: &str) -> Result<Self> {
+        Self::from_json(json)?.strict()
+    }
+
+    /// Parse a template from YAML, failing on unknown properties
+    pub fn from_yaml_strict(yaml: &str) -> Result<Self> {
+        Self::from_yaml(yaml)?.strict()
+    }
+
+    fn strict(self) -> Result<Self> {
+        let unknown = self.unknown_properties();
+        match unknown.is_empty() {
+            true => Ok(self),
+            false => Err(Error::UnknownProperties(unknown)),
+        }
+    }
+
+    /// Paths of properties which aren't part of the resource specification, ie. `Vpc.CidrBlok`.
+    /// Resources of types the crate doesn't know of, ie. custom resources, are skipped.
+    pub fn unknown_properties(&self) -> Vec<String> {
+        let mut unknown = vec![];
+        registry::walk_properties(self, &mut |path, _, property| {
+            if property.is_none() {
+                unknown.push(path.to_string());
+            }
+            property.is_some()
+        });
+        unknown
+    }
+}
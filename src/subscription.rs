@@ -0,0 +1,164 @@
+use serde::{de, Deserialize, Deserializer, Serialize};
+use serde_json::{json, Map, Value};
+
+/// Which messages an `AWS::SNS::Subscription` receives, by conditions on their attributes or,
+/// with a `FilterPolicyScope` of `MessageBody`, on the properties of their JSON body
+///
+/// ```
+/// # use caroline::FilterPolicy;
+/// let policy = FilterPolicy::new()
+///     .equals("store", vec!["example_corp"])
+///     .anything_but("event", vec!["order_cancelled"])
+///     .numeric("price_usd", ">=", 100);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct FilterPolicy(Map<String, Value>);
+
+impl FilterPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Match messages whose `attribute` satisfies any of the `conditions`, ie. a string, a
+    /// number or a `{"prefix": "..."}` operator
+    pub fn condition(mut self, attribute: &str, conditions: Vec<Value>) -> Self {
+        self.0
+            .insert(attribute.to_string(), Value::Array(conditions));
+        self
+    }
+
+    /// Match messages whose `attribute` equals any of `values`
+    pub fn equals<T: Into<Value>>(
+        self,
+        attribute: &str,
+        values: impl IntoIterator<Item = T>,
+    ) -> Self {
+        self.condition(attribute, values.into_iter().map(Into::into).collect())
+    }
+
+    /// Match messages whose `attribute` equals none of `values`
+    pub fn anything_but<T: Into<Value>>(
+        self,
+        attribute: &str,
+        values: impl IntoIterator<Item = T>,
+    ) -> Self {
+        let values = values.into_iter().map(Into::into).collect::<Vec<Value>>();
+        self.condition(attribute, vec![json!({ "anything-but": values })])
+    }
+
+    /// Match messages whose `attribute` starts with `prefix`
+    pub fn prefix(self, attribute: &str, prefix: &str) -> Self {
+        self.condition(attribute, vec![json!({ "prefix": prefix })])
+    }
+
+    /// Match messages whose numeric `attribute` compares to `value` by `operator`, ie. `<`
+    pub fn numeric(self, attribute: &str, operator: &str, value: impl Into<Value>) -> Self {
+        self.condition(
+            attribute,
+            vec![json!({ "numeric": [operator, value.into()] })],
+        )
+    }
+
+    /// Match messages which have, or lack, `attribute`
+    pub fn exists(self, attribute: &str, exists: bool) -> Self {
+        self.condition(attribute, vec![json!({ "exists": exists })])
+    }
+
+    /// Match messages whose body property `key` matches `policy`, only for a `FilterPolicyScope`
+    /// of `MessageBody`
+    pub fn nested(mut self, key: &str, policy: FilterPolicy) -> Self {
+        self.0.insert(key.to_string(), Value::Object(policy.0));
+        self
+    }
+}
+
+/// Filter policies are free-form objects, so only an intrinsic function keeps one from being
+/// taken for a policy
+impl<'de> Deserialize<'de> for FilterPolicy {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let map = Map::deserialize(deserializer)?;
+        match map.keys().next() {
+            Some(key) if map.len() == 1 && (key == "Ref" || key.starts_with("Fn::")) => {
+                Err(de::Error::custom(format!(
+                    "{} is an intrinsic function, not a filter policy",
+                    key
+                )))
+            }
+            _ => Ok(FilterPolicy(map)),
+        }
+    }
+}
+
+/// How the delay between retries of an HTTP/S delivery grows from `min_delay_target` to
+/// `max_delay_target`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum BackoffFunction {
+    Arithmetic,
+    Exponential,
+    Geometric,
+    Linear,
+}
+
+/// How often and how fast an HTTP/S delivery which failed is retried, all delays in seconds
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct HealthyRetryPolicy {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_delay_target: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_delay_target: Option<u32>,
+    /// Total number of retries, including those of the other `num_*` settings
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_retries: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_no_delay_retries: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_min_delay_retries: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_max_delay_retries: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub backoff_function: Option<BackoffFunction>,
+}
+
+/// How many deliveries per second an HTTP/S endpoint accepts
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ThrottlePolicy {
+    pub max_receives_per_second: u32,
+}
+
+/// Content type of the notifications sent to an HTTP/S endpoint, ie. `application/json`
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct RequestPolicy {
+    pub header_content_type: String,
+}
+
+/// The `DeliveryPolicy` of an `AWS::SNS::Subscription` to an HTTP/S endpoint
+///
+/// ```
+/// # use caroline::{BackoffFunction, DeliveryPolicy, HealthyRetryPolicy, ThrottlePolicy};
+/// let policy = DeliveryPolicy {
+///     healthy_retry_policy: Some(HealthyRetryPolicy {
+///         num_retries: Some(10),
+///         backoff_function: Some(BackoffFunction::Exponential),
+///         ..HealthyRetryPolicy::default()
+///     }),
+///     throttle_policy: Some(ThrottlePolicy {
+///         max_receives_per_second: 5,
+///     }),
+///     ..DeliveryPolicy::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct DeliveryPolicy {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub healthy_retry_policy: Option<HealthyRetryPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub throttle_policy: Option<ThrottlePolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub request_policy: Option<RequestPolicy>,
+}
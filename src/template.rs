@@ -0,0 +1,653 @@
+use crate::visit::{walk_references, References, Target};
+use crate::{Error, IntoLogicalId, LogicalId, Metadata, Resource, Result, UnknownResource};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::Arc;
+
+/// Format version written by `Template::new`
+pub(crate) const FORMAT_VERSION: &str = "2010-09-09";
+
+/// A CloudFormation template
+///
+/// Resources are kept in their serialized form so templates may hold any resource type,
+/// use `Template::resource` to get a typed resource back out.
+///
+/// Resources are shared between clones of a template, so cloning is cheap regardless of
+/// the number of resources; a resource is only copied once one of the clones modifies it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Template {
+    #[serde(
+        rename = "AWSTemplateFormatVersion",
+        skip_serializing_if = "Option::is_none"
+    )]
+    format_version: Option<String>,
+    #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(
+        rename = "Metadata",
+        default,
+        skip_serializing_if = "Metadata::is_empty"
+    )]
+    metadata: Metadata,
+    #[serde(
+        rename = "Parameters",
+        default,
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    parameters: BTreeMap<String, Parameter>,
+    #[serde(
+        rename = "Mappings",
+        default,
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    mappings: BTreeMap<String, Value>,
+    #[serde(
+        rename = "Conditions",
+        default,
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    conditions: BTreeMap<String, Value>,
+    #[serde(rename = "Resources", default)]
+    resources: Arc<BTreeMap<String, Arc<ResourceEntry>>>,
+    #[serde(
+        rename = "Outputs",
+        default,
+        skip_serializing_if = "BTreeMap::is_empty"
+    )]
+    outputs: BTreeMap<String, Output>,
+}
+
+impl Default for Template {
+    fn default() -> Self {
+        Self {
+            format_version: Some(FORMAT_VERSION.to_string()),
+            description: None,
+            metadata: Metadata::default(),
+            parameters: BTreeMap::new(),
+            mappings: BTreeMap::new(),
+            conditions: BTreeMap::new(),
+            resources: Arc::new(BTreeMap::new()),
+            outputs: BTreeMap::new(),
+        }
+    }
+}
+
+impl Template {
+    /// Create a new, empty, `Template`
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parse a template from JSON
+    pub fn from_json(json: &str) -> Result<Self> {
+        Ok(serde_json::from_str(json)?)
+    }
+
+    /// Render the template as pretty printed JSON
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string_pretty(self)?)
+    }
+
+    pub fn description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    pub fn set_description(&mut self, description: impl Into<String>) -> &mut Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// The template's `Metadata` section
+    pub fn metadata(&self) -> &Metadata {
+        &self.metadata
+    }
+
+    pub fn metadata_mut(&mut self) -> &mut Metadata {
+        &mut self.metadata
+    }
+
+    /// Whether `logical_id` is used by a resource or parameter of this template
+    pub fn contains(&self, logical_id: &str) -> bool {
+        self.resources.contains_key(logical_id) || self.parameters.contains_key(logical_id)
+    }
+
+    /// Add a typed resource under `logical_id`, strings have to be a valid `LogicalId`
+    pub fn add_resource<R: Resource>(
+        &mut self,
+        logical_id: impl IntoLogicalId,
+        resource: R,
+    ) -> Result<&mut ResourceEntry> {
+        let entry = ResourceEntry::new(&resource)?;
+        self.insert_resource(logical_id, entry)
+    }
+
+    /// Add a resource of a type the crate doesn't model under `logical_id`
+    pub fn add_unknown_resource(
+        &mut self,
+        logical_id: impl IntoLogicalId,
+        resource: UnknownResource,
+    ) -> Result<&mut ResourceEntry> {
+        self.insert_resource(logical_id, resource.into())
+    }
+
+    /// Add an already serialized resource under `logical_id`
+    pub fn insert_resource(
+        &mut self,
+        logical_id: impl IntoLogicalId,
+        entry: ResourceEntry,
+    ) -> Result<&mut ResourceEntry> {
+        let logical_id = logical_id.into_logical_id()?.into_string();
+        if self.contains(&logical_id) {
+            return Err(Error::DuplicateLogicalId(logical_id));
+        }
+        let entry = Arc::make_mut(&mut self.resources)
+            .entry(logical_id)
+            .or_insert_with(|| Arc::new(entry));
+        Ok(Arc::make_mut(entry))
+    }
+
+    /// Get the resource `logical_id` as its typed representation
+    pub fn resource<R: Resource>(&self, logical_id: &str) -> Result<R> {
+        let entry = self
+            .resources
+            .get(logical_id)
+            .ok_or_else(|| Error::UnknownLogicalId(logical_id.to_string()))?;
+        if entry.type_ != R::TYPE {
+            return Err(Error::TypeMismatch {
+                logical_id: logical_id.to_string(),
+                expected: R::TYPE.to_string(),
+                found: entry.type_.clone(),
+            });
+        }
+        Ok(serde_json::from_value(entry.properties.clone())?)
+    }
+
+    /// Resources of types the crate doesn't model, ordered by logical ID
+    pub fn unknown_resources(&self) -> impl Iterator<Item = (&str, UnknownResource)> {
+        self.resources()
+            .filter(|(_, entry)| !entry.is_known())
+            .map(|(logical_id, entry)| {
+                let resource = UnknownResource::new(&entry.type_, entry.properties.clone());
+                (logical_id, resource)
+            })
+    }
+
+    pub fn get(&self, logical_id: &str) -> Option<&ResourceEntry> {
+        self.resources.get(logical_id).map(Arc::as_ref)
+    }
+
+    /// Mutable access to the resource `logical_id`, copying it first if it's shared with
+    /// another clone of this template
+    pub fn get_mut(&mut self, logical_id: &str) -> Option<&mut ResourceEntry> {
+        if !self.resources.contains_key(logical_id) {
+            return None;
+        }
+        Arc::make_mut(&mut self.resources)
+            .get_mut(logical_id)
+            .map(Arc::make_mut)
+    }
+
+    pub fn remove(&mut self, logical_id: &str) -> Option<ResourceEntry> {
+        if !self.resources.contains_key(logical_id) {
+            return None;
+        }
+        Arc::make_mut(&mut self.resources)
+            .remove(logical_id)
+            .map(|entry| Arc::try_unwrap(entry).unwrap_or_else(|shared| (*shared).clone()))
+    }
+
+    /// Whether the resource `logical_id` is still shared, rather than copied, between `self`
+    /// and `other`, ie. neither has modified it since one was cloned from the other
+    pub fn shares_resource(&self, other: &Template, logical_id: &str) -> bool {
+        match (
+            self.resources.get(logical_id),
+            other.resources.get(logical_id),
+        ) {
+            (Some(a), Some(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
+    }
+
+    /// Resources of this template, ordered by logical ID
+    pub fn resources(&self) -> impl Iterator<Item = (&str, &ResourceEntry)> {
+        self.resources
+            .iter()
+            .map(|(id, entry)| (id.as_str(), entry.as_ref()))
+    }
+
+    /// Number of resources in this template
+    pub fn len(&self) -> usize {
+        self.resources.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty()
+    }
+
+    pub fn add_parameter(
+        &mut self,
+        logical_id: impl IntoLogicalId,
+        parameter: Parameter,
+    ) -> Result<&mut Parameter> {
+        let logical_id = logical_id.into_logical_id()?.into_string();
+        if self.contains(&logical_id) {
+            return Err(Error::DuplicateLogicalId(logical_id));
+        }
+        Ok(self.parameters.entry(logical_id).or_insert(parameter))
+    }
+
+    pub fn parameters(&self) -> impl Iterator<Item = (&str, &Parameter)> {
+        self.parameters.iter().map(|(id, p)| (id.as_str(), p))
+    }
+
+    /// Add a condition, ie. an `Fn::Equals` intrinsic, under `name`
+    pub fn add_condition(
+        &mut self,
+        name: impl Into<String>,
+        condition: impl Into<Value>,
+    ) -> Result<()> {
+        let name = name.into();
+        if self.conditions.contains_key(&name) {
+            return Err(Error::DuplicateLogicalId(name));
+        }
+        self.conditions.insert(name, condition.into());
+        Ok(())
+    }
+
+    pub fn conditions(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.conditions.iter().map(|(name, c)| (name.as_str(), c))
+    }
+
+    /// Add a mapping of keys to named values, used through `Fn::FindInMap`, under `name`
+    pub fn add_mapping(
+        &mut self,
+        name: impl Into<String>,
+        mapping: impl Into<Value>,
+    ) -> Result<()> {
+        let name = name.into();
+        if self.mappings.contains_key(&name) {
+            return Err(Error::DuplicateLogicalId(name));
+        }
+        self.mappings.insert(name, mapping.into());
+        Ok(())
+    }
+
+    pub fn mappings(&self) -> impl Iterator<Item = (&str, &Value)> {
+        self.mappings.iter().map(|(name, m)| (name.as_str(), m))
+    }
+
+    pub fn add_output(
+        &mut self,
+        logical_id: impl IntoLogicalId,
+        output: Output,
+    ) -> Result<&mut Output> {
+        let logical_id = logical_id.into_logical_id()?.into_string();
+        if self.outputs.contains_key(&logical_id) {
+            return Err(Error::DuplicateLogicalId(logical_id));
+        }
+        Ok(self.outputs.entry(logical_id).or_insert(output))
+    }
+
+    pub fn outputs(&self) -> impl Iterator<Item = (&str, &Output)> {
+        self.outputs.iter().map(|(id, o)| (id.as_str(), o))
+    }
+
+    pub fn output_mut(&mut self, logical_id: &str) -> Option<&mut Output> {
+        self.outputs.get_mut(logical_id)
+    }
+
+    /// Add the resources, parameters, conditions, mappings and outputs of `other`, ie. to
+    /// compose partial templates generated independently into one stack. Names `other` shares
+    /// with this template are handled according to `policy`, the description and metadata of
+    /// `other` are dropped.
+    ///
+    /// Fails with `Error::DuplicateLogicalId`, leaving the template unchanged, on a collision
+    /// under `CollisionPolicy::Error`, or if a prefixed name is still taken.
+    pub fn merge(&mut self, other: Template, policy: CollisionPolicy) -> Result<()> {
+        // Resources and parameters share a namespace, both are referenced through `Ref`
+        let ids = collisions(
+            other.resources.keys().chain(other.parameters.keys()),
+            |id| self.contains(id),
+            &policy,
+        )?;
+        let conditions = collisions(
+            other.conditions.keys(),
+            |name| self.conditions.contains_key(name),
+            &policy,
+        )?;
+        let mappings = collisions(
+            other.mappings.keys(),
+            |name| self.mappings.contains_key(name),
+            &policy,
+        )?;
+        let outputs = collisions(
+            other.outputs.keys(),
+            |id| self.outputs.contains_key(id),
+            &policy,
+        )?;
+        let renames = Renames {
+            ids: renamed(&ids),
+            conditions: renamed(&conditions),
+            mappings: renamed(&mappings),
+        };
+
+        let resources = Arc::try_unwrap(other.resources).unwrap_or_else(|shared| (*shared).clone());
+        for (logical_id, mut entry) in resources {
+            if let Some(logical_id) = merged_name(&ids, logical_id) {
+                // Resources without references to rename stay shared with `other`
+                if !renames.is_empty() {
+                    renames.apply_to_resource(Arc::make_mut(&mut entry));
+                }
+                Arc::make_mut(&mut self.resources).insert(logical_id, entry);
+            }
+        }
+        for (logical_id, parameter) in other.parameters {
+            if let Some(logical_id) = merged_name(&ids, logical_id) {
+                self.parameters.insert(logical_id, parameter);
+            }
+        }
+        for (name, mut condition) in other.conditions {
+            if let Some(name) = merged_name(&conditions, name) {
+                renames.apply(&mut condition);
+                self.conditions.insert(name, condition);
+            }
+        }
+        for (name, mapping) in other.mappings {
+            if let Some(name) = merged_name(&mappings, name) {
+                self.mappings.insert(name, mapping);
+            }
+        }
+        for (logical_id, mut output) in other.outputs {
+            if let Some(logical_id) = merged_name(&outputs, logical_id) {
+                renames.apply(&mut output.value);
+                if let Some(export) = output.export.as_mut() {
+                    renames.apply(&mut export.name);
+                }
+                if let Some(condition) = output.condition.as_mut() {
+                    rename(&renames.conditions, condition);
+                }
+                self.outputs.insert(logical_id, output);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// What `Template::merge` does with names of the merged template, ie. logical IDs, which are
+/// already used by the template it's merged into
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum CollisionPolicy {
+    /// Fail with `Error::DuplicateLogicalId`
+    Error,
+    /// Keep the existing definition, references within the merged template then refer to it
+    Skip,
+    /// Prepend the prefix to the name, ie. `Network` renames `Vpc` to `NetworkVpc`, along with
+    /// the references to it within the merged template
+    Prefix(String),
+}
+
+/// Names of `names` which are `taken`, mapped to their new name, or `None` if skipped
+fn collisions<'a>(
+    names: impl Iterator<Item = &'a String>,
+    taken: impl Fn(&str) -> bool,
+    policy: &CollisionPolicy,
+) -> Result<BTreeMap<String, Option<String>>> {
+    let names = names.collect::<BTreeSet<&String>>();
+    let mut collisions = BTreeMap::new();
+    for name in names.iter().filter(|name| taken(name)) {
+        let renamed = match policy {
+            CollisionPolicy::Error => return Err(Error::DuplicateLogicalId(name.to_string())),
+            CollisionPolicy::Skip => None,
+            CollisionPolicy::Prefix(prefix) => {
+                let renamed = LogicalId::new(format!("{}{}", prefix, name))?.into_string();
+                if taken(&renamed) || names.contains(&renamed) {
+                    return Err(Error::DuplicateLogicalId(renamed));
+                }
+                Some(renamed)
+            }
+        };
+        collisions.insert(name.to_string(), renamed);
+    }
+    Ok(collisions)
+}
+
+fn renamed(collisions: &BTreeMap<String, Option<String>>) -> BTreeMap<String, String> {
+    collisions
+        .iter()
+        .filter_map(|(name, renamed)| Some((name.clone(), renamed.clone()?)))
+        .collect()
+}
+
+/// Name `name` is merged under, `None` if it's skipped
+fn merged_name(collisions: &BTreeMap<String, Option<String>>, name: String) -> Option<String> {
+    match collisions.get(&name) {
+        Some(renamed) => renamed.clone(),
+        None => Some(name),
+    }
+}
+
+fn rename(renames: &BTreeMap<String, String>, name: &mut String) {
+    if let Some(renamed) = renames.get(name.as_str()) {
+        *name = renamed.clone();
+    }
+}
+
+/// Renamed logical IDs, conditions and mappings of a merged template
+struct Renames {
+    ids: BTreeMap<String, String>,
+    conditions: BTreeMap<String, String>,
+    mappings: BTreeMap<String, String>,
+}
+
+impl Renames {
+    fn is_empty(&self) -> bool {
+        self.ids.is_empty() && self.conditions.is_empty() && self.mappings.is_empty()
+    }
+
+    fn apply_to_resource(&self, entry: &mut ResourceEntry) {
+        self.apply(&mut entry.properties);
+        for logical_id in entry.depends_on.iter_mut() {
+            if let Some(renamed) = self.ids.get(logical_id.as_str()) {
+                // Renames were checked to be valid logical IDs by `collisions`
+                *logical_id = LogicalId::new(renamed.as_str()).expect("renamed logical ID");
+            }
+        }
+        if let Some(condition) = entry.condition.as_mut() {
+            rename(&self.conditions, condition);
+        }
+    }
+
+    /// Rename references of `Ref`, `Fn::GetAtt` and `Fn::Sub`, conditions of `Fn::If` and
+    /// `Condition`, and mappings of `Fn::FindInMap` within `value`
+    fn apply(&self, value: &mut Value) {
+        walk_references(value, &mut &*self);
+    }
+}
+
+impl References for &Renames {
+    fn target(&mut self, logical_id: &str, attribute: Option<&str>) -> Option<Target> {
+        self.ids.get(logical_id).map(|renamed| Target {
+            logical_id: renamed.clone(),
+            attribute: attribute.map(str::to_string),
+        })
+    }
+
+    fn resource(&mut self, logical_id: &str) -> Option<String> {
+        self.ids.get(logical_id).cloned()
+    }
+
+    fn condition(&mut self, name: &str) -> Option<String> {
+        self.conditions.get(name).cloned()
+    }
+
+    fn mapping(&mut self, name: &str) -> Option<String> {
+        self.mappings.get(name).cloned()
+    }
+}
+
+/// A resource declared in a template
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ResourceEntry {
+    #[serde(rename = "Type")]
+    pub type_: String,
+    #[serde(rename = "Properties", default, skip_serializing_if = "Value::is_null")]
+    pub properties: Value,
+    #[serde(
+        rename = "DependsOn",
+        default,
+        skip_serializing_if = "Vec::is_empty",
+        deserialize_with = "one_or_many"
+    )]
+    pub depends_on: Vec<LogicalId>,
+    #[serde(rename = "Condition", skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+    #[serde(rename = "DeletionPolicy", skip_serializing_if = "Option::is_none")]
+    pub deletion_policy: Option<String>,
+    #[serde(
+        rename = "UpdateReplacePolicy",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub update_replace_policy: Option<String>,
+    #[serde(
+        rename = "Metadata",
+        default,
+        skip_serializing_if = "Metadata::is_empty"
+    )]
+    pub metadata: Metadata,
+}
+
+impl ResourceEntry {
+    /// Serialize a typed resource into a `ResourceEntry`
+    pub fn new<R: Resource>(resource: &R) -> Result<Self> {
+        Ok(Self {
+            type_: R::TYPE.to_string(),
+            properties: serde_json::to_value(resource)?,
+            depends_on: vec![],
+            condition: None,
+            deletion_policy: None,
+            update_replace_policy: None,
+            metadata: Metadata::default(),
+        })
+    }
+
+    /// Whether this resource is of type `R`
+    pub fn is<R: Resource>(&self) -> bool {
+        self.type_ == R::TYPE
+    }
+
+    /// Whether the crate models this resource's type, see `UnknownResource`
+    pub fn is_known(&self) -> bool {
+        UnknownResource::is_known(&self.type_)
+    }
+
+    /// Have this resource created after `logical_id`
+    pub fn depends_on(&mut self, logical_id: impl IntoLogicalId) -> Result<&mut Self> {
+        self.depends_on.push(logical_id.into_logical_id()?);
+        Ok(self)
+    }
+
+    /// Only create this resource when `condition` is true
+    pub fn set_condition(&mut self, condition: impl Into<String>) -> &mut Self {
+        self.condition = Some(condition.into());
+        self
+    }
+
+    /// What happens to the physical resource when it's removed from the stack, ie. `Retain`
+    pub fn set_deletion_policy(&mut self, policy: impl Into<String>) -> &mut Self {
+        self.deletion_policy = Some(policy.into());
+        self
+    }
+}
+
+/// `DependsOn` may be given as either a single logical ID or a list of them
+fn one_or_many<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> std::result::Result<Vec<LogicalId>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(LogicalId),
+        Many(Vec<LogicalId>),
+    }
+    Ok(match OneOrMany::deserialize(deserializer)? {
+        OneOrMany::One(logical_id) => vec![logical_id],
+        OneOrMany::Many(logical_ids) => logical_ids,
+    })
+}
+
+/// An input parameter of a template
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Parameter {
+    #[serde(rename = "Type")]
+    pub type_: String,
+    #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(rename = "Default", skip_serializing_if = "Option::is_none")]
+    pub default: Option<Value>,
+    #[serde(
+        rename = "AllowedValues",
+        default,
+        skip_serializing_if = "Vec::is_empty"
+    )]
+    pub allowed_values: Vec<Value>,
+    #[serde(rename = "AllowedPattern", skip_serializing_if = "Option::is_none")]
+    pub allowed_pattern: Option<String>,
+    #[serde(
+        rename = "ConstraintDescription",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub constraint_description: Option<String>,
+    #[serde(rename = "MinLength", skip_serializing_if = "Option::is_none")]
+    pub min_length: Option<u32>,
+    #[serde(rename = "MaxLength", skip_serializing_if = "Option::is_none")]
+    pub max_length: Option<u32>,
+    #[serde(rename = "MinValue", skip_serializing_if = "Option::is_none")]
+    pub min_value: Option<f64>,
+    #[serde(rename = "MaxValue", skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<f64>,
+    #[serde(rename = "NoEcho", skip_serializing_if = "Option::is_none")]
+    pub no_echo: Option<bool>,
+}
+
+impl Parameter {
+    /// Create a new `Parameter` of `type_`, ie. `String` or `AWS::EC2::VPC::Id`
+    pub fn new(type_: impl Into<String>) -> Self {
+        Self {
+            type_: type_.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// A value exported by a template
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Output {
+    #[serde(rename = "Description", skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(rename = "Value")]
+    pub value: Value,
+    #[serde(rename = "Export", skip_serializing_if = "Option::is_none")]
+    pub export: Option<Export>,
+    #[serde(rename = "Condition", skip_serializing_if = "Option::is_none")]
+    pub condition: Option<String>,
+}
+
+impl Output {
+    /// Create a new `Output` of `value`, ie. an `Intrinsic::Ref`
+    pub fn new(value: impl Into<Value>) -> Self {
+        Self {
+            description: None,
+            value: value.into(),
+            export: None,
+            condition: None,
+        }
+    }
+}
+
+/// The name an output is exported under for use by other stacks
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Export {
+    #[serde(rename = "Name")]
+    pub name: Value,
+}
@@ -0,0 +1,600 @@
+use crate::cycles::{dependency_cycles, has_security_group};
+use crate::visit::{walk_references, References, Target};
+use crate::{InstanceTypeInfo, ResourceEntry, Template};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt;
+
+/// A problem found in a resource, output or condition of a template
+#[derive(Clone, Debug, PartialEq)]
+pub enum Issue {
+    /// `logical_id` references `target`, which isn't a resource, parameter or pseudo parameter
+    UnresolvedReference { logical_id: String, target: String },
+    /// The resource, output or condition `logical_id` uses `condition`, by its `Condition`
+    /// attribute, an `Fn::If` or a `Condition` function, which isn't declared in the template
+    UnknownCondition {
+        logical_id: String,
+        condition: String,
+    },
+    /// `condition` is declared, but no resource, output or other condition uses it
+    UnusedCondition { condition: String },
+    /// The DB cluster `logical_id` is a member of `global_cluster`, but its `property`,
+    /// `Engine` or `EngineVersion`, differs from the global cluster's
+    EngineMismatch {
+        logical_id: String,
+        global_cluster: String,
+        property: String,
+    },
+    /// The DynamoDB table `logical_id` uses `attribute` in the key schema of the table or one
+    /// of its indexes, but doesn't declare it in its `AttributeDefinitions`
+    UndefinedKeyAttribute {
+        logical_id: String,
+        attribute: String,
+    },
+    /// The SQS queue `logical_id` sets `property` inconsistently with `FifoQueue`: names of
+    /// FIFO queues end in `.fifo`, and only FIFO queues take `ContentBasedDeduplication`,
+    /// `DeduplicationScope` or `FifoThroughputLimit`
+    FifoMismatch {
+        logical_id: String,
+        property: String,
+    },
+    /// The EC2 instance or launch template `logical_id` sets its `CpuOptions` `property`,
+    /// `CoreCount` or `ThreadsPerCore`, to a value `instance_type` doesn't offer, or sets
+    /// `CpuOptions` at all for an instance type which doesn't take them
+    InvalidCpuOptions {
+        logical_id: String,
+        instance_type: String,
+        property: String,
+    },
+    /// The Spot Fleet or EC2 Fleet `logical_id` launches Spot Instances by the `lowestPrice`
+    /// allocation strategy `strategy`, which AWS advises against as the cheapest pools are
+    /// interrupted the most. `replacement` is the recommended strategy of the same resource.
+    DeprecatedAllocationStrategy {
+        logical_id: String,
+        strategy: String,
+        replacement: String,
+    },
+    /// The launch template `logical_id` places instances on the Dedicated Host of its
+    /// `HostId`, but doesn't set their `Tenancy` to `host`
+    HostIdWithoutHostTenancy { logical_id: String },
+    /// The resources of `cycle`, `logical_id` being the first, reference or depend on each
+    /// other in a loop, so none of them can be created first. `suggestion` is the usual fix.
+    DependencyCycle {
+        logical_id: String,
+        cycle: Vec<String>,
+        suggestion: String,
+    },
+}
+
+impl Issue {
+    /// The resource, output or condition this issue was found in
+    pub fn logical_id(&self) -> &str {
+        match self {
+            Issue::UnresolvedReference { logical_id, .. } => logical_id,
+            Issue::UnknownCondition { logical_id, .. } => logical_id,
+            Issue::UnusedCondition { condition } => condition,
+            Issue::EngineMismatch { logical_id, .. } => logical_id,
+            Issue::UndefinedKeyAttribute { logical_id, .. } => logical_id,
+            Issue::FifoMismatch { logical_id, .. } => logical_id,
+            Issue::InvalidCpuOptions { logical_id, .. } => logical_id,
+            Issue::DeprecatedAllocationStrategy { logical_id, .. } => logical_id,
+            Issue::HostIdWithoutHostTenancy { logical_id } => logical_id,
+            Issue::DependencyCycle { logical_id, .. } => logical_id,
+        }
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Issue::UnresolvedReference { logical_id, target } => {
+                write!(f, "{} references unknown logical ID {}", logical_id, target)
+            }
+            Issue::UnknownCondition {
+                logical_id,
+                condition,
+            } => write!(f, "{} uses unknown condition {}", logical_id, condition),
+            Issue::UnusedCondition { condition } => {
+                write!(f, "Condition {} is never used", condition)
+            }
+            Issue::EngineMismatch {
+                logical_id,
+                global_cluster,
+                property,
+            } => write!(
+                f,
+                "{} has a different {} than its global cluster {}",
+                logical_id, property, global_cluster
+            ),
+            Issue::UndefinedKeyAttribute {
+                logical_id,
+                attribute,
+            } => write!(
+                f,
+                "{} uses key attribute {} without defining it",
+                logical_id, attribute
+            ),
+            Issue::FifoMismatch {
+                logical_id,
+                property,
+            } => write!(
+                f,
+                "{} has a {} inconsistent with FifoQueue",
+                logical_id, property
+            ),
+            Issue::InvalidCpuOptions {
+                logical_id,
+                instance_type,
+                property,
+            } => write!(
+                f,
+                "{} has a {} not offered by instance type {}",
+                logical_id, property, instance_type
+            ),
+            Issue::DeprecatedAllocationStrategy {
+                logical_id,
+                strategy,
+                replacement,
+            } => write!(
+                f,
+                "{} uses the deprecated allocation strategy {}, use {} instead",
+                logical_id, strategy, replacement
+            ),
+            Issue::HostIdWithoutHostTenancy { logical_id } => {
+                write!(f, "{} has a HostId without host tenancy", logical_id)
+            }
+            Issue::DependencyCycle {
+                cycle, suggestion, ..
+            } => write!(
+                f,
+                "{} depend on each other in a cycle, {}",
+                cycle.join(", "),
+                suggestion
+            ),
+        }
+    }
+}
+
+/// Result of checking a single resource
+#[derive(Clone, Debug, Default)]
+struct Checked {
+    /// Logical IDs and condition names the result depends on
+    references: BTreeSet<String>,
+    issues: Vec<Issue>,
+}
+
+/// Validates the resources of a template and keeps the results, so after editing a resource
+/// only it and the resources referencing it need to be checked again.
+///
+/// Outputs, conditions and dependency cycles aren't covered, `Template::validate` checks them
+/// too.
+#[derive(Clone, Debug, Default)]
+pub struct Validator {
+    checked: BTreeMap<String, Checked>,
+}
+
+impl Validator {
+    /// Validate every resource of `template`
+    pub fn new(template: &Template) -> Self {
+        let checked = template
+            .resources()
+            .map(|(logical_id, entry)| (logical_id.to_string(), check(template, logical_id, entry)))
+            .collect();
+        Self { checked }
+    }
+
+    /// Re-check after the resource, parameter or condition `logical_id` was added, modified or
+    /// removed from `template`, returning the logical IDs of the resources which were checked
+    pub fn update(&mut self, template: &Template, logical_id: &str) -> Vec<String> {
+        let mut affected = self
+            .checked
+            .iter()
+            .filter(|(_, checked)| checked.references.contains(logical_id))
+            .map(|(id, _)| id.clone())
+            .collect::<BTreeSet<String>>();
+        affected.insert(logical_id.to_string());
+
+        let mut rechecked = vec![];
+        for id in affected {
+            match template.get(&id) {
+                Some(entry) => {
+                    let checked = check(template, &id, entry);
+                    self.checked.insert(id.clone(), checked);
+                    rechecked.push(id);
+                }
+                None => {
+                    self.checked.remove(&id);
+                }
+            }
+        }
+        rechecked
+    }
+
+    /// Issues currently found, ordered by logical ID
+    pub fn issues(&self) -> impl Iterator<Item = &Issue> {
+        self.checked
+            .values()
+            .flat_map(|checked| checked.issues.iter())
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.issues().next().is_none()
+    }
+}
+
+impl Template {
+    /// Check every resource for references to logical IDs and conditions this template
+    /// doesn't declare, outputs and conditions for conditions it doesn't declare, and report
+    /// conditions nothing uses, along with resources depending on each other in a cycle. See
+    /// `Validator` to re-check only the resources affected by an edit.
+    pub fn validate(&self) -> Vec<Issue> {
+        let mut issues = Validator::new(self)
+            .issues()
+            .cloned()
+            .collect::<Vec<Issue>>();
+        issues.extend(condition_issues(self));
+        issues.extend(cycle_issues(self));
+        issues
+    }
+}
+
+/// Unknown conditions used by outputs and by other conditions, and conditions nothing uses
+fn condition_issues(template: &Template) -> Vec<Issue> {
+    let declared = template
+        .conditions()
+        .map(|(name, _)| name)
+        .collect::<BTreeSet<&str>>();
+    let mut used = BTreeSet::new();
+    for (_, entry) in template.resources() {
+        let mut conditions = BTreeSet::new();
+        collect_references(&entry.properties, &mut BTreeSet::new(), &mut conditions);
+        // Resources were checked already, they only count towards the conditions used
+        used.extend(conditions);
+        used.extend(entry.condition.iter().cloned());
+    }
+    let mut issues = vec![];
+    let mut check = |logical_id: &str, conditions: BTreeSet<String>| {
+        for condition in conditions {
+            if !declared.contains(condition.as_str()) {
+                issues.push(Issue::UnknownCondition {
+                    logical_id: logical_id.to_string(),
+                    condition: condition.clone(),
+                });
+            }
+            used.insert(condition);
+        }
+    };
+
+    for (name, output) in template.outputs() {
+        let mut conditions = BTreeSet::new();
+        collect_references(&output.value, &mut BTreeSet::new(), &mut conditions);
+        conditions.extend(output.condition.iter().cloned());
+        check(name, conditions);
+    }
+    for (name, condition) in template.conditions() {
+        let mut conditions = BTreeSet::new();
+        collect_references(condition, &mut BTreeSet::new(), &mut conditions);
+        check(name, conditions);
+    }
+
+    issues.extend(
+        declared
+            .into_iter()
+            .filter(|name| !used.contains(*name))
+            .map(|name| Issue::UnusedCondition {
+                condition: name.to_string(),
+            }),
+    );
+    issues
+}
+
+/// Dependency cycles, security groups of a cycle usually reference each other by their rules
+fn cycle_issues(template: &Template) -> Vec<Issue> {
+    dependency_cycles(template)
+        .into_iter()
+        .map(|cycle| {
+            let suggestion = match has_security_group(template, &cycle) {
+                true => {
+                    "move the rules of the security groups referencing each other into \
+                         standalone AWS::EC2::SecurityGroupIngress and \
+                         AWS::EC2::SecurityGroupEgress resources, see Template::break_cycles"
+                }
+                false => "remove one of the references or DependsOn between them",
+            };
+            Issue::DependencyCycle {
+                logical_id: cycle[0].clone(),
+                cycle,
+                suggestion: suggestion.to_string(),
+            }
+        })
+        .collect()
+}
+
+fn check(template: &Template, logical_id: &str, entry: &ResourceEntry) -> Checked {
+    let mut targets = BTreeSet::new();
+    let mut conditions = BTreeSet::new();
+    collect_references(&entry.properties, &mut targets, &mut conditions);
+    targets.extend(entry.depends_on.iter().map(|id| id.to_string()));
+    conditions.extend(entry.condition.iter().cloned());
+
+    let declared_conditions = template
+        .conditions()
+        .map(|(name, _)| name)
+        .collect::<BTreeSet<&str>>();
+    let mut issues = vec![];
+    for target in targets.iter() {
+        if !target.starts_with("AWS::") && !template.contains(target) {
+            issues.push(Issue::UnresolvedReference {
+                logical_id: logical_id.to_string(),
+                target: target.clone(),
+            });
+        }
+    }
+    for condition in conditions.iter() {
+        if !declared_conditions.contains(condition.as_str()) {
+            issues.push(Issue::UnknownCondition {
+                logical_id: logical_id.to_string(),
+                condition: condition.clone(),
+            });
+        }
+    }
+    issues.extend(global_cluster_issues(template, logical_id, entry));
+    issues.extend(key_attribute_issues(logical_id, entry));
+    issues.extend(fifo_queue_issues(logical_id, entry));
+    issues.extend(cpu_options_issues(logical_id, entry));
+    issues.extend(allocation_strategy_issues(logical_id, entry));
+    issues.extend(host_tenancy_issues(logical_id, entry));
+    Checked {
+        references: targets.into_iter().chain(conditions).collect(),
+        issues,
+    }
+}
+
+/// Engine and version of a DB cluster have to match those of the global cluster it joins.
+/// Only literal values are compared, and only for global clusters of the same template.
+fn global_cluster_issues(
+    template: &Template,
+    logical_id: &str,
+    entry: &ResourceEntry,
+) -> Vec<Issue> {
+    let global_cluster = match entry.properties.get("GlobalClusterIdentifier") {
+        Some(Value::Object(map)) if entry.type_ == "AWS::RDS::DBCluster" => {
+            match map.get("Ref").and_then(Value::as_str) {
+                Some(target) => target,
+                None => return vec![],
+            }
+        }
+        _ => return vec![],
+    };
+    let global = match template.get(global_cluster) {
+        Some(global) if global.type_ == "AWS::RDS::GlobalCluster" => global,
+        _ => return vec![],
+    };
+    ["Engine", "EngineVersion"]
+        .iter()
+        .filter(|property| {
+            match (
+                entry.properties.get(**property),
+                global.properties.get(**property),
+            ) {
+                (Some(Value::String(member)), Some(Value::String(global))) => member != global,
+                _ => false,
+            }
+        })
+        .map(|property| Issue::EngineMismatch {
+            logical_id: logical_id.to_string(),
+            global_cluster: global_cluster.to_string(),
+            property: property.to_string(),
+        })
+        .collect()
+}
+
+/// Every attribute in the key schema of a DynamoDB table or its secondary indexes has to be
+/// declared in its `AttributeDefinitions`. Only literal attribute names are compared.
+fn key_attribute_issues(logical_id: &str, entry: &ResourceEntry) -> Vec<Issue> {
+    if entry.type_ != "AWS::DynamoDB::Table" && entry.type_ != "AWS::DynamoDB::GlobalTable" {
+        return vec![];
+    }
+    let defined = match entry.properties.get("AttributeDefinitions") {
+        Some(Value::Array(definitions)) => definitions
+            .iter()
+            .filter_map(|definition| definition.get("AttributeName"))
+            .collect::<Vec<&Value>>(),
+        _ => return vec![],
+    };
+    let indexes = ["GlobalSecondaryIndexes", "LocalSecondaryIndexes"]
+        .iter()
+        .filter_map(|property| entry.properties.get(*property))
+        .filter_map(Value::as_array)
+        .flatten();
+    let mut undefined = BTreeSet::new();
+    for key_schema in std::iter::once(&entry.properties)
+        .chain(indexes)
+        .filter_map(|value| value.get("KeySchema"))
+        .filter_map(Value::as_array)
+    {
+        undefined.extend(
+            key_schema
+                .iter()
+                .filter_map(|key| key.get("AttributeName"))
+                .filter(|name| !defined.contains(name))
+                .filter_map(Value::as_str),
+        );
+    }
+    undefined
+        .into_iter()
+        .map(|attribute| Issue::UndefinedKeyAttribute {
+            logical_id: logical_id.to_string(),
+            attribute: attribute.to_string(),
+        })
+        .collect()
+}
+
+/// A queue named `*.fifo` has to be a FIFO queue and the other way around, deduplication and
+/// high throughput settings are only accepted for FIFO queues. Intrinsic values are skipped.
+fn fifo_queue_issues(logical_id: &str, entry: &ResourceEntry) -> Vec<Issue> {
+    if entry.type_ != "AWS::SQS::Queue" {
+        return vec![];
+    }
+    let fifo = match entry.properties.get("FifoQueue") {
+        Some(Value::Bool(fifo)) => *fifo,
+        None => false,
+        _ => return vec![],
+    };
+    let mut properties = vec![];
+    if let Some(Value::String(name)) = entry.properties.get("QueueName") {
+        if name.ends_with(".fifo") != fifo {
+            properties.push("QueueName");
+        }
+    }
+    if !fifo {
+        if let Some(Value::Bool(true)) = entry.properties.get("ContentBasedDeduplication") {
+            properties.push("ContentBasedDeduplication");
+        }
+        properties.extend(
+            ["DeduplicationScope", "FifoThroughputLimit"]
+                .iter()
+                .filter(|property| entry.properties.get(**property).is_some()),
+        );
+    }
+    properties
+        .into_iter()
+        .map(|property| Issue::FifoMismatch {
+            logical_id: logical_id.to_string(),
+            property: property.to_string(),
+        })
+        .collect()
+}
+
+/// The `CpuOptions` of an EC2 instance or launch template have to be offered by its instance
+/// type. Only literal values of instance types known to the crate are checked.
+fn cpu_options_issues(logical_id: &str, entry: &ResourceEntry) -> Vec<Issue> {
+    let properties = match entry.type_.as_str() {
+        "AWS::EC2::Instance" => &entry.properties,
+        "AWS::EC2::LaunchTemplate" => match entry.properties.get("LaunchTemplateData") {
+            Some(data) => data,
+            None => return vec![],
+        },
+        _ => return vec![],
+    };
+    let (instance_type, cpu_options) = match (
+        properties.get("InstanceType").and_then(Value::as_str),
+        properties.get("CpuOptions"),
+    ) {
+        (Some(name), Some(cpu_options)) => match InstanceTypeInfo::get(name) {
+            Some(instance_type) => (instance_type, cpu_options),
+            None => return vec![],
+        },
+        _ => return vec![],
+    };
+    let invalid = |property: &str| Issue::InvalidCpuOptions {
+        logical_id: logical_id.to_string(),
+        instance_type: instance_type.name.to_string(),
+        property: property.to_string(),
+    };
+    if !instance_type.supports_cpu_options() {
+        return vec![invalid("CpuOptions")];
+    }
+    let value = |property: &str| cpu_options.get(property).and_then(Value::as_u64);
+    let mut issues = vec![];
+    if let Some(cores) = value("CoreCount") {
+        if !instance_type.accepts_cpu_options(Some(cores as u32), None) {
+            issues.push(invalid("CoreCount"));
+        }
+    }
+    if let Some(threads) = value("ThreadsPerCore") {
+        if !instance_type.accepts_cpu_options(None, Some(threads as u32)) {
+            issues.push(invalid("ThreadsPerCore"));
+        }
+    }
+    issues
+}
+
+/// Spot Instances shouldn't be launched by the `lowestPrice` allocation strategy anymore,
+/// `priceCapacityOptimized` gets almost the same prices at far fewer interruptions
+fn allocation_strategy_issues(logical_id: &str, entry: &ResourceEntry) -> Vec<Issue> {
+    let (options, replacement) = match entry.type_.as_str() {
+        "AWS::EC2::SpotFleet" => (
+            entry.properties.get("SpotFleetRequestConfigData"),
+            "priceCapacityOptimized",
+        ),
+        "AWS::EC2::EC2Fleet" => (
+            entry.properties.get("SpotOptions"),
+            "price-capacity-optimized",
+        ),
+        _ => return vec![],
+    };
+    match options
+        .and_then(|options| options.get("AllocationStrategy"))
+        .and_then(Value::as_str)
+    {
+        Some(strategy @ "lowestPrice") | Some(strategy @ "lowest-price") => {
+            vec![Issue::DeprecatedAllocationStrategy {
+                logical_id: logical_id.to_string(),
+                strategy: strategy.to_string(),
+                replacement: replacement.to_string(),
+            }]
+        }
+        _ => vec![],
+    }
+}
+
+/// Instances are only placed on the Dedicated Host of a launch template with host tenancy.
+/// A `Tenancy` computed by an intrinsic function isn't checked.
+fn host_tenancy_issues(logical_id: &str, entry: &ResourceEntry) -> Vec<Issue> {
+    if entry.type_ != "AWS::EC2::LaunchTemplate" {
+        return vec![];
+    }
+    let placement = match entry
+        .properties
+        .get("LaunchTemplateData")
+        .and_then(|data| data.get("Placement"))
+    {
+        Some(placement) if placement.get("HostId").is_some() => placement,
+        _ => return vec![],
+    };
+    match placement.get("Tenancy") {
+        Some(Value::String(tenancy)) if tenancy != "host" => {}
+        None => {}
+        _ => return vec![],
+    }
+    vec![Issue::HostIdWithoutHostTenancy {
+        logical_id: logical_id.to_string(),
+    }]
+}
+
+/// Logical IDs referenced by `Ref`, `Fn::GetAtt` and `Fn::Sub`, and conditions used by `Fn::If`
+/// and `Condition`
+pub(crate) fn collect_references(
+    value: &Value,
+    targets: &mut BTreeSet<String>,
+    conditions: &mut BTreeSet<String>,
+) {
+    struct Collect<'a> {
+        targets: &'a mut BTreeSet<String>,
+        conditions: &'a mut BTreeSet<String>,
+    }
+
+    impl References for Collect<'_> {
+        fn target(&mut self, logical_id: &str, _attribute: Option<&str>) -> Option<Target> {
+            self.targets.insert(logical_id.to_string());
+            None
+        }
+
+        fn resource(&mut self, logical_id: &str) -> Option<String> {
+            self.targets.insert(logical_id.to_string());
+            None
+        }
+
+        fn condition(&mut self, name: &str) -> Option<String> {
+            self.conditions.insert(name.to_string());
+            None
+        }
+    }
+
+    let mut collect = Collect {
+        targets,
+        conditions,
+    };
+    walk_references(&mut value.clone(), &mut collect);
+}
@@ -0,0 +1,115 @@
+use crate::visit::{self, PropertyPath, Segment, Visitor};
+use crate::{Output, Parameter, Resource, ResourceEntry, Template};
+use serde_json::Value;
+
+/// A read-only view of a template, for analysis such as linting or diffing which only needs
+/// to look at the template. Everything handed out borrows from the template, nothing is cloned.
+#[derive(Clone, Copy, Debug)]
+pub struct TemplateRef<'a> {
+    template: &'a Template,
+}
+
+impl Template {
+    pub fn view(&self) -> TemplateRef<'_> {
+        TemplateRef { template: self }
+    }
+}
+
+impl<'a> TemplateRef<'a> {
+    pub fn description(&self) -> Option<&'a str> {
+        self.template.description()
+    }
+
+    /// Resources, ordered by logical ID
+    pub fn resources(&self) -> impl Iterator<Item = ResourceRef<'a>> + 'a {
+        self.template
+            .resources()
+            .map(|(logical_id, entry)| ResourceRef { logical_id, entry })
+    }
+
+    /// Resources of type `R`, ordered by logical ID
+    pub fn resources_of<R: Resource>(&self) -> impl Iterator<Item = ResourceRef<'a>> + 'a {
+        self.resources()
+            .filter(|resource| resource.resource_type() == R::TYPE)
+    }
+
+    pub fn get(&self, logical_id: &str) -> Option<ResourceRef<'a>> {
+        self.template
+            .resources()
+            .find(|(id, _)| *id == logical_id)
+            .map(|(logical_id, entry)| ResourceRef { logical_id, entry })
+    }
+
+    pub fn parameters(&self) -> impl Iterator<Item = (&'a str, &'a Parameter)> {
+        self.template.parameters()
+    }
+
+    pub fn outputs(&self) -> impl Iterator<Item = (&'a str, &'a Output)> {
+        self.template.outputs()
+    }
+
+    /// Every property value of every resource, paths starting with the logical ID, see `Visitor`
+    pub fn values(&self) -> impl Iterator<Item = (PropertyPath<'a>, &'a Value)> {
+        self.resources().flat_map(|resource| resource.values())
+    }
+
+    /// Visit every property value of every resource, paths starting with the logical ID
+    pub fn walk(&self, visitor: &mut impl Visitor) {
+        self.resources().for_each(|resource| resource.walk(visitor));
+    }
+}
+
+/// A read-only view of a resource of a template, see `TemplateRef`
+#[derive(Clone, Copy, Debug)]
+pub struct ResourceRef<'a> {
+    logical_id: &'a str,
+    entry: &'a ResourceEntry,
+}
+
+impl<'a> ResourceRef<'a> {
+    pub fn logical_id(&self) -> &'a str {
+        self.logical_id
+    }
+
+    pub fn resource_type(&self) -> &'a str {
+        &self.entry.type_
+    }
+
+    pub fn entry(&self) -> &'a ResourceEntry {
+        self.entry
+    }
+
+    /// Top level properties, ordered by name
+    pub fn properties(&self) -> impl Iterator<Item = (&'a str, &'a Value)> {
+        self.entry
+            .properties
+            .as_object()
+            .into_iter()
+            .flat_map(|properties| properties.iter())
+            .map(|(name, value)| (name.as_str(), value))
+    }
+
+    pub fn property(&self, name: &str) -> Option<&'a Value> {
+        self.entry.properties.get(name)
+    }
+
+    /// Every property value, paths starting with the logical ID, see `Visitor`
+    pub fn values(&self) -> impl Iterator<Item = (PropertyPath<'a>, &'a Value)> {
+        let mut values = vec![];
+        self.traverse(&mut |path, value| values.push((path.clone(), value)));
+        values.into_iter()
+    }
+
+    /// Visit every property value, paths starting with the logical ID
+    pub fn walk(&self, visitor: &mut impl Visitor) {
+        self.traverse(&mut |path, value| visitor.visit(path, value));
+    }
+
+    fn traverse(&self, f: &mut dyn FnMut(&PropertyPath<'a>, &'a Value)) {
+        let mut path = PropertyPath::new();
+        path.push(Segment::Key(self.logical_id));
+        if let Value::Object(_) = &self.entry.properties {
+            visit::walk(&mut path, &self.entry.properties, f);
+        }
+    }
+}
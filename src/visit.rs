@@ -0,0 +1,448 @@
+use crate::Expr;
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use std::collections::{BTreeSet, HashMap};
+use std::fmt;
+
+/// Step of a `PropertyPath`, either a property name or map key, or a list index
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Segment<'a> {
+    Key(&'a str),
+    Index(usize),
+}
+
+/// Location of a value within a resource, ie. `Vpc.Tags[0].Key`. Paths of whole templates
+/// start with the logical ID of the resource.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PropertyPath<'a> {
+    segments: Vec<Segment<'a>>,
+}
+
+impl<'a> PropertyPath<'a> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn segments(&self) -> &[Segment<'a>] {
+        &self.segments
+    }
+
+    /// The innermost property name or map key, ie. `Key` for `Vpc.Tags[0].Key` and
+    /// `SecurityGroupIds` for `Instance.SecurityGroupIds[1]`
+    pub fn key(&self) -> Option<&'a str> {
+        self.segments
+            .iter()
+            .rev()
+            .find_map(|segment| match segment {
+                Segment::Key(key) => Some(*key),
+                Segment::Index(_) => None,
+            })
+    }
+
+    pub fn len(&self) -> usize {
+        self.segments.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.segments.is_empty()
+    }
+
+    /// Whether the path matches `pattern`, written like the paths themselves, ie.
+    /// `Instance.Tags[0].Key`. `*` matches any property name or key, `[*]` any index, and
+    /// `**` any number of steps, so `**.ImageId` matches every `ImageId` of a template.
+    pub fn matches(&self, pattern: &str) -> bool {
+        let pattern = pattern
+            .split('.')
+            .flat_map(|part| {
+                let (key, indices) = part.split_at(part.find('[').unwrap_or(part.len()));
+                std::iter::once(key)
+                    .filter(|key| !key.is_empty())
+                    .chain(indices.split_terminator(']'))
+            })
+            .collect::<Vec<&str>>();
+        matches(&self.segments, &pattern)
+    }
+
+    pub(crate) fn push(&mut self, segment: Segment<'a>) {
+        self.segments.push(segment);
+    }
+
+    pub(crate) fn pop(&mut self) {
+        self.segments.pop();
+    }
+}
+
+impl fmt::Display for PropertyPath<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (idx, segment) in self.segments.iter().enumerate() {
+            match segment {
+                Segment::Key(key) if idx == 0 => write!(f, "{}", key)?,
+                Segment::Key(key) => write!(f, ".{}", key)?,
+                Segment::Index(index) => write!(f, "[{}]", index)?,
+            }
+        }
+        Ok(())
+    }
+}
+
+fn matches(segments: &[Segment<'_>], pattern: &[&str]) -> bool {
+    match (pattern.split_first(), segments.split_first()) {
+        (None, None) => true,
+        (Some((&"**", rest)), _) => {
+            matches(segments, rest) || (!segments.is_empty() && matches(&segments[1..], pattern))
+        }
+        (Some((part, rest)), Some((segment, remaining))) => {
+            let matched = match (segment, part.strip_prefix('[')) {
+                (Segment::Index(_), Some("*")) => true,
+                (Segment::Index(index), Some(wanted)) => wanted.parse() == Ok(*index),
+                (Segment::Key(key), None) => *part == "*" || key == part,
+                _ => false,
+            };
+            matched && matches(remaining, rest)
+        }
+        _ => false,
+    }
+}
+
+/// Receives every value of a property tree, without the tree having to be cloned.
+///
+/// Lists and property types are descended into rather than visited, so values are either
+/// primitives or intrinsic functions, ie. `{"Ref": "Vpc"}`.
+pub trait Visitor {
+    fn visit(&mut self, path: &PropertyPath<'_>, value: &Value);
+}
+
+impl<F: FnMut(&PropertyPath<'_>, &Value)> Visitor for F {
+    fn visit(&mut self, path: &PropertyPath<'_>, value: &Value) {
+        self(path, value)
+    }
+}
+
+/// Walks the property tree of a typed value, implemented for every generated type so generic
+/// tooling can look at properties without per-type code, see `visit_properties`
+pub trait Walk {
+    /// Visit every value within `self`, located below `path`
+    fn walk<'a>(&'a self, path: &mut PropertyPath<'a>, visitor: &mut dyn Visitor);
+}
+
+impl<T: Serialize> Walk for Expr<T> {
+    fn walk<'a>(&'a self, path: &mut PropertyPath<'a>, visitor: &mut dyn Visitor) {
+        if let Ok(value) = serde_json::to_value(self) {
+            visitor.visit(path, &value);
+        }
+    }
+}
+
+impl Walk for Value {
+    fn walk<'a>(&'a self, path: &mut PropertyPath<'a>, visitor: &mut dyn Visitor) {
+        walk(path, self, &mut |path, value| visitor.visit(path, value));
+    }
+}
+
+impl<T: Walk> Walk for Option<T> {
+    fn walk<'a>(&'a self, path: &mut PropertyPath<'a>, visitor: &mut dyn Visitor) {
+        if let Some(value) = self {
+            value.walk(path, visitor);
+        }
+    }
+}
+
+impl<T: Walk> Walk for Vec<T> {
+    fn walk<'a>(&'a self, path: &mut PropertyPath<'a>, visitor: &mut dyn Visitor) {
+        for (idx, item) in self.iter().enumerate() {
+            path.push(Segment::Index(idx));
+            item.walk(path, visitor);
+            path.pop();
+        }
+    }
+}
+
+/// Entries are visited ordered by key, as they would be serialized
+impl<T: Walk> Walk for HashMap<String, T> {
+    fn walk<'a>(&'a self, path: &mut PropertyPath<'a>, visitor: &mut dyn Visitor) {
+        let mut entries = self.iter().collect::<Vec<(&String, &T)>>();
+        entries.sort_by_key(|(key, _)| *key);
+        for (key, value) in entries {
+            path.push(Segment::Key(key));
+            value.walk(path, visitor);
+            path.pop();
+        }
+    }
+}
+
+impl Walk for Map<String, Value> {
+    fn walk<'a>(&'a self, path: &mut PropertyPath<'a>, visitor: &mut dyn Visitor) {
+        for (key, value) in self.iter() {
+            path.push(Segment::Key(key));
+            value.walk(path, visitor);
+            path.pop();
+        }
+    }
+}
+
+/// Whether an object is an intrinsic function rather than a property value, ie. `{"Fn::If": ...}`
+pub(crate) fn is_intrinsic(values: &Map<String, Value>) -> bool {
+    values.len() == 1
+        && values
+            .keys()
+            .all(|key| key == "Ref" || key.starts_with("Fn::"))
+}
+
+/// Call `f` with every primitive or intrinsic within `value`, allowing it to be modified
+pub(crate) fn walk_mut<'a>(
+    path: &mut PropertyPath<'a>,
+    value: &'a mut Value,
+    f: &mut dyn FnMut(&PropertyPath<'a>, &mut Value),
+) {
+    let is_tree = match &*value {
+        Value::Object(values) => !is_intrinsic(values),
+        Value::Array(_) => true,
+        _ => false,
+    };
+    if !is_tree {
+        return f(path, value);
+    }
+    match value {
+        Value::Object(values) => {
+            for (key, value) in values.iter_mut() {
+                path.push(Segment::Key(key));
+                walk_mut(path, value, f);
+                path.pop();
+            }
+        }
+        Value::Array(items) => {
+            for (idx, item) in items.iter_mut().enumerate() {
+                path.push(Segment::Index(idx));
+                walk_mut(path, item, f);
+                path.pop();
+            }
+        }
+        _ => (),
+    }
+}
+
+/// Call `f` with every primitive or intrinsic within `value`, located at `path`
+pub(crate) fn walk<'a>(
+    path: &mut PropertyPath<'a>,
+    value: &'a Value,
+    f: &mut dyn FnMut(&PropertyPath<'a>, &'a Value),
+) {
+    match value {
+        Value::Object(values) if !is_intrinsic(values) => {
+            for (key, value) in values.iter() {
+                path.push(Segment::Key(key));
+                walk(path, value, f);
+                path.pop();
+            }
+        }
+        Value::Array(items) => {
+            for (idx, item) in items.iter().enumerate() {
+                path.push(Segment::Index(idx));
+                walk(path, item, f);
+                path.pop();
+            }
+        }
+        _ => f(path, value),
+    }
+}
+
+/// A resource or parameter referred to, with the attribute of `Fn::GetAtt`, see `References`
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct Target {
+    pub logical_id: String,
+    pub attribute: Option<String>,
+}
+
+/// Receives the references `walk_references` finds, returning what to refer to instead to
+/// rewrite them
+pub(crate) trait References {
+    /// A `Ref`, `Fn::GetAtt` or `Fn::Sub` variable of `logical_id`, with the `attribute` of
+    /// `Fn::GetAtt` and `${Name.Attribute}`. A `Fn::GetAtt` replaced by a target without an
+    /// attribute becomes a `Ref`.
+    fn target(&mut self, logical_id: &str, attribute: Option<&str>) -> Option<Target>;
+
+    /// The resource of an `Fn::GetAtt` without a literal attribute, ie. one computed by an
+    /// intrinsic function, returning the logical ID to use instead
+    fn resource(&mut self, _logical_id: &str) -> Option<String> {
+        None
+    }
+
+    /// A condition of `Fn::If` or `Condition`, returning the name to use instead
+    fn condition(&mut self, _name: &str) -> Option<String> {
+        None
+    }
+
+    /// A mapping of `Fn::FindInMap`, returning the name to use instead
+    fn mapping(&mut self, _name: &str) -> Option<String> {
+        None
+    }
+}
+
+/// Pass every reference to a resource, parameter, condition or mapping within `value` to
+/// `references`, replacing those it returns a replacement for
+pub(crate) fn walk_references(value: &mut Value, references: &mut dyn References) {
+    // The replacing target, and whether it's given as `Name.Attribute`
+    let mut replacement = None;
+    match value {
+        Value::Object(map) if map.len() == 1 => {
+            let (name, args) = map.iter_mut().next().expect("An entry");
+            match (name.as_str(), args) {
+                ("Ref", Value::String(logical_id)) => {
+                    replacement = references.target(logical_id, None).map(|t| (t, false));
+                }
+                ("Fn::GetAtt", Value::Array(parts)) => match parts.as_mut_slice() {
+                    [Value::String(logical_id), Value::String(attribute)] => {
+                        replacement = references
+                            .target(logical_id, Some(attribute))
+                            .map(|t| (t, false));
+                    }
+                    [Value::String(logical_id), attribute] => {
+                        if let Some(renamed) = references.resource(logical_id) {
+                            *logical_id = renamed;
+                        };
+                        walk_references(attribute, references);
+                    }
+                    parts => parts
+                        .iter_mut()
+                        .for_each(|part| walk_references(part, references)),
+                },
+                ("Fn::GetAtt", Value::String(path)) => match path.split_once('.') {
+                    Some((logical_id, attribute)) => {
+                        replacement = references
+                            .target(logical_id, Some(attribute))
+                            .map(|t| (t, true));
+                    }
+                    None => {
+                        if let Some(renamed) = references.resource(path) {
+                            *path = renamed;
+                        }
+                    }
+                },
+                ("Fn::Sub", Value::String(s)) => *s = sub(s, &BTreeSet::new(), references),
+                ("Fn::Sub", Value::Array(parts)) => {
+                    let defined = match parts.get(1) {
+                        Some(Value::Object(variables)) => variables.keys().cloned().collect(),
+                        _ => BTreeSet::new(),
+                    };
+                    if let Some(Value::String(s)) = parts.first_mut() {
+                        *s = sub(s, &defined, references);
+                    }
+                    parts
+                        .iter_mut()
+                        .skip(1)
+                        .for_each(|part| walk_references(part, references));
+                }
+                ("Fn::If", Value::Array(parts)) => {
+                    if let Some(Value::String(condition)) = parts.first_mut() {
+                        if let Some(renamed) = references.condition(condition) {
+                            *condition = renamed;
+                        };
+                    }
+                    parts
+                        .iter_mut()
+                        .for_each(|part| walk_references(part, references));
+                }
+                ("Condition", Value::String(condition)) => {
+                    if let Some(renamed) = references.condition(condition) {
+                        *condition = renamed;
+                    };
+                }
+                ("Fn::FindInMap", Value::Array(parts)) => {
+                    if let Some(Value::String(mapping)) = parts.first_mut() {
+                        if let Some(renamed) = references.mapping(mapping) {
+                            *mapping = renamed;
+                        };
+                    }
+                    parts
+                        .iter_mut()
+                        .for_each(|part| walk_references(part, references));
+                }
+                (_, args) => walk_references(args, references),
+            }
+        }
+        Value::Object(map) => map
+            .values_mut()
+            .for_each(|value| walk_references(value, references)),
+        Value::Array(items) => items
+            .iter_mut()
+            .for_each(|item| walk_references(item, references)),
+        _ => (),
+    }
+    if let Some((target, joined)) = replacement {
+        *value = match (target.attribute, joined) {
+            (None, _) => json!({ "Ref": target.logical_id }),
+            (Some(attribute), true) => {
+                json!({"Fn::GetAtt": format!("{}.{}", target.logical_id, attribute)})
+            }
+            (Some(attribute), false) => json!({"Fn::GetAtt": [target.logical_id, attribute]}),
+        };
+    }
+}
+
+/// `s` with its `${Name}` and `${Name.Attribute}` variables passed to `references` and
+/// replaced, leaving `defined` variables and literal `${!Name}` escapes alone
+fn sub(s: &str, defined: &BTreeSet<String>, references: &mut dyn References) -> String {
+    let mut rewritten = String::with_capacity(s.len());
+    for part in sub_parts(s) {
+        let variable = match part {
+            SubPart::Text(text) => {
+                rewritten.push_str(text);
+                continue;
+            }
+            SubPart::Variable(variable) => variable,
+        };
+        let (logical_id, attribute) = match variable.split_once('.') {
+            Some((logical_id, attribute)) => (logical_id, Some(attribute)),
+            None => (variable, None),
+        };
+        let replacement = match defined.contains(logical_id) {
+            true => None,
+            false => references.target(logical_id, attribute),
+        };
+        match replacement {
+            Some(Target {
+                logical_id,
+                attribute: Some(attribute),
+            }) => rewritten.push_str(&format!("${{{}.{}}}", logical_id, attribute)),
+            Some(Target { logical_id, .. }) => rewritten.push_str(&format!("${{{}}}", logical_id)),
+            None => rewritten.push_str(&format!("${{{}}}", variable)),
+        }
+    }
+    rewritten
+}
+
+/// Piece of an `Fn::Sub` string, see `sub_parts`
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum SubPart<'a> {
+    /// Literal text, including `${!Name}` escapes as written
+    Text(&'a str),
+    /// The `Name` or `Name.Attribute` of a `${Name.Attribute}` variable
+    Variable(&'a str),
+}
+
+/// The literal text and variables `s` is made of, in order
+pub(crate) fn sub_parts(s: &str) -> Vec<SubPart<'_>> {
+    let mut parts = vec![];
+    let mut rest = s;
+    while let Some(start) = rest.find("${") {
+        let end = match rest[start..].find('}') {
+            Some(end) => start + end,
+            None => break,
+        };
+        let variable = &rest[start + 2..end];
+        match variable.is_empty() || variable.starts_with('!') {
+            true => parts.push(SubPart::Text(&rest[..=end])),
+            false => {
+                if start > 0 {
+                    parts.push(SubPart::Text(&rest[..start]));
+                }
+                parts.push(SubPart::Variable(variable));
+            }
+        }
+        rest = &rest[end + 1..];
+    }
+    if !rest.is_empty() {
+        parts.push(SubPart::Text(rest));
+    }
+    parts
+}
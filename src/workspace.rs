@@ -0,0 +1,114 @@
+use crate::{IntoLogicalId, Resource, Result, Template};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard};
+
+type Templates = BTreeMap<String, Arc<Mutex<Template>>>;
+
+/// A set of named templates, ie. one stack per tenant, which may be shared between threads
+/// generating them in parallel.
+///
+/// Each template is locked on its own, so threads working on different templates don't wait
+/// on each other. Templates are always returned ordered by name, regardless of the order
+/// threads added them in.
+///
+/// A thread panicking while updating a template doesn't poison the workspace, the template
+/// is kept with whatever changes were made before the panic.
+#[derive(Debug, Default)]
+pub struct Workspace {
+    templates: RwLock<Templates>,
+}
+
+impl Workspace {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add `template` under `name`, returning the template it replaced, if any
+    pub fn insert(&self, name: impl Into<String>, template: Template) -> Option<Template> {
+        let mut templates = self.write();
+        templates
+            .insert(name.into(), Arc::new(Mutex::new(template)))
+            .map(|replaced| lock(&replaced).clone())
+    }
+
+    /// Modify the template `name`, creating an empty template if there's none yet
+    pub fn update<T>(&self, name: &str, f: impl FnOnce(&mut Template) -> T) -> T {
+        let template = self.entry(name);
+        let mut template = lock(&template);
+        f(&mut template)
+    }
+
+    /// Add a typed resource under `logical_id` to the template `name`, see `Template::add_resource`
+    pub fn add_resource<R: Resource>(
+        &self,
+        name: &str,
+        logical_id: impl IntoLogicalId,
+        resource: R,
+    ) -> Result<()> {
+        self.update(name, |template| {
+            template.add_resource(logical_id, resource).map(|_| ())
+        })
+    }
+
+    /// A snapshot of the template `name`, cheap to take as resources are shared with it
+    pub fn template(&self, name: &str) -> Option<Template> {
+        let templates = self.read();
+        templates.get(name).map(|template| lock(template).clone())
+    }
+
+    /// Names of all templates, in order
+    pub fn names(&self) -> Vec<String> {
+        let templates = self.read();
+        templates.keys().cloned().collect()
+    }
+
+    /// A snapshot of all templates, ordered by name
+    pub fn templates(&self) -> Vec<(String, Template)> {
+        let templates = self.read();
+        templates
+            .iter()
+            .map(|(name, template)| (name.clone(), lock(template).clone()))
+            .collect()
+    }
+
+    /// All templates, ordered by name
+    pub fn into_templates(self) -> BTreeMap<String, Template> {
+        self.templates()
+            .into_iter()
+            .collect::<BTreeMap<String, Template>>()
+    }
+
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn read(&self) -> RwLockReadGuard<'_, Templates> {
+        self.templates
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, Templates> {
+        self.templates
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn entry(&self, name: &str) -> Arc<Mutex<Template>> {
+        let templates = self.read();
+        if let Some(template) = templates.get(name) {
+            return template.clone();
+        }
+        drop(templates);
+        let mut templates = self.write();
+        templates.entry(name.to_string()).or_default().clone()
+    }
+}
+
+fn lock(template: &Mutex<Template>) -> MutexGuard<'_, Template> {
+    template.lock().unwrap_or_else(PoisonError::into_inner)
+}
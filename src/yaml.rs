@@ -0,0 +1,145 @@
+use crate::{Result, Template};
+use serde_yaml::value::{Mapping, TaggedValue, Value};
+use std::collections::BTreeMap;
+
+/// Resource `Metadata` key comments preceding a resource are kept under when parsing YAML
+pub const COMMENT_KEY: &str = "Caroline::Comment";
+
+impl Template {
+    /// Parse a template from YAML. Short forms of intrinsic functions are read as their long
+    /// form, ie. `!Ref MyVpc` as `Ref: MyVpc` and `!GetAtt MyVpc.CidrBlock` as
+    /// `Fn::GetAtt: [MyVpc, CidrBlock]`.
+    pub fn from_yaml(yaml: &str) -> Result<Self> {
+        let value: Value = serde_yaml::from_str(yaml)?;
+        Ok(serde_yaml::from_value(long_form(value))?)
+    }
+
+    /// Parse a template from YAML, keeping the comments preceding each resource in the
+    /// resource's `Metadata` under `COMMENT_KEY`, so `to_yaml` can write them back.
+    pub fn from_yaml_with_comments(yaml: &str) -> Result<Self> {
+        let mut template = Self::from_yaml(yaml)?;
+        for (logical_id, comment) in resource_comments(yaml) {
+            if let Some(entry) = template.get_mut(&logical_id) {
+                entry.metadata.insert(COMMENT_KEY, comment)?;
+            }
+        }
+        Ok(template)
+    }
+
+    /// Render the template as YAML, with comments kept under `COMMENT_KEY` written above
+    /// their resource rather than as metadata
+    pub fn to_yaml(&self) -> Result<String> {
+        let mut template = self.clone();
+        let mut comments = BTreeMap::new();
+        let commented = self
+            .resources()
+            .filter(|(_, entry)| entry.metadata.get(COMMENT_KEY).is_some())
+            .map(|(id, _)| id.to_string())
+            .collect::<Vec<String>>();
+        for logical_id in commented {
+            let entry = template.get_mut(&logical_id).expect("Resource exists");
+            if let Some(comment) = entry.metadata.remove(COMMENT_KEY) {
+                comments.insert(logical_id, comment.as_str().unwrap_or_default().to_string());
+            }
+        }
+
+        let yaml = serde_yaml::to_string(&template)?;
+        if comments.is_empty() {
+            return Ok(yaml);
+        }
+        let mut lines = vec![];
+        let mut in_resources = false;
+        for line in yaml.lines() {
+            if !line.starts_with(' ') {
+                in_resources = line == "Resources:";
+            } else if in_resources && !line.starts_with("   ") {
+                let logical_id = line.trim().trim_end_matches(':').trim_matches('"');
+                if let Some(comment) = comments.get(logical_id) {
+                    lines.extend(comment.lines().map(|text| match text.is_empty() {
+                        true => "  #".to_string(),
+                        false => format!("  # {}", text),
+                    }));
+                }
+            }
+            lines.push(line.to_string());
+        }
+        Ok(lines.join("\n") + "\n")
+    }
+}
+
+/// `value` with the short forms of intrinsic functions replaced by their long form, ie.
+/// `!Sub "${Env}-logs"` by `Fn::Sub: "${Env}-logs"`
+fn long_form(value: Value) -> Value {
+    match value {
+        Value::Sequence(items) => Value::Sequence(items.into_iter().map(long_form).collect()),
+        Value::Mapping(mapping) => Value::Mapping(
+            mapping
+                .into_iter()
+                .map(|(key, value)| (key, long_form(value)))
+                .collect(),
+        ),
+        Value::Tagged(tagged) => {
+            let TaggedValue { tag, value } = *tagged;
+            let function = tag.to_string();
+            let function = function.trim_start_matches('!');
+            let (key, value) = match (function, long_form(value)) {
+                ("Ref", value) | ("Condition", value) => (function.to_string(), value),
+                // The attribute name may contain dots itself, ie. `Db.Endpoint.Port`
+                ("GetAtt", Value::String(name)) => {
+                    let value = match name.split_once('.') {
+                        Some((logical_id, attribute)) => Value::Sequence(vec![
+                            Value::String(logical_id.to_string()),
+                            Value::String(attribute.to_string()),
+                        ]),
+                        None => Value::String(name),
+                    };
+                    ("Fn::GetAtt".to_string(), value)
+                }
+                (_, value) => (format!("Fn::{}", function), value),
+            };
+            let mut mapping = Mapping::new();
+            mapping.insert(Value::String(key), value);
+            Value::Mapping(mapping)
+        }
+        value => value,
+    }
+}
+
+/// Comment lines directly preceding each resource of a YAML template, keyed by logical ID
+fn resource_comments(yaml: &str) -> BTreeMap<String, String> {
+    let mut comments = BTreeMap::new();
+    let mut in_resources = false;
+    let mut indent = None;
+    let mut pending: Vec<&str> = vec![];
+
+    for line in yaml.lines() {
+        let trimmed = line.trim_start();
+        let line_indent = line.len() - trimmed.len();
+        if trimmed.is_empty() {
+            pending.clear();
+            continue;
+        }
+        if let Some(text) = trimmed.strip_prefix('#') {
+            if in_resources {
+                pending.push(text.strip_prefix(' ').unwrap_or(text));
+            }
+            continue;
+        }
+        if line_indent == 0 {
+            in_resources = trimmed.trim_end() == "Resources:";
+            indent = None;
+            pending.clear();
+            continue;
+        }
+        if in_resources && *indent.get_or_insert(line_indent) == line_indent {
+            if let Some(logical_id) = trimmed.trim_end().strip_suffix(':') {
+                if !pending.is_empty() {
+                    let logical_id = logical_id.trim_matches(|c| c == '"' || c == '\'');
+                    comments.insert(logical_id.to_string(), pending.join("\n"));
+                }
+            }
+        }
+        pending.clear();
+    }
+    comments
+}
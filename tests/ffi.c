@@ -0,0 +1,42 @@
+/* Links against the library built with the `ffi` feature, checking include/caroline.h
+ * matches it, see the `ffi` job of .circleci/config.yml */
+#include <stdio.h>
+#include <string.h>
+
+#include "caroline.h"
+
+int main(void) {
+    const char *json =
+        "{\"Resources\": {\"Topic\": {\"Type\": \"AWS::SNS::Topic\", \"Properties\": {}}}}";
+    CarolineTemplate *template_ = NULL;
+    if (caroline_template_from_json((const uint8_t *)json, strlen(json), &template_) !=
+        CAROLINE_OK) {
+        CarolineBuffer error = caroline_last_error();
+        fprintf(stderr, "%.*s\n", (int)error.len, (const char *)error.data);
+        caroline_buffer_free(error);
+        return 1;
+    }
+
+    /* The issues are written, and have to be freed, whether there are any or not */
+    CarolineBuffer issues;
+    int32_t status = caroline_template_validate(template_, &issues);
+    if (status == CAROLINE_INVALID_TEMPLATE) {
+        fprintf(stderr, "%.*s\n", (int)issues.len, (const char *)issues.data);
+    }
+    if (status == CAROLINE_OK || status == CAROLINE_INVALID_TEMPLATE) {
+        caroline_buffer_free(issues);
+    }
+    if (status != CAROLINE_OK) {
+        caroline_template_free(template_);
+        return 1;
+    }
+
+    CarolineBuffer yaml;
+    if (caroline_template_to_yaml(template_, &yaml) != CAROLINE_OK) {
+        return 1;
+    }
+    printf("%.*s\n", (int)yaml.len, (const char *)yaml.data);
+    caroline_buffer_free(yaml);
+    caroline_template_free(template_);
+    return 0;
+}
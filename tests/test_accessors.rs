@@ -0,0 +1,53 @@
+use caroline::types::AWS::ApplicationAutoScaling::ScalableTarget::{
+    ScalableTarget, ScalableTargetBuilder, SuspendedState,
+};
+use caroline::types::AWS::AutoScaling::AutoScalingGroup::AutoScalingGroupBuilder;
+use caroline::types::AWS::EC2::NetworkAclEntry::Icmp;
+use caroline::{Expr, Intrinsic, Template};
+use serde_json::json;
+
+#[test]
+fn test_accessors() {
+    let mut target = ScalableTargetBuilder::default()
+        .MaxCapacity(4)
+        .MinCapacity(1)
+        .ResourceId("service/default/web")
+        .RoleARN(Intrinsic::get_att("ScalingRole", "Arn").unwrap())
+        .ScalableDimension("ecs:service:DesiredCount")
+        .ServiceNamespace("ecs")
+        .build()
+        .unwrap();
+    assert_eq!(target.max_capacity(), &Expr::Literal(4));
+    assert!(target.suspended_state().is_none());
+
+    target
+        .set_max_capacity(10)
+        .set_suspended_state(SuspendedState::default());
+    assert_eq!(target.MaxCapacity, Expr::Literal(10));
+    assert!(target.suspended_state().is_some());
+
+    let mut template = Template::new();
+    template.add_resource("Target", target).unwrap();
+    let target = template.resource::<ScalableTarget>("Target").unwrap();
+    assert_eq!(target.max_capacity(), &Expr::Literal(10));
+    assert_eq!(
+        template.get("Target").unwrap().properties["MaxCapacity"],
+        json!(10)
+    );
+
+    // Acronyms are kept together, keywords are escaped
+    let mut group = AutoScalingGroupBuilder::default()
+        .MaxSize("2")
+        .MinSize("1")
+        .build()
+        .unwrap();
+    group
+        .set_target_group_arns(vec![Expr::from("arn:aws:elasticloadbalancing:target")])
+        .set_vpc_zone_identifier(vec![Expr::from("subnet-12345678")]);
+    assert_eq!(group.target_group_arns().map(Vec::len), Some(1));
+    assert_eq!(group.service_linked_role_arn(), None);
+
+    let mut icmp = Icmp::default();
+    icmp.set_type(8);
+    assert_eq!(icmp.r#type(), Some(&Expr::Literal(8)));
+}
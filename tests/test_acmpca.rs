@@ -0,0 +1,76 @@
+use caroline::types::AWS::ACMPCA::Certificate::{
+    ApiPassthroughBuilder, CertificateBuilder, ExtensionsBuilder, GeneralNameBuilder,
+    ValidityBuilder,
+};
+use caroline::types::AWS::ACMPCA::CertificateAuthority::{
+    CertificateAuthorityBuilder, SubjectBuilder,
+};
+use caroline::types::AWS::ACMPCA::CertificateAuthorityActivation::CertificateAuthorityActivationBuilder;
+use caroline::{Intrinsic, Template};
+use serde_json::json;
+
+#[test]
+fn test_private_root_ca() {
+    let authority = CertificateAuthorityBuilder::default()
+        .Type("ROOT")
+        .KeyAlgorithm("EC_prime256v1")
+        .SigningAlgorithm("SHA256WITHECDSA")
+        .Subject(
+            SubjectBuilder::default()
+                .CommonName("Internal Root CA")
+                .Organization("Example")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let ca_certificate = CertificateBuilder::default()
+        .CertificateAuthorityArn(Intrinsic::Ref("RootCA".into()))
+        .CertificateSigningRequest(
+            Intrinsic::get_att("RootCA", "CertificateSigningRequest").unwrap(),
+        )
+        .SigningAlgorithm("SHA256WITHECDSA")
+        .TemplateArn("arn:aws:acm-pca:::template/RootCACertificate/V1")
+        .Validity(
+            ValidityBuilder::default()
+                .Type("YEARS")
+                .Value(10.0)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let activation = CertificateAuthorityActivationBuilder::default()
+        .CertificateAuthorityArn(Intrinsic::Ref("RootCA".into()))
+        .Certificate(Intrinsic::get_att("RootCACertificate", "Certificate").unwrap())
+        .Status("ACTIVE")
+        .build()
+        .unwrap();
+    let passthrough = ApiPassthroughBuilder::default()
+        .Extensions(
+            ExtensionsBuilder::default()
+                .SubjectAlternativeNames(vec![GeneralNameBuilder::default()
+                    .DnsName("api.internal.example.com")
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("RootCA", authority).unwrap();
+    template
+        .add_resource("RootCACertificate", ca_certificate)
+        .unwrap();
+    template.add_resource("Activation", activation).unwrap();
+    assert_eq!(
+        template.get("RootCA").unwrap().properties["Subject"],
+        json!({"CommonName": "Internal Root CA", "Organization": "Example"})
+    );
+    assert_eq!(
+        serde_json::to_value(passthrough).unwrap(),
+        json!({"Extensions": {"SubjectAlternativeNames": [{"DnsName": "api.internal.example.com"}]}})
+    );
+}
@@ -0,0 +1,108 @@
+use caroline::types::AWS::AmazonMQ::Broker::{Broker, BrokerBuilder, UserBuilder};
+use caroline::types::AWS::AmazonMQ::Configuration::{Configuration, ConfigurationBuilder};
+use caroline::types::AWS::AmazonMQ::ConfigurationAssociation::{
+    ConfigurationAssociation, ConfigurationAssociationBuilder, ConfigurationIdBuilder,
+};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_broker() {
+    let broker = BrokerBuilder::default()
+        .BrokerName("broker")
+        .DeploymentMode("SINGLE_INSTANCE")
+        .EngineType("ACTIVEMQ")
+        .EngineVersion("5.17.6")
+        .HostInstanceType("mq.t3.micro")
+        .PubliclyAccessible(false)
+        .Users(vec![UserBuilder::default()
+            .Password("{{resolve:secretsmanager:broker-admin:SecretString:password}}")
+            .Username("admin")
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Broker", broker).unwrap();
+    let entry = template.get("Broker").unwrap();
+    assert_eq!(entry.type_, "AWS::AmazonMQ::Broker");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "BrokerName": "broker",
+            "DeploymentMode": "SINGLE_INSTANCE",
+            "EngineType": "ACTIVEMQ",
+            "EngineVersion": "5.17.6",
+            "HostInstanceType": "mq.t3.micro",
+            "PubliclyAccessible": false,
+            "Users": [{
+                "Password": "{{resolve:secretsmanager:broker-admin:SecretString:password}}",
+                "Username": "admin"
+            }]
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let broker = parsed.resource::<Broker>("Broker").unwrap();
+    assert_eq!(serde_json::to_value(broker).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_configuration() {
+    let configuration = ConfigurationBuilder::default()
+        .EngineType("ACTIVEMQ")
+        .Name("configuration")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("Configuration", configuration)
+        .unwrap();
+    let entry = template.get("Configuration").unwrap();
+    assert_eq!(entry.type_, "AWS::AmazonMQ::Configuration");
+    assert_eq!(
+        entry.properties,
+        json!({"EngineType": "ACTIVEMQ", "Name": "configuration"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let configuration = parsed.resource::<Configuration>("Configuration").unwrap();
+    assert_eq!(
+        serde_json::to_value(configuration).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_configuration_association() {
+    let configuration_association = ConfigurationAssociationBuilder::default()
+        .Broker("b-0123456789")
+        .Configuration(
+            ConfigurationIdBuilder::default()
+                .Id("c-0123456789")
+                .Revision(1)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("ConfigurationAssociation", configuration_association)
+        .unwrap();
+    let entry = template.get("ConfigurationAssociation").unwrap();
+    assert_eq!(entry.type_, "AWS::AmazonMQ::ConfigurationAssociation");
+    assert_eq!(
+        entry.properties,
+        json!({"Broker": "b-0123456789", "Configuration": {"Id": "c-0123456789", "Revision": 1}})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let configuration_association = parsed
+        .resource::<ConfigurationAssociation>("ConfigurationAssociation")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(configuration_association).unwrap(),
+        entry.properties
+    );
+}
@@ -0,0 +1,185 @@
+use caroline::types::AWS::AppConfig::Application::{Application, ApplicationBuilder};
+use caroline::types::AWS::AppConfig::ConfigurationProfile::{
+    ConfigurationProfile, ConfigurationProfileBuilder,
+};
+use caroline::types::AWS::AppConfig::Deployment::{Deployment, DeploymentBuilder};
+use caroline::types::AWS::AppConfig::DeploymentStrategy::{
+    DeploymentStrategy, DeploymentStrategyBuilder,
+};
+use caroline::types::AWS::AppConfig::Environment::{Environment, EnvironmentBuilder};
+use caroline::types::AWS::AppConfig::HostedConfigurationVersion::{
+    HostedConfigurationVersion, HostedConfigurationVersionBuilder,
+};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_application() {
+    let application = ApplicationBuilder::default()
+        .Name("application")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Application", application).unwrap();
+    let entry = template.get("Application").unwrap();
+    assert_eq!(entry.type_, "AWS::AppConfig::Application");
+    assert_eq!(entry.properties, json!({"Name": "application"}));
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let application = parsed.resource::<Application>("Application").unwrap();
+    assert_eq!(serde_json::to_value(application).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_configuration_profile() {
+    let configuration_profile = ConfigurationProfileBuilder::default()
+        .ApplicationId("application-0123456789")
+        .LocationUri("hosted")
+        .Name("configuration-profile")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("ConfigurationProfile", configuration_profile)
+        .unwrap();
+    let entry = template.get("ConfigurationProfile").unwrap();
+    assert_eq!(entry.type_, "AWS::AppConfig::ConfigurationProfile");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "ApplicationId": "application-0123456789",
+            "LocationUri": "hosted",
+            "Name": "configuration-profile"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let configuration_profile = parsed
+        .resource::<ConfigurationProfile>("ConfigurationProfile")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(configuration_profile).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_deployment() {
+    let deployment = DeploymentBuilder::default()
+        .ApplicationId("application-0123456789")
+        .ConfigurationProfileId("configuration-profile-0123456789")
+        .ConfigurationVersion("1")
+        .DeploymentStrategyId("deployment-strategy-0123456789")
+        .EnvironmentId("environment-0123456789")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Deployment", deployment).unwrap();
+    let entry = template.get("Deployment").unwrap();
+    assert_eq!(entry.type_, "AWS::AppConfig::Deployment");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "ApplicationId": "application-0123456789",
+            "ConfigurationProfileId": "configuration-profile-0123456789",
+            "ConfigurationVersion": "1",
+            "DeploymentStrategyId": "deployment-strategy-0123456789",
+            "EnvironmentId": "environment-0123456789"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let deployment = parsed.resource::<Deployment>("Deployment").unwrap();
+    assert_eq!(serde_json::to_value(deployment).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_deployment_strategy() {
+    let deployment_strategy = DeploymentStrategyBuilder::default()
+        .DeploymentDurationInMinutes(10)
+        .GrowthFactor(25.0)
+        .Name("deployment-strategy")
+        .ReplicateTo("NONE")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("DeploymentStrategy", deployment_strategy)
+        .unwrap();
+    let entry = template.get("DeploymentStrategy").unwrap();
+    assert_eq!(entry.type_, "AWS::AppConfig::DeploymentStrategy");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "DeploymentDurationInMinutes": 10,
+            "GrowthFactor": 25.0,
+            "Name": "deployment-strategy",
+            "ReplicateTo": "NONE"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let deployment_strategy = parsed
+        .resource::<DeploymentStrategy>("DeploymentStrategy")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(deployment_strategy).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_environment() {
+    let environment = EnvironmentBuilder::default()
+        .ApplicationId("application-0123456789")
+        .Name("environment")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Environment", environment).unwrap();
+    let entry = template.get("Environment").unwrap();
+    assert_eq!(entry.type_, "AWS::AppConfig::Environment");
+    assert_eq!(
+        entry.properties,
+        json!({"ApplicationId": "application-0123456789", "Name": "environment"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let environment = parsed.resource::<Environment>("Environment").unwrap();
+    assert_eq!(serde_json::to_value(environment).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_hosted_configuration_version() {
+    let hosted_configuration_version = HostedConfigurationVersionBuilder::default()
+        .ApplicationId("application-0123456789")
+        .ConfigurationProfileId("configuration-profile-0123456789")
+        .Content("{\"feature\": true}")
+        .ContentType("application/json")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("HostedConfigurationVersion", hosted_configuration_version)
+        .unwrap();
+    let entry = template.get("HostedConfigurationVersion").unwrap();
+    assert_eq!(entry.type_, "AWS::AppConfig::HostedConfigurationVersion");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "ApplicationId": "application-0123456789",
+            "ConfigurationProfileId": "configuration-profile-0123456789",
+            "Content": "{\"feature\": true}",
+            "ContentType": "application/json"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let hosted_configuration_version = parsed
+        .resource::<HostedConfigurationVersion>("HostedConfigurationVersion")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(hosted_configuration_version).unwrap(),
+        entry.properties
+    );
+}
@@ -0,0 +1,103 @@
+use caroline::types::AWS::AppFlow::ConnectorProfile::{
+    ConnectorProfileBuilder, ConnectorProfileConfigBuilder, ConnectorProfileCredentialsBuilder,
+    SnowflakeConnectorProfileCredentialsBuilder,
+};
+use caroline::types::AWS::AppFlow::Flow::{
+    DestinationConnectorProperties, DestinationFlowConfigBuilder, FlowBuilder,
+    RedshiftDestinationPropertiesBuilder, S3SourcePropertiesBuilder, SourceConnectorProperties,
+    SourceFlowConfigBuilder, TaskBuilder, TriggerConfigBuilder,
+};
+use caroline::{DebugUnredacted, Template};
+use serde_json::json;
+
+#[test]
+fn test_s3_to_redshift_flow() {
+    let source = SourceConnectorProperties {
+        S3: Some(
+            S3SourcePropertiesBuilder::default()
+                .BucketName("landing")
+                .BucketPrefix("orders")
+                .build()
+                .unwrap(),
+        ),
+        ..SourceConnectorProperties::default()
+    };
+    let destination = DestinationConnectorProperties {
+        Redshift: Some(
+            RedshiftDestinationPropertiesBuilder::default()
+                .Object("public.orders")
+                .IntermediateBucketName("staging")
+                .build()
+                .unwrap(),
+        ),
+        ..DestinationConnectorProperties::default()
+    };
+    let flow = FlowBuilder::default()
+        .FlowName("orders-to-redshift")
+        .TriggerConfig(
+            TriggerConfigBuilder::default()
+                .TriggerType("OnDemand")
+                .build()
+                .unwrap(),
+        )
+        .SourceFlowConfig(
+            SourceFlowConfigBuilder::default()
+                .ConnectorType("S3")
+                .SourceConnectorProperties(source)
+                .build()
+                .unwrap(),
+        )
+        .DestinationFlowConfigList(vec![DestinationFlowConfigBuilder::default()
+            .ConnectorType("Redshift")
+            .ConnectorProfileName("warehouse")
+            .DestinationConnectorProperties(destination)
+            .build()
+            .unwrap()])
+        .Tasks(vec![TaskBuilder::default()
+            .TaskType("Map_all")
+            .SourceFields(vec![])
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Orders", flow).unwrap();
+    let properties = &template.get("Orders").unwrap().properties;
+    assert_eq!(
+        properties["SourceFlowConfig"]["SourceConnectorProperties"],
+        json!({"S3": {"BucketName": "landing", "BucketPrefix": "orders"}})
+    );
+    assert_eq!(
+        properties["DestinationFlowConfigList"][0]["DestinationConnectorProperties"],
+        json!({"Redshift": {"Object": "public.orders", "IntermediateBucketName": "staging"}})
+    );
+}
+
+#[test]
+fn test_connector_credentials_are_redacted() {
+    let credentials = ConnectorProfileCredentialsBuilder::default()
+        .Snowflake(
+            SnowflakeConnectorProfileCredentialsBuilder::default()
+                .Username("loader")
+                .Password("hunter2")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let profile = ConnectorProfileBuilder::default()
+        .ConnectorProfileName("warehouse")
+        .ConnectorType("Snowflake")
+        .ConnectionMode("Public")
+        .ConnectorProfileConfig(
+            ConnectorProfileConfigBuilder::default()
+                .ConnectorProfileCredentials(credentials)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    assert!(!format!("{:?}", profile).contains("hunter2"));
+    assert!(format!("{:?}", profile.debug_unredacted()).contains("hunter2"));
+}
@@ -0,0 +1,56 @@
+use caroline::types::AWS::APS::RuleGroupsNamespace::{
+    RuleGroupsNamespace, RuleGroupsNamespaceBuilder,
+};
+use caroline::types::AWS::APS::Workspace::{Workspace, WorkspaceBuilder};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_rule_groups_namespace() {
+    let rule_groups_namespace = RuleGroupsNamespaceBuilder::default()
+        .Data("groups:\n  - name: example\n    rules: []\n")
+        .Name("alerts")
+        .Workspace("arn:aws:aps:us-east-1:123456789012:workspace/ws-0123456789")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("RuleGroupsNamespace", rule_groups_namespace)
+        .unwrap();
+    let entry = template.get("RuleGroupsNamespace").unwrap();
+    assert_eq!(entry.type_, "AWS::APS::RuleGroupsNamespace");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "Data": "groups:\n  - name: example\n    rules: []\n",
+            "Name": "alerts",
+            "Workspace": "arn:aws:aps:us-east-1:123456789012:workspace/ws-0123456789"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let rule_groups_namespace = parsed
+        .resource::<RuleGroupsNamespace>("RuleGroupsNamespace")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(rule_groups_namespace).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_workspace() {
+    let workspace = WorkspaceBuilder::default()
+        .Alias("metrics")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Workspace", workspace).unwrap();
+    let entry = template.get("Workspace").unwrap();
+    assert_eq!(entry.type_, "AWS::APS::Workspace");
+    assert_eq!(entry.properties, json!({"Alias": "metrics"}));
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let workspace = parsed.resource::<Workspace>("Workspace").unwrap();
+    assert_eq!(serde_json::to_value(workspace).unwrap(), entry.properties);
+}
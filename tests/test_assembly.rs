@@ -0,0 +1,53 @@
+use caroline::types::AWS::EC2::VPC::VPCBuilder;
+use caroline::{AssemblyAsset, CloudAssembly, Packaging, Template};
+use serde_json::Value;
+use std::fs;
+
+#[test]
+fn test_cloud_assembly() {
+    let mut template = Template::new();
+    template
+        .add_resource(
+            "Vpc",
+            VPCBuilder::default()
+                .CidrBlock("10.0.0.0/16")
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+
+    let mut assembly = CloudAssembly::new("Network", template.clone());
+    assembly.add_asset(AssemblyAsset {
+        source_hash: "abc123".to_string(),
+        path: "lambda".into(),
+        packaging: Packaging::Zip,
+    });
+
+    let dir = std::env::temp_dir().join("caroline-test-cloud-assembly");
+    assembly.write(&dir).unwrap();
+
+    let read = |file: &str| -> Value {
+        serde_json::from_str(&fs::read_to_string(dir.join(file)).unwrap()).unwrap()
+    };
+    let manifest = read("manifest.json");
+    assert_eq!(
+        manifest["artifacts"]["Network"]["properties"]["templateFile"],
+        "Network.template.json"
+    );
+    assert_eq!(
+        manifest["artifacts"]["Network.assets"]["type"],
+        "cdk:asset-manifest"
+    );
+
+    let assets = read("Network.assets.json");
+    let destination = &assets["files"]["abc123"]["destinations"]["current_account-current_region"];
+    assert_eq!(destination["objectKey"], "abc123.zip");
+    assert_eq!(
+        destination["bucketName"],
+        "cdk-hnb659fds-assets-${AWS::AccountId}-${AWS::Region}"
+    );
+
+    let written = fs::read_to_string(dir.join("Network.template.json")).unwrap();
+    assert_eq!(Template::from_json(&written).unwrap(), template);
+    fs::remove_dir_all(&dir).unwrap();
+}
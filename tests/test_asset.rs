@@ -0,0 +1,58 @@
+use caroline::types::AWS::Lambda::Function::{CodeBuilder, FunctionBuilder};
+use caroline::{Asset, AssetResolver, CloudAssembly, Intrinsic, Template};
+use serde_json::{json, Value};
+use std::fs;
+
+#[test]
+fn test_asset_resolution() {
+    let dir = std::env::temp_dir().join("caroline-test-asset");
+    fs::create_dir_all(dir.join("lib")).unwrap();
+    fs::write(dir.join("index.py"), "def handler(event, context): pass").unwrap();
+    fs::write(dir.join("lib").join("util.py"), "").unwrap();
+
+    let asset = Asset::directory(&dir).unwrap();
+    assert_eq!(asset.hash().len(), 64);
+    assert_eq!(asset.object_key(), format!("{}.zip", asset.hash()));
+    assert_eq!(Asset::directory(&dir).unwrap(), asset);
+
+    fs::write(dir.join("lib").join("util.py"), "import os").unwrap();
+    assert_ne!(Asset::directory(&dir).unwrap().hash(), asset.hash());
+    fs::remove_dir_all(&dir).unwrap();
+
+    let code = CodeBuilder::default()
+        .S3Bucket(asset.bucket())
+        .S3Key(asset.key())
+        .build()
+        .unwrap();
+    let function = FunctionBuilder::default()
+        .Code(code)
+        .Handler("index.handler")
+        .Role(Intrinsic::get_att("Role", "Arn").unwrap())
+        .Runtime("python3.8")
+        .build()
+        .unwrap();
+    let mut template = Template::new();
+    template.add_resource("Function", function).unwrap();
+
+    let mut staging = template.clone();
+    let resolved = AssetResolver::new("artifacts")
+        .prefix("lambda/")
+        .resolve(&mut staging);
+    assert!(resolved.contains(&asset.object_key()));
+    let code = &staging.get("Function").unwrap().properties["Code"];
+    assert_eq!(code["S3Bucket"], "artifacts");
+    assert_eq!(
+        code["S3Key"],
+        Value::String(format!("lambda/{}", asset.object_key()))
+    );
+
+    let mut assembly = CloudAssembly::new("Functions", template.clone());
+    assembly.add(&asset);
+    AssetResolver::for_assembly(&assembly).resolve(&mut template);
+    let code = &template.get("Function").unwrap().properties["Code"];
+    assert_eq!(
+        code["S3Bucket"],
+        json!({"Fn::Sub": "cdk-hnb659fds-assets-${AWS::AccountId}-${AWS::Region}"})
+    );
+    assert_eq!(assembly.assets()[0].source_hash, asset.hash());
+}
@@ -0,0 +1,58 @@
+use caroline::types::AWS::AutoScalingPlans::ScalingPlan::{
+    ApplicationSourceBuilder, ScalingInstructionBuilder, ScalingPlan, ScalingPlanBuilder,
+    TargetTrackingConfigurationBuilder,
+};
+use caroline::{Intrinsic, Template};
+use serde_json::json;
+
+#[test]
+fn test_scaling_plan() {
+    let scaling_plan = ScalingPlanBuilder::default()
+        .ApplicationSource(
+            ApplicationSourceBuilder::default()
+                .CloudFormationStackARN(Intrinsic::Ref("AWS::StackId".into()))
+                .build()
+                .unwrap(),
+        )
+        .ScalingInstructions(vec![ScalingInstructionBuilder::default()
+            .MaxCapacity(4)
+            .MinCapacity(1)
+            .PredictiveScalingMode("ForecastAndScale")
+            .ResourceId("autoScalingGroup/web")
+            .ScalableDimension("autoscaling:autoScalingGroup:DesiredCapacity")
+            .ServiceNamespace("autoscaling")
+            .TargetTrackingConfigurations(vec![TargetTrackingConfigurationBuilder::default()
+                .TargetValue(50.0)
+                .build()
+                .unwrap()])
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("ScalingPlan", scaling_plan).unwrap();
+    let entry = template.get("ScalingPlan").unwrap();
+    assert_eq!(entry.type_, "AWS::AutoScalingPlans::ScalingPlan");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "ApplicationSource": {"CloudFormationStackARN": {"Ref": "AWS::StackId"}},
+            "ScalingInstructions": [{
+                "MaxCapacity": 4,
+                "MinCapacity": 1,
+                "PredictiveScalingMode": "ForecastAndScale",
+                "ResourceId": "autoScalingGroup/web",
+                "ScalableDimension": "autoscaling:autoScalingGroup:DesiredCapacity",
+                "ServiceNamespace": "autoscaling",
+                "TargetTrackingConfigurations": [{"TargetValue": 50.0}]
+            }]
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let scaling_plan = parsed.resource::<ScalingPlan>("ScalingPlan").unwrap();
+    assert_eq!(
+        serde_json::to_value(scaling_plan).unwrap(),
+        entry.properties
+    );
+}
@@ -0,0 +1,29 @@
+use caroline::types::AWS::Budgets::Budget::{Budget, BudgetBuilder, BudgetDataBuilder};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_budget() {
+    let budget = BudgetBuilder::default()
+        .Budget(
+            BudgetDataBuilder::default()
+                .BudgetType("COST")
+                .TimeUnit("MONTHLY")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Budget", budget).unwrap();
+    let entry = template.get("Budget").unwrap();
+    assert_eq!(entry.type_, "AWS::Budgets::Budget");
+    assert_eq!(
+        entry.properties,
+        json!({"Budget": {"BudgetType": "COST", "TimeUnit": "MONTHLY"}})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let budget = parsed.resource::<Budget>("Budget").unwrap();
+    assert_eq!(serde_json::to_value(budget).unwrap(), entry.properties);
+}
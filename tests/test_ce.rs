@@ -0,0 +1,73 @@
+use caroline::types::AWS::CE::AnomalyMonitor::{AnomalyMonitor, AnomalyMonitorBuilder};
+use caroline::types::AWS::CE::AnomalySubscription::{
+    AnomalySubscription, AnomalySubscriptionBuilder, SubscriberBuilder,
+};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_anomaly_monitor() {
+    let anomaly_monitor = AnomalyMonitorBuilder::default()
+        .MonitorName("anomaly-monitor")
+        .MonitorType("DIMENSIONAL")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("AnomalyMonitor", anomaly_monitor)
+        .unwrap();
+    let entry = template.get("AnomalyMonitor").unwrap();
+    assert_eq!(entry.type_, "AWS::CE::AnomalyMonitor");
+    assert_eq!(
+        entry.properties,
+        json!({"MonitorName": "anomaly-monitor", "MonitorType": "DIMENSIONAL"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let anomaly_monitor = parsed.resource::<AnomalyMonitor>("AnomalyMonitor").unwrap();
+    assert_eq!(
+        serde_json::to_value(anomaly_monitor).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_anomaly_subscription() {
+    let anomaly_subscription = AnomalySubscriptionBuilder::default()
+        .Frequency("DAILY")
+        .MonitorArnList(vec![
+            "arn:aws:ce::123456789012:anomalymonitor/0123456789".into()
+        ])
+        .Subscribers(vec![SubscriberBuilder::default()
+            .Address("finance@example.com")
+            .Type("EMAIL")
+            .build()
+            .unwrap()])
+        .SubscriptionName("anomaly-subscription")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("AnomalySubscription", anomaly_subscription)
+        .unwrap();
+    let entry = template.get("AnomalySubscription").unwrap();
+    assert_eq!(entry.type_, "AWS::CE::AnomalySubscription");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "Frequency": "DAILY",
+            "MonitorArnList": ["arn:aws:ce::123456789012:anomalymonitor/0123456789"],
+            "Subscribers": [{"Address": "finance@example.com", "Type": "EMAIL"}],
+            "SubscriptionName": "anomaly-subscription"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let anomaly_subscription = parsed
+        .resource::<AnomalySubscription>("AnomalySubscription")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(anomaly_subscription).unwrap(),
+        entry.properties
+    );
+}
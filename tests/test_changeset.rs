@@ -0,0 +1,53 @@
+use caroline::types::AWS::EC2::VPC::VPCBuilder;
+use caroline::types::AWS::S3::Bucket::BucketBuilder;
+use caroline::{Action, ChangeSet, Replacement, Template, UpdateType};
+
+fn template(cidr: &str, dns_support: bool) -> Template {
+    let vpc = VPCBuilder::default()
+        .CidrBlock(cidr)
+        .EnableDnsSupport(dns_support)
+        .build()
+        .unwrap();
+    let mut template = Template::new();
+    template.add_resource("Vpc", vpc).unwrap();
+    template
+}
+
+#[test]
+fn test_in_place_update() {
+    let before = template("10.0.0.0/16", true);
+    let after = template("10.0.0.0/16", false);
+
+    let changes = ChangeSet::between(&before, &after);
+    let vpc = changes.get("Vpc").unwrap();
+    assert_eq!(vpc.action, Action::Modify);
+    assert_eq!(vpc.replacement, Replacement::False);
+    assert_eq!(vpc.properties[0].name, "EnableDnsSupport");
+    assert_eq!(vpc.properties[0].update_type, Some(UpdateType::Mutable));
+    assert_eq!(changes.destructive().count(), 0);
+    assert!(ChangeSet::between(&before, &before).is_empty());
+}
+
+#[test]
+fn test_destructive_changes() {
+    let mut before = template("10.0.0.0/16", true);
+    before
+        .add_resource("Logs", BucketBuilder::default().build().unwrap())
+        .unwrap();
+    before
+        .add_resource("Archive", BucketBuilder::default().build().unwrap())
+        .unwrap()
+        .set_deletion_policy("Retain");
+    let after = template("10.1.0.0/16", false);
+
+    let changes = ChangeSet::between(&before, &after);
+    assert_eq!(changes.get("Vpc").unwrap().replacement, Replacement::True);
+    assert_eq!(changes.get("Logs").unwrap().action, Action::Remove);
+    assert!(!changes.get("Archive").unwrap().is_destructive());
+
+    let destructive = changes
+        .destructive()
+        .map(|change| change.logical_id.as_str())
+        .collect::<Vec<&str>>();
+    assert_eq!(destructive, vec!["Logs", "Vpc"]);
+}
@@ -0,0 +1,73 @@
+use caroline::types::AWS::Chatbot::MicrosoftTeamsChannelConfiguration::{
+    MicrosoftTeamsChannelConfiguration, MicrosoftTeamsChannelConfigurationBuilder,
+};
+use caroline::types::AWS::Chatbot::SlackChannelConfiguration::{
+    SlackChannelConfiguration, SlackChannelConfigurationBuilder,
+};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_microsoft_teams_channel_configuration() {
+    let teams = MicrosoftTeamsChannelConfigurationBuilder::default()
+        .ConfigurationName("alerts")
+        .IamRoleArn("arn:aws:iam::123456789012:role/chatbot")
+        .TeamId("0a1b2c3d-4e5f-6a7b-8c9d-0e1f2a3b4c5d")
+        .TeamsChannelId("19:0123456789abcdef@thread.tacv2")
+        .TeamsTenantId("5d4c3b2a-1f0e-9d8c-7b6a-5f4e3d2c1b0a")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Teams", teams).unwrap();
+    let entry = template.get("Teams").unwrap();
+    assert_eq!(
+        entry.type_,
+        "AWS::Chatbot::MicrosoftTeamsChannelConfiguration"
+    );
+    assert_eq!(
+        entry.properties,
+        json!({
+            "ConfigurationName": "alerts",
+            "IamRoleArn": "arn:aws:iam::123456789012:role/chatbot",
+            "TeamId": "0a1b2c3d-4e5f-6a7b-8c9d-0e1f2a3b4c5d",
+            "TeamsChannelId": "19:0123456789abcdef@thread.tacv2",
+            "TeamsTenantId": "5d4c3b2a-1f0e-9d8c-7b6a-5f4e3d2c1b0a"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let teams = parsed
+        .resource::<MicrosoftTeamsChannelConfiguration>("Teams")
+        .unwrap();
+    assert_eq!(serde_json::to_value(teams).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_slack_channel_configuration() {
+    let slack = SlackChannelConfigurationBuilder::default()
+        .ConfigurationName("alerts")
+        .IamRoleArn("arn:aws:iam::123456789012:role/chatbot")
+        .SlackChannelId("C0123456789")
+        .SlackWorkspaceId("T0123456789")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Slack", slack).unwrap();
+    let entry = template.get("Slack").unwrap();
+    assert_eq!(entry.type_, "AWS::Chatbot::SlackChannelConfiguration");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "ConfigurationName": "alerts",
+            "IamRoleArn": "arn:aws:iam::123456789012:role/chatbot",
+            "SlackChannelId": "C0123456789",
+            "SlackWorkspaceId": "T0123456789"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let slack = parsed
+        .resource::<SlackChannelConfiguration>("Slack")
+        .unwrap();
+    assert_eq!(serde_json::to_value(slack).unwrap(), entry.properties);
+}
@@ -0,0 +1,29 @@
+use caroline::types::AWS::Cloud9::EnvironmentEC2::{EnvironmentEC2, EnvironmentEC2Builder};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_environment_ec2() {
+    let environment_ec2 = EnvironmentEC2Builder::default()
+        .ImageId("amazonlinux-2023-x86_64")
+        .InstanceType("t3.small")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("EnvironmentEC2", environment_ec2)
+        .unwrap();
+    let entry = template.get("EnvironmentEC2").unwrap();
+    assert_eq!(entry.type_, "AWS::Cloud9::EnvironmentEC2");
+    assert_eq!(
+        entry.properties,
+        json!({"ImageId": "amazonlinux-2023-x86_64", "InstanceType": "t3.small"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let environment_ec2 = parsed.resource::<EnvironmentEC2>("EnvironmentEC2").unwrap();
+    assert_eq!(
+        serde_json::to_value(environment_ec2).unwrap(),
+        entry.properties
+    );
+}
@@ -0,0 +1,70 @@
+use caroline::types::AWS::CloudFront::Function::{
+    FunctionBuilder, FunctionConfigBuilder, KeyValueStoreAssociationBuilder,
+};
+use caroline::types::AWS::CloudFront::KeyValueStore::KeyValueStoreBuilder;
+use caroline::types::AWS::CloudFront::OriginAccessControl::{
+    OriginAccessControlBuilder, OriginAccessControlConfigBuilder,
+};
+use caroline::{Intrinsic, Template};
+use serde_json::json;
+
+#[test]
+fn test_viewer_request_function() {
+    let store = KeyValueStoreBuilder::default()
+        .Name("redirects")
+        .build()
+        .unwrap();
+    let function = FunctionBuilder::default()
+        .Name("redirect-legacy-paths")
+        .AutoPublish(true)
+        .FunctionCode("async function handler(event) { return event.request; }")
+        .FunctionConfig(
+            FunctionConfigBuilder::default()
+                .Comment("Redirect legacy paths")
+                .Runtime("cloudfront-js-2.0")
+                .KeyValueStoreAssociations(vec![KeyValueStoreAssociationBuilder::default()
+                    .KeyValueStoreARN(Intrinsic::get_att("Redirects", "Arn").unwrap())
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let access_control = OriginAccessControlBuilder::default()
+        .OriginAccessControlConfig(
+            OriginAccessControlConfigBuilder::default()
+                .Name("assets")
+                .OriginAccessControlOriginType("s3")
+                .SigningBehavior("always")
+                .SigningProtocol("sigv4")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Redirects", store).unwrap();
+    template.add_resource("RedirectLegacy", function).unwrap();
+    template
+        .add_resource("AssetsAccess", access_control)
+        .unwrap();
+    let properties = &template.get("RedirectLegacy").unwrap().properties;
+    assert_eq!(properties["AutoPublish"], json!(true));
+    assert_eq!(
+        properties["FunctionConfig"],
+        json!({
+            "Comment": "Redirect legacy paths",
+            "Runtime": "cloudfront-js-2.0",
+            "KeyValueStoreAssociations": [
+                {"KeyValueStoreARN": {"Fn::GetAtt": ["Redirects", "Arn"]}}
+            ]
+        })
+    );
+    assert_eq!(
+        template.get("AssetsAccess").unwrap().properties["OriginAccessControlConfig"]
+            ["SigningBehavior"],
+        json!("always")
+    );
+}
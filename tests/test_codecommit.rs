@@ -0,0 +1,39 @@
+use caroline::types::AWS::CodeCommit::Repository::{RepositoryBuilder, RepositoryTriggerBuilder};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_repository_with_triggers() {
+    let trigger = RepositoryTriggerBuilder::default()
+        .Name("notify")
+        .DestinationArn("arn:aws:sns:us-east-1:123456789012:topic")
+        .Events(vec!["all".into()])
+        .build()
+        .unwrap();
+
+    let repo = RepositoryBuilder::default()
+        .RepositoryName("caroline")
+        .Triggers(vec![trigger])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Repository", repo).unwrap();
+    let entry = template.get("Repository").unwrap();
+    assert_eq!(entry.type_, "AWS::CodeCommit::Repository");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "RepositoryName": "caroline",
+            "Triggers": [{
+                "Name": "notify",
+                "DestinationArn": "arn:aws:sns:us-east-1:123456789012:topic",
+                "Events": ["all"]
+            }]
+        })
+    );
+    assert_eq!(
+        Template::from_json(&template.to_json().unwrap()).unwrap(),
+        template
+    );
+}
@@ -0,0 +1,77 @@
+use caroline::enums::{ComputePlatform, MinimumHealthyHostsType, TrafficRoutingType};
+use caroline::types::AWS::CodeDeploy::Application::ApplicationBuilder;
+use caroline::types::AWS::CodeDeploy::DeploymentConfig::{
+    DeploymentConfig, DeploymentConfigBuilder, MinimumHealthyHostsBuilder, TimeBasedCanaryBuilder,
+    TrafficRoutingConfigBuilder,
+};
+use caroline::{Expr, Template};
+use serde_json::json;
+
+#[test]
+fn test_deployment_config() {
+    let application = ApplicationBuilder::default()
+        .ComputePlatform(ComputePlatform::Lambda)
+        .build()
+        .unwrap();
+    let canary = DeploymentConfigBuilder::default()
+        .ComputePlatform(ComputePlatform::Lambda)
+        .TrafficRoutingConfig(
+            TrafficRoutingConfigBuilder::default()
+                .Type(TrafficRoutingType::TimeBasedCanary)
+                .TimeBasedCanary(
+                    TimeBasedCanaryBuilder::default()
+                        .CanaryInterval(5)
+                        .CanaryPercentage(10)
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let half_at_a_time = DeploymentConfigBuilder::default()
+        .ComputePlatform(ComputePlatform::Server)
+        .MinimumHealthyHosts(
+            MinimumHealthyHostsBuilder::default()
+                .Type(MinimumHealthyHostsType::FleetPercent)
+                .Value(50)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Application", application).unwrap();
+    template.add_resource("Canary", canary).unwrap();
+    template
+        .add_resource("HalfAtATime", half_at_a_time)
+        .unwrap();
+    assert_eq!(
+        template.get("Application").unwrap().properties,
+        json!({"ComputePlatform": "Lambda"})
+    );
+    assert_eq!(
+        template.get("Canary").unwrap().properties,
+        json!({
+            "ComputePlatform": "Lambda",
+            "TrafficRoutingConfig": {
+                "Type": "TimeBasedCanary",
+                "TimeBasedCanary": {"CanaryInterval": 5, "CanaryPercentage": 10}
+            }
+        })
+    );
+    assert_eq!(
+        template.get("HalfAtATime").unwrap().properties["MinimumHealthyHosts"],
+        json!({"Type": "FLEET_PERCENT", "Value": 50})
+    );
+
+    let config = template
+        .resource::<DeploymentConfig>("HalfAtATime")
+        .unwrap();
+    assert_eq!(
+        config.MinimumHealthyHosts.unwrap().Type,
+        Expr::Literal(MinimumHealthyHostsType::FleetPercent)
+    );
+}
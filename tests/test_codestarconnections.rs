@@ -0,0 +1,24 @@
+use caroline::types::AWS::CodeStarConnections::Connection::{Connection, ConnectionBuilder};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_connection() {
+    let connection = ConnectionBuilder::default()
+        .ConnectionName("github")
+        .ProviderType("GitHub")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Connection", connection).unwrap();
+    let entry = template.get("Connection").unwrap();
+    assert_eq!(entry.type_, "AWS::CodeStarConnections::Connection");
+    assert_eq!(
+        entry.properties,
+        json!({"ConnectionName": "github", "ProviderType": "GitHub"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let connection = parsed.resource::<Connection>("Connection").unwrap();
+    assert_eq!(serde_json::to_value(connection).unwrap(), entry.properties);
+}
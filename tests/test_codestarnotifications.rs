@@ -0,0 +1,49 @@
+use caroline::types::AWS::CodeStarNotifications::NotificationRule::{
+    NotificationRule, NotificationRuleBuilder, TargetBuilder,
+};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_notification_rule() {
+    let notification_rule = NotificationRuleBuilder::default()
+        .DetailType("FULL")
+        .EventTypeIds(vec!["codecommit-repository-pull-request-created".into()])
+        .Name("pull-requests")
+        .Resource("arn:aws:codecommit:us-east-1:123456789012:caroline")
+        .Targets(vec![TargetBuilder::default()
+            .TargetAddress("arn:aws:sns:us-east-1:123456789012:notifications")
+            .TargetType("SNS")
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("NotificationRule", notification_rule)
+        .unwrap();
+    let entry = template.get("NotificationRule").unwrap();
+    assert_eq!(entry.type_, "AWS::CodeStarNotifications::NotificationRule");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "DetailType": "FULL",
+            "EventTypeIds": ["codecommit-repository-pull-request-created"],
+            "Name": "pull-requests",
+            "Resource": "arn:aws:codecommit:us-east-1:123456789012:caroline",
+            "Targets": [{
+                "TargetAddress": "arn:aws:sns:us-east-1:123456789012:notifications",
+                "TargetType": "SNS"
+            }]
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let notification_rule = parsed
+        .resource::<NotificationRule>("NotificationRule")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(notification_rule).unwrap(),
+        entry.properties
+    );
+}
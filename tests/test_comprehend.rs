@@ -0,0 +1,76 @@
+use caroline::types::AWS::Comprehend::DocumentClassifier::{
+    DocumentClassifier, DocumentClassifierBuilder, DocumentClassifierInputDataConfigBuilder,
+};
+use caroline::types::AWS::Comprehend::Flywheel::{Flywheel, FlywheelBuilder};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_document_classifier() {
+    let document_classifier = DocumentClassifierBuilder::default()
+        .DataAccessRoleArn("arn:aws:iam::123456789012:role/comprehend")
+        .DocumentClassifierName("support-tickets")
+        .InputDataConfig(
+            DocumentClassifierInputDataConfigBuilder::default()
+                .DataFormat("COMPREHEND_CSV")
+                .S3Uri("s3://classifier-data/train.csv")
+                .build()
+                .unwrap(),
+        )
+        .LanguageCode("en")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("DocumentClassifier", document_classifier)
+        .unwrap();
+    let entry = template.get("DocumentClassifier").unwrap();
+    assert_eq!(entry.type_, "AWS::Comprehend::DocumentClassifier");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "DataAccessRoleArn": "arn:aws:iam::123456789012:role/comprehend",
+            "DocumentClassifierName": "support-tickets",
+            "InputDataConfig": {
+                "DataFormat": "COMPREHEND_CSV",
+                "S3Uri": "s3://classifier-data/train.csv"
+            },
+            "LanguageCode": "en"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let document_classifier = parsed
+        .resource::<DocumentClassifier>("DocumentClassifier")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(document_classifier).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_flywheel() {
+    let flywheel = FlywheelBuilder::default()
+        .DataAccessRoleArn("arn:aws:iam::123456789012:role/comprehend")
+        .DataLakeS3Uri("s3://flywheel-data/")
+        .FlywheelName("support-tickets")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Flywheel", flywheel).unwrap();
+    let entry = template.get("Flywheel").unwrap();
+    assert_eq!(entry.type_, "AWS::Comprehend::Flywheel");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "DataAccessRoleArn": "arn:aws:iam::123456789012:role/comprehend",
+            "DataLakeS3Uri": "s3://flywheel-data/",
+            "FlywheelName": "support-tickets"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let flywheel = parsed.resource::<Flywheel>("Flywheel").unwrap();
+    assert_eq!(serde_json::to_value(flywheel).unwrap(), entry.properties);
+}
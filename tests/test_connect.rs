@@ -0,0 +1,170 @@
+use caroline::types::AWS::Connect::ContactFlow::{ContactFlow, ContactFlowBuilder};
+use caroline::types::AWS::Connect::HoursOfOperation::{
+    HoursOfOperation, HoursOfOperationBuilder, HoursOfOperationConfigBuilder,
+    HoursOfOperationTimeSlice, HoursOfOperationTimeSliceBuilder,
+};
+use caroline::types::AWS::Connect::Instance::{AttributesBuilder, Instance, InstanceBuilder};
+use caroline::types::AWS::Connect::QuickConnect::{
+    PhoneNumberQuickConnectConfigBuilder, QuickConnect, QuickConnectBuilder,
+    QuickConnectConfigBuilder,
+};
+use caroline::{Intrinsic, Template};
+use serde_json::json;
+
+fn time(hours: i32, minutes: i32) -> HoursOfOperationTimeSlice {
+    HoursOfOperationTimeSliceBuilder::default()
+        .Hours(hours)
+        .Minutes(minutes)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_contact_flow() {
+    let contact_flow = ContactFlowBuilder::default()
+        .Content("{\"Version\": \"2019-10-30\", \"Actions\": []}")
+        .InstanceArn(Intrinsic::get_att("Instance", "Arn").unwrap())
+        .Name("inbound")
+        .Type("CONTACT_FLOW")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("ContactFlow", contact_flow).unwrap();
+    let entry = template.get("ContactFlow").unwrap();
+    assert_eq!(entry.type_, "AWS::Connect::ContactFlow");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "Content": "{\"Version\": \"2019-10-30\", \"Actions\": []}",
+            "InstanceArn": {"Fn::GetAtt": ["Instance", "Arn"]},
+            "Name": "inbound",
+            "Type": "CONTACT_FLOW"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let contact_flow = parsed.resource::<ContactFlow>("ContactFlow").unwrap();
+    assert_eq!(
+        serde_json::to_value(contact_flow).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_hours_of_operation() {
+    let hours_of_operation = HoursOfOperationBuilder::default()
+        .Config(vec![HoursOfOperationConfigBuilder::default()
+            .Day("MONDAY")
+            .EndTime(time(17, 0))
+            .StartTime(time(9, 0))
+            .build()
+            .unwrap()])
+        .InstanceArn(Intrinsic::get_att("Instance", "Arn").unwrap())
+        .Name("office-hours")
+        .TimeZone("America/New_York")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("HoursOfOperation", hours_of_operation)
+        .unwrap();
+    let entry = template.get("HoursOfOperation").unwrap();
+    assert_eq!(entry.type_, "AWS::Connect::HoursOfOperation");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "Config": [{
+                "Day": "MONDAY",
+                "EndTime": {"Hours": 17, "Minutes": 0},
+                "StartTime": {"Hours": 9, "Minutes": 0}
+            }],
+            "InstanceArn": {"Fn::GetAtt": ["Instance", "Arn"]},
+            "Name": "office-hours",
+            "TimeZone": "America/New_York"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let hours_of_operation = parsed
+        .resource::<HoursOfOperation>("HoursOfOperation")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(hours_of_operation).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_instance() {
+    let instance = InstanceBuilder::default()
+        .Attributes(
+            AttributesBuilder::default()
+                .InboundCalls(true)
+                .OutboundCalls(false)
+                .build()
+                .unwrap(),
+        )
+        .IdentityManagementType("CONNECT_MANAGED")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Instance", instance).unwrap();
+    let entry = template.get("Instance").unwrap();
+    assert_eq!(entry.type_, "AWS::Connect::Instance");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "Attributes": {"InboundCalls": true, "OutboundCalls": false},
+            "IdentityManagementType": "CONNECT_MANAGED"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let instance = parsed.resource::<Instance>("Instance").unwrap();
+    assert_eq!(serde_json::to_value(instance).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_quick_connect() {
+    let quick_connect = QuickConnectBuilder::default()
+        .InstanceArn(Intrinsic::get_att("Instance", "Arn").unwrap())
+        .Name("front-desk")
+        .QuickConnectConfig(
+            QuickConnectConfigBuilder::default()
+                .PhoneConfig(
+                    PhoneNumberQuickConnectConfigBuilder::default()
+                        .PhoneNumber("+12065550100")
+                        .build()
+                        .unwrap(),
+                )
+                .QuickConnectType("PHONE_NUMBER")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("QuickConnect", quick_connect)
+        .unwrap();
+    let entry = template.get("QuickConnect").unwrap();
+    assert_eq!(entry.type_, "AWS::Connect::QuickConnect");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "InstanceArn": {"Fn::GetAtt": ["Instance", "Arn"]},
+            "Name": "front-desk",
+            "QuickConnectConfig": {
+                "PhoneConfig": {"PhoneNumber": "+12065550100"},
+                "QuickConnectType": "PHONE_NUMBER"
+            }
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let quick_connect = parsed.resource::<QuickConnect>("QuickConnect").unwrap();
+    assert_eq!(
+        serde_json::to_value(quick_connect).unwrap(),
+        entry.properties
+    );
+}
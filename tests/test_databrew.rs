@@ -0,0 +1,97 @@
+use caroline::types::AWS::DataBrew::Dataset::{
+    Dataset, DatasetBuilder, InputBuilder, S3LocationBuilder,
+};
+use caroline::types::AWS::DataBrew::Job::{Job, JobBuilder};
+use caroline::types::AWS::DataBrew::Recipe::{
+    ActionBuilder, Recipe, RecipeBuilder, RecipeStepBuilder,
+};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_dataset() {
+    let dataset = DatasetBuilder::default()
+        .Input(
+            InputBuilder::default()
+                .S3InputDefinition(
+                    S3LocationBuilder::default()
+                        .Bucket("raw-data")
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .Name("orders")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Dataset", dataset).unwrap();
+    let entry = template.get("Dataset").unwrap();
+    assert_eq!(entry.type_, "AWS::DataBrew::Dataset");
+    assert_eq!(
+        entry.properties,
+        json!({"Input": {"S3InputDefinition": {"Bucket": "raw-data"}}, "Name": "orders"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let dataset = parsed.resource::<Dataset>("Dataset").unwrap();
+    assert_eq!(serde_json::to_value(dataset).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_job() {
+    let job = JobBuilder::default()
+        .DatasetName("orders")
+        .Name("orders-profile")
+        .RoleArn("arn:aws:iam::123456789012:role/databrew")
+        .Type("PROFILE")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Job", job).unwrap();
+    let entry = template.get("Job").unwrap();
+    assert_eq!(entry.type_, "AWS::DataBrew::Job");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "DatasetName": "orders",
+            "Name": "orders-profile",
+            "RoleArn": "arn:aws:iam::123456789012:role/databrew",
+            "Type": "PROFILE"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let job = parsed.resource::<Job>("Job").unwrap();
+    assert_eq!(serde_json::to_value(job).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_recipe() {
+    let recipe = RecipeBuilder::default()
+        .Name("normalize")
+        .Steps(vec![RecipeStepBuilder::default()
+            .Action(
+                ActionBuilder::default()
+                    .Operation("UPPER_CASE")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Recipe", recipe).unwrap();
+    let entry = template.get("Recipe").unwrap();
+    assert_eq!(entry.type_, "AWS::DataBrew::Recipe");
+    assert_eq!(
+        entry.properties,
+        json!({"Name": "normalize", "Steps": [{"Action": {"Operation": "UPPER_CASE"}}]})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let recipe = parsed.resource::<Recipe>("Recipe").unwrap();
+    assert_eq!(serde_json::to_value(recipe).unwrap(), entry.properties);
+}
@@ -0,0 +1,56 @@
+use caroline::types::AWS::Detective::Graph::{Graph, GraphBuilder};
+use caroline::types::AWS::Detective::MemberInvitation::{
+    MemberInvitation, MemberInvitationBuilder,
+};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_graph() {
+    let graph = GraphBuilder::default()
+        .AutoEnableMembers(true)
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Graph", graph).unwrap();
+    let entry = template.get("Graph").unwrap();
+    assert_eq!(entry.type_, "AWS::Detective::Graph");
+    assert_eq!(entry.properties, json!({"AutoEnableMembers": true}));
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let graph = parsed.resource::<Graph>("Graph").unwrap();
+    assert_eq!(serde_json::to_value(graph).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_member_invitation() {
+    let member_invitation = MemberInvitationBuilder::default()
+        .GraphArn("arn:aws:detective:us-east-1:123456789012:graph/example")
+        .MemberEmailAddress("security@example.com")
+        .MemberId("member-0123456789")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("MemberInvitation", member_invitation)
+        .unwrap();
+    let entry = template.get("MemberInvitation").unwrap();
+    assert_eq!(entry.type_, "AWS::Detective::MemberInvitation");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "GraphArn": "arn:aws:detective:us-east-1:123456789012:graph/example",
+            "MemberEmailAddress": "security@example.com",
+            "MemberId": "member-0123456789"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let member_invitation = parsed
+        .resource::<MemberInvitation>("MemberInvitation")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(member_invitation).unwrap(),
+        entry.properties
+    );
+}
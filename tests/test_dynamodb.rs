@@ -0,0 +1,97 @@
+use caroline::enums::{KeyType, ScalarAttributeType};
+use caroline::types::AWS::DynamoDB::GlobalTable::{
+    AttributeDefinitionBuilder, GlobalSecondaryIndexBuilder, GlobalTableBuilder, KeySchema,
+    KeySchemaBuilder, ProjectionBuilder, ReplicaSSESpecificationBuilder,
+    ReplicaSpecificationBuilder, SSESpecificationBuilder,
+};
+use caroline::{Issue, Template};
+use serde_json::json;
+
+fn key(name: &str, key_type: KeyType) -> KeySchema {
+    KeySchemaBuilder::default()
+        .AttributeName(name)
+        .KeyType(key_type)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_global_table() {
+    let replica = |region: &str| {
+        ReplicaSpecificationBuilder::default()
+            .Region(region)
+            .SSESpecification(
+                ReplicaSSESpecificationBuilder::default()
+                    .KMSMasterKeyId("alias/orders")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    };
+    let table = GlobalTableBuilder::default()
+        .BillingMode("PAY_PER_REQUEST")
+        .AttributeDefinitions(vec![
+            AttributeDefinitionBuilder::default()
+                .AttributeName("OrderId")
+                .AttributeType(ScalarAttributeType::S)
+                .build()
+                .unwrap(),
+            AttributeDefinitionBuilder::default()
+                .AttributeName("CustomerId")
+                .AttributeType(ScalarAttributeType::S)
+                .build()
+                .unwrap(),
+        ])
+        .KeySchema(vec![key("OrderId", KeyType::Hash)])
+        .GlobalSecondaryIndexes(vec![GlobalSecondaryIndexBuilder::default()
+            .IndexName("ByCustomer")
+            .KeySchema(vec![
+                key("CustomerId", KeyType::Hash),
+                key("CreatedAt", KeyType::Range),
+            ])
+            .Projection(
+                ProjectionBuilder::default()
+                    .ProjectionType("KEYS_ONLY")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()])
+        .Replicas(vec![replica("eu-west-1"), replica("us-east-1")])
+        .SSESpecification(
+            SSESpecificationBuilder::default()
+                .SSEEnabled(true)
+                .SSEType("KMS")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Orders", table).unwrap();
+    let properties = &template.get("Orders").unwrap().properties;
+    assert_eq!(
+        properties["KeySchema"],
+        json!([{"AttributeName": "OrderId", "KeyType": "HASH"}])
+    );
+    assert_eq!(
+        properties["Replicas"][1],
+        json!({"Region": "us-east-1", "SSESpecification": {"KMSMasterKeyId": "alias/orders"}})
+    );
+
+    // The sort key of the index isn't among the attribute definitions
+    assert_eq!(
+        template.validate(),
+        vec![Issue::UndefinedKeyAttribute {
+            logical_id: "Orders".to_string(),
+            attribute: "CreatedAt".to_string(),
+        }]
+    );
+    template.get_mut("Orders").unwrap().properties["AttributeDefinitions"]
+        .as_array_mut()
+        .unwrap()
+        .push(json!({"AttributeName": "CreatedAt", "AttributeType": "N"}));
+    assert!(template.validate().is_empty());
+}
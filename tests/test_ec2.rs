@@ -1,5 +1,46 @@
+use caroline::enums::{Affinity, SpotAllocationStrategy, SpotFleetAllocationStrategy, Tenancy};
+use caroline::types::AWS::EC2::CapacityReservation::CapacityReservationBuilder;
+use caroline::types::AWS::EC2::CustomerGateway::CustomerGatewayBuilder;
+use caroline::types::AWS::EC2::Host::{Host, HostBuilder};
+use caroline::types::AWS::EC2::LaunchTemplate::{
+    CapacityReservationSpecificationBuilder, CapacityReservationTargetBuilder,
+    LaunchTemplateBuilder, LaunchTemplateDataBuilder, Placement, PlacementBuilder,
+};
+use caroline::types::AWS::EC2::NetworkAcl::NetworkAclBuilder;
+use caroline::types::AWS::EC2::NetworkAclEntry::{
+    IcmpBuilder, NetworkAclEntryBuilder, PortRangeBuilder,
+};
+use caroline::types::AWS::EC2::NetworkInterface::{
+    InstanceIpv6AddressBuilder, NetworkInterfaceBuilder,
+};
+use caroline::types::AWS::EC2::NetworkInterfaceAttachment::NetworkInterfaceAttachmentBuilder;
+use caroline::types::AWS::EC2::NetworkInterfacePermission::NetworkInterfacePermissionBuilder;
+use caroline::types::AWS::EC2::RouteTable::RouteTableBuilder;
+use caroline::types::AWS::EC2::SecurityGroup::{IngressBuilder, SecurityGroupBuilder};
+use caroline::types::AWS::EC2::SpotFleet::{
+    SpotFleet, SpotFleetBuilder, SpotFleetRequestConfigDataBuilder,
+};
+use caroline::types::AWS::EC2::Subnet::Subnet;
+use caroline::types::AWS::EC2::SubnetNetworkAclAssociation::SubnetNetworkAclAssociationBuilder;
+use caroline::types::AWS::EC2::TransitGateway::TransitGatewayBuilder;
+use caroline::types::AWS::EC2::TransitGatewayAttachment::TransitGatewayAttachmentBuilder;
+use caroline::types::AWS::EC2::VPCEndpoint::VPCEndpointBuilder;
+use caroline::types::AWS::EC2::VPCEndpointService::VPCEndpointServiceBuilder;
+use caroline::types::AWS::EC2::VPCEndpointServicePermissions::VPCEndpointServicePermissionsBuilder;
+use caroline::types::AWS::EC2::VPCGatewayAttachment::VPCGatewayAttachmentBuilder;
+use caroline::types::AWS::EC2::VPNConnection::{
+    VPNConnectionBuilder, VpnTunnelOptionsSpecificationBuilder,
+};
+use caroline::types::AWS::EC2::VPNConnectionRoute::VPNConnectionRouteBuilder;
+use caroline::types::AWS::EC2::VPNGateway::VPNGatewayBuilder;
+use caroline::types::AWS::EC2::VPNGatewayRoutePropagation::VPNGatewayRoutePropagationBuilder;
+use caroline::types::AWS::EC2::Volume::{Volume, VolumeBuilder};
 use caroline::types::AWS::EC2::VPC::VPCBuilder;
-use serde_yaml;
+use caroline::{
+    az_count, Architecture, ChangeSet, Error, Expr, InstanceTypeInfo, InstanceTypeQuery, Intrinsic,
+    Issue, SubnetPlan, Template,
+};
+use serde_json::json;
 
 #[test]
 fn test_vpc_ec2() {
@@ -11,3 +52,675 @@ fn test_vpc_ec2() {
     let v = serde_yaml::to_string(&vpc).unwrap();
     println!("{}", v);
 }
+
+#[test]
+fn test_volume_defaults() {
+    assert_eq!(Volume::default_for_field("Encrypted"), Some(json!(false)));
+    assert_eq!(Volume::default_for_field("VolumeType"), Some(json!("gp2")));
+    assert_eq!(Volume::default_for_field("Size"), None);
+
+    // Omitted optional properties are left out rather than serialized as null
+    let volume = VolumeBuilder::default()
+        .AvailabilityZone("us-east-1a")
+        .build()
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(&volume).unwrap(),
+        json!({"AvailabilityZone": "us-east-1a"})
+    );
+}
+
+#[test]
+fn test_transit_gateway_attachment() {
+    let hub = TransitGatewayBuilder::default()
+        .AmazonSideAsn(64512u64)
+        .DefaultRouteTableAssociation("enable")
+        .build()
+        .unwrap();
+    let spoke = TransitGatewayAttachmentBuilder::default()
+        .TransitGatewayId(Intrinsic::Ref("Hub".into()))
+        .VpcId("vpc-0123")
+        .SubnetIds(vec!["subnet-0123".into()])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Hub", hub).unwrap();
+    template.add_resource("Spoke", spoke).unwrap();
+    assert!(template.validate().is_empty());
+    assert_eq!(
+        template.get("Spoke").unwrap().properties["TransitGatewayId"],
+        json!({"Ref": "Hub"})
+    );
+}
+
+#[test]
+fn test_private_link() {
+    let s3 = VPCEndpointBuilder::default()
+        .VpcEndpointType("Gateway")
+        .ServiceName(Intrinsic::sub("com.amazonaws.${AWS::Region}.s3"))
+        .VpcId("vpc-0123")
+        .RouteTableIds(vec!["rtb-0123".into()])
+        .PolicyDocument(json!({
+            "Statement": [{"Effect": "Allow", "Principal": "*", "Action": "s3:GetObject"}]
+        }))
+        .build()
+        .unwrap();
+    let service = VPCEndpointServiceBuilder::default()
+        .NetworkLoadBalancerArns(vec!["arn:aws:elasticloadbalancing:nlb".into()])
+        .AcceptanceRequired(true)
+        .build()
+        .unwrap();
+    let permissions = VPCEndpointServicePermissionsBuilder::default()
+        .ServiceId(Intrinsic::Ref("Service".into()))
+        .AllowedPrincipals(vec!["arn:aws:iam::123456789012:root".into()])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("S3Endpoint", s3).unwrap();
+    template.add_resource("Service", service).unwrap();
+    template.add_resource("Permissions", permissions).unwrap();
+    assert!(template.validate().is_empty());
+    assert_eq!(
+        template.get("Service").unwrap().properties,
+        json!({
+            "AcceptanceRequired": true,
+            "NetworkLoadBalancerArns": ["arn:aws:elasticloadbalancing:nlb"]
+        })
+    );
+}
+
+#[test]
+fn test_subnet_plan() {
+    let plan = SubnetPlan::new("10.0.0.0/16", az_count("eu-west-1").unwrap())
+        .unwrap()
+        .public("Public", 24)
+        .private("Private", 20);
+    let mut template = Template::new();
+    let subnets = plan
+        .add_to(&mut template, Intrinsic::Ref("Vpc".into()))
+        .unwrap();
+
+    let cidrs = subnets
+        .iter()
+        .map(|subnet| (subnet.logical_id.as_str(), subnet.cidr.to_string()))
+        .collect::<Vec<(&str, String)>>();
+    assert_eq!(
+        cidrs,
+        vec![
+            ("PublicSubnet0", "10.0.48.0/24".to_string()),
+            ("PublicSubnet1", "10.0.49.0/24".to_string()),
+            ("PublicSubnet2", "10.0.50.0/24".to_string()),
+            ("PrivateSubnet0", "10.0.0.0/20".to_string()),
+            ("PrivateSubnet1", "10.0.16.0/20".to_string()),
+            ("PrivateSubnet2", "10.0.32.0/20".to_string()),
+        ]
+    );
+    for (idx, subnet) in subnets.iter().enumerate() {
+        assert!(subnets[idx + 1..]
+            .iter()
+            .all(|other| !subnet.cidr.overlaps(&other.cidr)));
+    }
+
+    let public = template.resource::<Subnet>("PublicSubnet2").unwrap();
+    assert_eq!(public.MapPublicIpOnLaunch, Some(Expr::Literal(true)));
+    assert_eq!(
+        serde_json::to_value(&public.AvailabilityZone).unwrap(),
+        json!({"Fn::Select": [2, {"Fn::GetAZs": ""}]})
+    );
+    assert!(template
+        .resource::<Subnet>("PrivateSubnet0")
+        .unwrap()
+        .MapPublicIpOnLaunch
+        .is_none());
+}
+
+#[test]
+fn test_subnet_plan_errors() {
+    assert!(matches!(
+        SubnetPlan::new("10.0.0.1/16", 2),
+        Err(Error::InvalidCidr(_))
+    ));
+    let plan = SubnetPlan::new("10.0.0.0/24", 3)
+        .unwrap()
+        .private("Private", 25);
+    assert!(matches!(
+        plan.subnets(),
+        Err(Error::InsufficientAddressSpace(_))
+    ));
+    assert_eq!(az_count("mars-north-1"), None);
+}
+
+#[test]
+fn test_capacity_reservation_and_host() {
+    let reservation = CapacityReservationBuilder::default()
+        .AvailabilityZone(Intrinsic::select(0, Intrinsic::get_azs("")))
+        .InstanceCount(2)
+        .InstancePlatform("Linux/UNIX")
+        .InstanceType("m5.large")
+        .build()
+        .unwrap();
+    let host = HostBuilder::default()
+        .AvailabilityZone(Intrinsic::select(0, Intrinsic::get_azs("")))
+        .InstanceFamily("m5")
+        .build()
+        .unwrap();
+    let mut template = Template::new();
+    let host = template.add_typed_resource("Host", host).unwrap();
+    let data = LaunchTemplateDataBuilder::default()
+        .CapacityReservationSpecification(
+            CapacityReservationSpecificationBuilder::default()
+                .CapacityReservationTarget(
+                    CapacityReservationTargetBuilder::default()
+                        .CapacityReservationId(Intrinsic::Ref("Reservation".into()))
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .Placement(
+            PlacementBuilder::default()
+                .HostId(host)
+                .Tenancy(Tenancy::Host)
+                .Affinity(Affinity::Host)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let launch_template = LaunchTemplateBuilder::default()
+        .LaunchTemplateData(data)
+        .build()
+        .unwrap();
+
+    template.add_resource("Reservation", reservation).unwrap();
+    template
+        .add_resource("LaunchTemplate", launch_template)
+        .unwrap();
+    assert!(template.validate().is_empty());
+
+    let host = template.resource::<Host>("Host").unwrap();
+    assert!(host.InstanceType.is_none());
+    assert_eq!(
+        template.get("Reservation").unwrap().properties["InstanceCount"],
+        json!(2)
+    );
+}
+
+#[test]
+fn test_host_tenancy() {
+    let mut template = Template::new();
+    template
+        .insert_resource(
+            "LaunchTemplate",
+            serde_json::from_value(json!({
+                "Type": "AWS::EC2::LaunchTemplate",
+                "Properties": {
+                    "LaunchTemplateData": {
+                        "Placement": {"HostId": {"Ref": "Host"}, "Tenancy": "dedicated"}
+                    }
+                }
+            }))
+            .unwrap(),
+        )
+        .unwrap();
+    template
+        .insert_resource(
+            "Host",
+            serde_json::from_value(json!({
+                "Type": "AWS::EC2::Host",
+                "Properties": {"AvailabilityZone": "eu-west-1a", "InstanceFamily": "m5"}
+            }))
+            .unwrap(),
+        )
+        .unwrap();
+    assert_eq!(
+        template
+            .validate()
+            .iter()
+            .map(|issue| issue.to_string())
+            .collect::<Vec<String>>(),
+        vec!["LaunchTemplate has a HostId without host tenancy"]
+    );
+
+    // Refs of parsed templates are checked against the type of the resource
+    assert!(template.typed_resource::<Host>("Host").is_ok());
+    assert!(matches!(
+        template.typed_resource::<Host>("LaunchTemplate"),
+        Err(Error::TypeMismatch { .. })
+    ));
+
+    let placement = PlacementBuilder::default()
+        .HostId("h-0123456789abcdef0")
+        .Tenancy(Tenancy::Host)
+        .build()
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(&placement).unwrap(),
+        json!({"HostId": "h-0123456789abcdef0", "Tenancy": "host"})
+    );
+    let placement: Placement =
+        serde_json::from_value(json!({"HostId": {"Ref": "Host"}, "Affinity": "default"})).unwrap();
+    assert_eq!(placement.Affinity, Some(Expr::Literal(Affinity::Default)));
+    assert!(serde_json::from_value::<Placement>(json!({"Tenancy": "shared"})).is_err());
+}
+
+#[test]
+fn test_network_interface() {
+    let interface = NetworkInterfaceBuilder::default()
+        .SubnetId(Intrinsic::Ref("Subnet".into()))
+        .GroupSet(vec![Intrinsic::get_att("SecurityGroup", "GroupId")
+            .unwrap()
+            .into()])
+        .Ipv4PrefixCount(1)
+        .Ipv6Addresses(vec![InstanceIpv6AddressBuilder::default()
+            .Ipv6Address("2001:db8::10")
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+    let attachment = NetworkInterfaceAttachmentBuilder::default()
+        .InstanceId(Intrinsic::Ref("Instance".into()))
+        .NetworkInterfaceId(Intrinsic::Ref("Interface".into()))
+        .DeviceIndex("1")
+        .build()
+        .unwrap();
+    let permission = NetworkInterfacePermissionBuilder::default()
+        .AwsAccountId("123456789012")
+        .NetworkInterfaceId(Intrinsic::Ref("Interface".into()))
+        .Permission("INSTANCE-ATTACH")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Interface", interface).unwrap();
+    template.add_resource("Attachment", attachment).unwrap();
+    template.add_resource("Permission", permission).unwrap();
+    // Only the subnet, security group and instance are defined elsewhere
+    assert_eq!(template.validate().len(), 3);
+    assert_eq!(
+        template.get("Interface").unwrap().properties["Ipv6Addresses"],
+        json!([{"Ipv6Address": "2001:db8::10"}])
+    );
+}
+
+#[test]
+fn test_network_acl() {
+    let acl = NetworkAclBuilder::default()
+        .VpcId(Intrinsic::Ref("Vpc".into()))
+        .build()
+        .unwrap();
+    let https = NetworkAclEntryBuilder::default()
+        .NetworkAclId(Intrinsic::Ref("Acl".into()))
+        .RuleNumber(100)
+        .Protocol(6)
+        .RuleAction("allow")
+        .CidrBlock("0.0.0.0/0")
+        .PortRange(
+            PortRangeBuilder::default()
+                .From(443)
+                .To(443)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let ping = NetworkAclEntryBuilder::default()
+        .NetworkAclId(Intrinsic::Ref("Acl".into()))
+        .RuleNumber(110)
+        .Protocol(58)
+        .RuleAction("allow")
+        .Ipv6CidrBlock("::/0")
+        .Icmp(IcmpBuilder::default().Type(128).Code(-1).build().unwrap())
+        .build()
+        .unwrap();
+    let association = SubnetNetworkAclAssociationBuilder::default()
+        .NetworkAclId(Intrinsic::Ref("Acl".into()))
+        .SubnetId(Intrinsic::Ref("Subnet".into()))
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Acl", acl).unwrap();
+    template.add_resource("Https", https).unwrap();
+    template.add_resource("Ping", ping).unwrap();
+    template.add_resource("Association", association).unwrap();
+    assert!(template
+        .get("Ping")
+        .unwrap()
+        .properties
+        .get("CidrBlock")
+        .is_none());
+    assert_eq!(
+        template.get("Https").unwrap().properties["PortRange"],
+        json!({"From": 443, "To": 443})
+    );
+
+    // Renumbering a rule replaces it
+    let mut renumbered = template.clone();
+    renumbered.get_mut("Https").unwrap().properties["RuleNumber"] = json!(90);
+    let changes = ChangeSet::between(&template, &renumbered);
+    assert!(changes.get("Https").unwrap().is_destructive());
+}
+
+#[test]
+fn test_site_to_site_vpn() {
+    let gateway = VPNGatewayBuilder::default()
+        .Type("ipsec.1")
+        .AmazonSideAsn(64512)
+        .build()
+        .unwrap();
+    let attachment = VPCGatewayAttachmentBuilder::default()
+        .VpcId(Intrinsic::Ref("Vpc".into()))
+        .VpnGatewayId(Intrinsic::Ref("VpnGateway".into()))
+        .build()
+        .unwrap();
+    let customer_gateway = CustomerGatewayBuilder::default()
+        .Type("ipsec.1")
+        .BgpAsn(65000)
+        .IpAddress("203.0.113.12")
+        .build()
+        .unwrap();
+    let connection = VPNConnectionBuilder::default()
+        .Type("ipsec.1")
+        .CustomerGatewayId(Intrinsic::Ref("CustomerGateway".into()))
+        .VpnGatewayId(Intrinsic::Ref("VpnGateway".into()))
+        .StaticRoutesOnly(true)
+        .VpnTunnelOptionsSpecifications(vec![VpnTunnelOptionsSpecificationBuilder::default()
+            .TunnelInsideCidr("169.254.10.0/30")
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+    let route = VPNConnectionRouteBuilder::default()
+        .DestinationCidrBlock("192.168.0.0/16")
+        .VpnConnectionId(Intrinsic::Ref("Connection".into()))
+        .build()
+        .unwrap();
+    let propagation = VPNGatewayRoutePropagationBuilder::default()
+        .RouteTableIds(vec![Intrinsic::Ref("RouteTable".into()).into()])
+        .VpnGatewayId(Intrinsic::Ref("VpnGateway".into()))
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource(
+            "Vpc",
+            VPCBuilder::default()
+                .CidrBlock("10.0.0.0/16")
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+    template.add_resource("VpnGateway", gateway).unwrap();
+    template.add_resource("Attachment", attachment).unwrap();
+    template
+        .add_resource("CustomerGateway", customer_gateway)
+        .unwrap();
+    template.add_resource("Connection", connection).unwrap();
+    template.add_resource("Route", route).unwrap();
+    template
+        .add_resource("Propagation", propagation)
+        .unwrap()
+        .depends_on("Attachment")
+        .unwrap();
+    template
+        .add_resource(
+            "RouteTable",
+            RouteTableBuilder::default()
+                .VpcId(Intrinsic::Ref("Vpc".into()))
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+    assert!(template.validate().is_empty());
+    assert_eq!(
+        template.get("Connection").unwrap().properties["VpnTunnelOptionsSpecifications"],
+        json!([{"TunnelInsideCidr": "169.254.10.0/30"}])
+    );
+}
+
+#[test]
+fn test_security_group_cycle() {
+    let group = |peer: &str| {
+        SecurityGroupBuilder::default()
+            .GroupDescription(format!("Allows {}", peer))
+            .SecurityGroupIngress(vec![
+                IngressBuilder::default()
+                    .IpProtocol("tcp")
+                    .FromPort(443)
+                    .ToPort(443)
+                    .CidrIp("10.0.0.0/16")
+                    .build()
+                    .unwrap(),
+                IngressBuilder::default()
+                    .IpProtocol("tcp")
+                    .FromPort(5432)
+                    .ToPort(5432)
+                    .SourceSecurityGroupId(Intrinsic::get_att(peer, "GroupId").unwrap())
+                    .build()
+                    .unwrap(),
+            ])
+            .build()
+            .unwrap()
+    };
+    let mut template = Template::new();
+    template.add_resource("App", group("Db")).unwrap();
+    template
+        .add_resource("Db", group("App"))
+        .unwrap()
+        .set_condition("HasDb");
+
+    let issues = template.validate();
+    let cycle = issues
+        .iter()
+        .find(|issue| matches!(issue, Issue::DependencyCycle { .. }))
+        .unwrap();
+    assert_eq!(cycle.logical_id(), "App");
+    assert!(cycle
+        .to_string()
+        .starts_with("App, Db depend on each other in a cycle, move the rules"));
+
+    assert_eq!(
+        template.break_cycles().unwrap(),
+        vec!["AppIngress1", "DbIngress1"]
+    );
+    assert!(!template
+        .validate()
+        .iter()
+        .any(|issue| matches!(issue, Issue::DependencyCycle { .. })));
+    assert_eq!(
+        template.get("App").unwrap().properties["SecurityGroupIngress"],
+        json!([{"IpProtocol": "tcp", "FromPort": 443, "ToPort": 443, "CidrIp": "10.0.0.0/16"}])
+    );
+    let rule = template.get("DbIngress1").unwrap();
+    assert_eq!(rule.type_, "AWS::EC2::SecurityGroupIngress");
+    assert_eq!(rule.condition.as_deref(), Some("HasDb"));
+    assert_eq!(
+        rule.properties,
+        json!({
+            "IpProtocol": "tcp",
+            "FromPort": 5432,
+            "ToPort": 5432,
+            "SourceSecurityGroupId": {"Fn::GetAtt": ["App", "GroupId"]},
+            "GroupId": {"Fn::GetAtt": ["Db", "GroupId"]}
+        })
+    );
+}
+
+#[test]
+fn test_conditional_rules_are_left_in_cycles() {
+    let conditional = json!({
+        "Fn::If": [
+            "HasDb",
+            {"IpProtocol": "tcp", "FromPort": 5432, "ToPort": 5432,
+             "SourceSecurityGroupId": {"Fn::GetAtt": ["Db", "GroupId"]}},
+            {"Ref": "AWS::NoValue"}
+        ]
+    });
+    let template = json!({
+        "Resources": {
+            "App": {
+                "Type": "AWS::EC2::SecurityGroup",
+                "Properties": {"GroupDescription": "App", "SecurityGroupIngress": [conditional]}
+            },
+            "Db": {
+                "Type": "AWS::EC2::SecurityGroup",
+                "Properties": {
+                    "GroupDescription": "Db",
+                    "SecurityGroupIngress": [{
+                        "IpProtocol": "tcp", "FromPort": 5432, "ToPort": 5432,
+                        "SourceSecurityGroupId": {"Fn::GetAtt": ["App", "GroupId"]}
+                    }]
+                }
+            }
+        }
+    });
+    let mut template = Template::from_json(&template.to_string()).unwrap();
+
+    assert_eq!(template.break_cycles().unwrap(), vec!["DbIngress1"]);
+    assert_eq!(
+        template.get("App").unwrap().properties["SecurityGroupIngress"],
+        json!([conditional])
+    );
+    assert!(template
+        .get("Db")
+        .unwrap()
+        .properties
+        .get("SecurityGroupIngress")
+        .is_none());
+}
+
+#[test]
+fn test_instance_types() {
+    let c6g = InstanceTypeInfo::get("c6g.2xlarge").unwrap();
+    assert_eq!((c6g.vcpus, c6g.memory_mib), (8, 16 * 1024));
+    assert_eq!((c6g.family(), c6g.size()), ("c6g", "2xlarge"));
+    assert!(c6g.accepts_cpu_options(Some(4), Some(1)));
+    assert!(!c6g.accepts_cpu_options(None, Some(2)));
+    assert!(!InstanceTypeInfo::get("t2.micro")
+        .unwrap()
+        .supports_cpu_options());
+    assert!(InstanceTypeInfo::get("m9z.large").is_none());
+
+    let query = InstanceTypeQuery::new()
+        .min_vcpus(8)
+        .min_memory_mib(64 * 1024)
+        .architecture(Architecture::X86_64);
+    assert_eq!(query.smallest().unwrap().name, "r5.2xlarge");
+    assert!(query.iter().all(|info| info.vcpus >= 8));
+    assert!(InstanceTypeQuery::new().family("nope").smallest().is_none());
+}
+
+#[test]
+fn test_cpu_options_validation() {
+    let template = json!({
+        "Resources": {
+            "Server": {
+                "Type": "AWS::EC2::Instance",
+                "Properties": {
+                    "ImageId": "ami-12345678",
+                    "InstanceType": "m5.xlarge",
+                    "CpuOptions": {"CoreCount": 2, "ThreadsPerCore": 1}
+                }
+            },
+            "Workers": {
+                "Type": "AWS::EC2::LaunchTemplate",
+                "Properties": {"LaunchTemplateData": {
+                    "InstanceType": "m5.4xlarge",
+                    "CpuOptions": {"CoreCount": 3, "ThreadsPerCore": 4}
+                }}
+            },
+            "Legacy": {
+                "Type": "AWS::EC2::Instance",
+                "Properties": {
+                    "ImageId": "ami-12345678",
+                    "InstanceType": "t2.micro",
+                    "CpuOptions": {"ThreadsPerCore": 1}
+                }
+            }
+        }
+    });
+    let template = Template::from_json(&template.to_string()).unwrap();
+    let issues = template.validate();
+    assert_eq!(
+        issues
+            .iter()
+            .map(|issue| issue.to_string())
+            .collect::<Vec<String>>(),
+        vec![
+            "Legacy has a CpuOptions not offered by instance type t2.micro",
+            "Workers has a CoreCount not offered by instance type m5.4xlarge",
+            "Workers has a ThreadsPerCore not offered by instance type m5.4xlarge",
+        ]
+    );
+    assert!(matches!(
+        &issues[0],
+        Issue::InvalidCpuOptions { instance_type, .. } if instance_type == "t2.micro"
+    ));
+}
+
+#[test]
+fn test_allocation_strategies() {
+    let fleet = SpotFleetBuilder::default()
+        .SpotFleetRequestConfigData(
+            SpotFleetRequestConfigDataBuilder::default()
+                .IamFleetRole("arn:aws:iam::123456789012:role/aws-ec2-spot-fleet-tagging-role")
+                .TargetCapacity(4)
+                .AllocationStrategy(SpotFleetAllocationStrategy::LowestPrice)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let mut template = Template::new();
+    template.add_resource("Fleet", fleet).unwrap();
+    assert_eq!(
+        template.get("Fleet").unwrap().properties["SpotFleetRequestConfigData"]
+            ["AllocationStrategy"],
+        json!("lowestPrice")
+    );
+    let parsed = template.resource::<SpotFleet>("Fleet").unwrap();
+    assert_eq!(
+        parsed.SpotFleetRequestConfigData.AllocationStrategy,
+        Some(Expr::Literal(SpotFleetAllocationStrategy::LowestPrice))
+    );
+
+    template
+        .insert_resource(
+            "Ec2Fleet",
+            serde_json::from_value(json!({
+                "Type": "AWS::EC2::EC2Fleet",
+                "Properties": {
+                    "SpotOptions": {"AllocationStrategy": "lowest-price"},
+                    "TargetCapacitySpecification": {"TotalTargetCapacity": 2},
+                    "LaunchTemplateConfigs": []
+                }
+            }))
+            .unwrap(),
+        )
+        .unwrap();
+    assert_eq!(
+        template
+            .validate()
+            .iter()
+            .map(|issue| issue.to_string())
+            .collect::<Vec<String>>(),
+        vec![
+            "Ec2Fleet uses the deprecated allocation strategy lowest-price, use price-capacity-optimized instead",
+            "Fleet uses the deprecated allocation strategy lowestPrice, use priceCapacityOptimized instead",
+        ]
+    );
+
+    // EC2 Fleet also takes the names of Spot Fleet
+    let strategy: SpotAllocationStrategy =
+        serde_json::from_value(json!("priceCapacityOptimized")).unwrap();
+    assert_eq!(strategy, SpotAllocationStrategy::PriceCapacityOptimized);
+    assert_eq!(
+        serde_json::to_value(strategy).unwrap(),
+        json!("price-capacity-optimized")
+    );
+}
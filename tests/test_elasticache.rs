@@ -0,0 +1,105 @@
+use caroline::types::AWS::ElastiCache::GlobalReplicationGroup::{
+    GlobalReplicationGroupBuilder, GlobalReplicationGroupMemberBuilder,
+};
+use caroline::types::AWS::ElastiCache::ServerlessCache::{
+    CacheUsageLimitsBuilder, DataStorageBuilder, ECPUPerSecondBuilder, ServerlessCacheBuilder,
+};
+use caroline::types::AWS::ElastiCache::User::UserBuilder;
+use caroline::types::AWS::ElastiCache::UserGroup::UserGroupBuilder;
+use caroline::{Expr, Intrinsic, Template};
+use serde_json::json;
+
+#[test]
+fn test_global_datastore() {
+    let member = |id: &str, role: &str| {
+        GlobalReplicationGroupMemberBuilder::default()
+            .ReplicationGroupId(id)
+            .ReplicationGroupRegion("eu-west-1")
+            .Role(role)
+            .build()
+            .unwrap()
+    };
+    let global = GlobalReplicationGroupBuilder::default()
+        .GlobalReplicationGroupIdSuffix("sessions")
+        .AutomaticFailoverEnabled(true)
+        .Members(vec![member("sessions-eu", "PRIMARY")])
+        .build()
+        .unwrap();
+    let mut template = Template::new();
+    template.add_resource("Sessions", global).unwrap();
+    assert_eq!(
+        template.get("Sessions").unwrap().properties["Members"],
+        json!([{
+            "ReplicationGroupId": "sessions-eu",
+            "ReplicationGroupRegion": "eu-west-1",
+            "Role": "PRIMARY"
+        }])
+    );
+}
+
+#[test]
+fn test_serverless_cache_with_users() {
+    let user = UserBuilder::default()
+        .UserId("app")
+        .UserName("app")
+        .Engine("redis")
+        .AccessString("on ~app:* +@all")
+        .Passwords(vec![Expr::from("correct-horse-battery-staple")])
+        .build()
+        .unwrap();
+    let default_user = UserBuilder::default()
+        .UserId("no-default")
+        .UserName("default")
+        .Engine("redis")
+        .AccessString("off -@all")
+        .NoPasswordRequired(true)
+        .build()
+        .unwrap();
+    let group = UserGroupBuilder::default()
+        .UserGroupId("app-users")
+        .Engine("redis")
+        .UserIds(vec![
+            Intrinsic::Ref("AppUser".into()).into(),
+            Intrinsic::Ref("DefaultUser".into()).into(),
+        ])
+        .build()
+        .unwrap();
+    let limits = CacheUsageLimitsBuilder::default()
+        .DataStorage(
+            DataStorageBuilder::default()
+                .Maximum(10)
+                .Unit("GB")
+                .build()
+                .unwrap(),
+        )
+        .ECPUPerSecond(
+            ECPUPerSecondBuilder::default()
+                .Maximum(5000)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let cache = ServerlessCacheBuilder::default()
+        .ServerlessCacheName("sessions")
+        .Engine("redis")
+        .CacheUsageLimits(limits)
+        .UserGroupId(Intrinsic::Ref("AppUsers".into()))
+        .build()
+        .unwrap();
+
+    assert!(!format!("{:?}", user).contains("correct-horse"));
+    let mut template = Template::new();
+    template.add_resource("AppUser", user).unwrap();
+    template.add_resource("DefaultUser", default_user).unwrap();
+    template.add_resource("AppUsers", group).unwrap();
+    template.add_resource("Cache", cache).unwrap();
+    assert_eq!(
+        template.get("Cache").unwrap().properties["CacheUsageLimits"],
+        json!({
+            "DataStorage": {"Maximum": 10, "Unit": "GB"},
+            "ECPUPerSecond": {"Maximum": 5000}
+        })
+    );
+    assert_eq!(template.plaintext_secrets(), vec!["AppUser.Passwords"]);
+}
@@ -0,0 +1,68 @@
+use caroline::types::AWS::ElasticLoadBalancing::LoadBalancer::{
+    ConnectionDrainingPolicyBuilder, HealthCheckBuilder, ListenersBuilder, LoadBalancer,
+    LoadBalancerBuilder, PoliciesBuilder,
+};
+use caroline::{Expr, Intrinsic, Template};
+use serde_json::json;
+
+#[test]
+fn test_classic_load_balancer() {
+    let listener = ListenersBuilder::default()
+        .LoadBalancerPort("443")
+        .InstancePort("80")
+        .Protocol("HTTPS")
+        .InstanceProtocol("HTTP")
+        .SSLCertificateId(Intrinsic::Ref("Certificate".into()))
+        .PolicyNames(vec!["TLS".into()])
+        .build()
+        .unwrap();
+    let health_check = HealthCheckBuilder::default()
+        .Target("HTTP:80/health")
+        .HealthyThreshold("3")
+        .UnhealthyThreshold("5")
+        .Interval("30")
+        .Timeout("5")
+        .build()
+        .unwrap();
+    let policy = PoliciesBuilder::default()
+        .PolicyName("TLS")
+        .PolicyType("SSLNegotiationPolicyType")
+        .Attributes(vec![
+            json!({"Name": "Reference-Security-Policy", "Value": "ELBSecurityPolicy-TLS-1-2-2017-01"}),
+        ])
+        .build()
+        .unwrap();
+    let load_balancer = LoadBalancerBuilder::default()
+        .Listeners(vec![listener])
+        .HealthCheck(health_check)
+        .Policies(vec![policy])
+        .ConnectionDrainingPolicy(
+            ConnectionDrainingPolicyBuilder::default()
+                .Enabled(true)
+                .Timeout(60)
+                .build()
+                .unwrap(),
+        )
+        .CrossZone(true)
+        .AvailabilityZones(vec![Intrinsic::select(0, Intrinsic::get_azs("")).into()])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Legacy", load_balancer).unwrap();
+    let properties = &template.get("Legacy").unwrap().properties;
+    assert_eq!(properties["Listeners"][0]["LoadBalancerPort"], json!("443"));
+    assert_eq!(
+        properties["Policies"][0]["Attributes"][0]["Name"],
+        json!("Reference-Security-Policy")
+    );
+
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let load_balancer = parsed.resource::<LoadBalancer>("Legacy").unwrap();
+    assert_eq!(load_balancer.CrossZone, Some(Expr::Literal(true)));
+    assert_eq!(
+        load_balancer.HealthCheck.unwrap().Target,
+        Expr::Literal("HTTP:80/health".to_string())
+    );
+    assert!(parsed.unknown_properties().is_empty());
+}
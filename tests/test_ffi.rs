@@ -0,0 +1,67 @@
+#![cfg(feature = "ffi")]
+
+use caroline::ffi::*;
+use caroline::Template;
+use serde_json::{json, Value};
+use std::ptr;
+
+unsafe fn take(buffer: CarolineBuffer) -> String {
+    let string = String::from_utf8(std::slice::from_raw_parts(buffer.data, buffer.len).to_vec());
+    caroline_buffer_free(buffer);
+    string.unwrap()
+}
+
+#[test]
+fn test_ffi() {
+    let json = json!({
+        "Resources": {
+            "Subscription": {
+                "Type": "AWS::SNS::Subscription",
+                "Properties": {"Protocol": "sqs", "TopicArn": {"Ref": "Topic"}}
+            }
+        }
+    })
+    .to_string();
+    unsafe {
+        let mut template: *mut Template = ptr::null_mut();
+        let status = caroline_template_from_json(json.as_ptr(), json.len(), &mut template);
+        assert_eq!(status, CAROLINE_OK);
+
+        let mut out = CarolineBuffer {
+            data: ptr::null_mut(),
+            len: 0,
+        };
+        assert_eq!(
+            caroline_template_validate(template, &mut out),
+            CAROLINE_INVALID_TEMPLATE
+        );
+        let issues: Value = serde_json::from_str(&take(out)).unwrap();
+        assert_eq!(
+            issues,
+            json!([{
+                "LogicalId": "Subscription",
+                "Message": "Subscription references unknown logical ID Topic"
+            }])
+        );
+
+        let mut out = CarolineBuffer {
+            data: ptr::null_mut(),
+            len: 0,
+        };
+        assert_eq!(caroline_template_to_yaml(template, &mut out), CAROLINE_OK);
+        assert!(take(out).contains("AWS::SNS::Subscription"));
+        caroline_template_free(template);
+
+        let invalid = "{\"Resources\": [";
+        let mut template: *mut Template = ptr::null_mut();
+        let status = caroline_template_from_json(invalid.as_ptr(), invalid.len(), &mut template);
+        assert_eq!(status, CAROLINE_PARSE_ERROR);
+        assert!(template.is_null());
+        assert!(!take(caroline_last_error()).is_empty());
+
+        let status = caroline_template_from_json([0xff].as_ptr(), 1, &mut template);
+        assert_eq!(status, CAROLINE_INVALID_UTF8);
+        let status = caroline_template_from_json(ptr::null(), 0, &mut template);
+        assert_eq!(status, CAROLINE_NULL_POINTER);
+    }
+}
@@ -0,0 +1,73 @@
+use caroline::types::AWS::FIS::ExperimentTemplate::{
+    ExperimentTemplateActionBuilder, ExperimentTemplateBuilder,
+    ExperimentTemplateStopConditionBuilder, ExperimentTemplateTargetBuilder,
+};
+use caroline::{Expr, Intrinsic, Template};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[test]
+fn test_stop_instances_experiment() {
+    let target = ExperimentTemplateTargetBuilder::default()
+        .ResourceType("aws:ec2:instance")
+        .ResourceTags(
+            vec![("Tier".to_string(), Expr::from("web"))]
+                .into_iter()
+                .collect::<HashMap<String, Expr<String>>>(),
+        )
+        .SelectionMode("PERCENT(50)")
+        .build()
+        .unwrap();
+    let action = ExperimentTemplateActionBuilder::default()
+        .ActionId("aws:ec2:stop-instances")
+        .Targets(
+            vec![("Instances".to_string(), Expr::from("WebServers"))]
+                .into_iter()
+                .collect::<HashMap<String, Expr<String>>>(),
+        )
+        .build()
+        .unwrap();
+    let experiment = ExperimentTemplateBuilder::default()
+        .Description("Stop half of the web servers")
+        .RoleArn(Intrinsic::get_att("FisRole", "Arn").unwrap())
+        .Targets(
+            vec![("WebServers".to_string(), target)]
+                .into_iter()
+                .collect::<HashMap<_, _>>(),
+        )
+        .Actions(
+            vec![("StopInstances".to_string(), action)]
+                .into_iter()
+                .collect::<HashMap<_, _>>(),
+        )
+        .StopConditions(vec![ExperimentTemplateStopConditionBuilder::default()
+            .Source("aws:cloudwatch:alarm")
+            .Value(Intrinsic::get_att("HighErrorRate", "Arn").unwrap())
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("StopWebServers", experiment).unwrap();
+    let properties = &template.get("StopWebServers").unwrap().properties;
+    assert_eq!(
+        properties["Targets"],
+        json!({"WebServers": {
+            "ResourceType": "aws:ec2:instance",
+            "ResourceTags": {"Tier": "web"},
+            "SelectionMode": "PERCENT(50)"
+        }})
+    );
+    assert_eq!(
+        properties["Actions"]["StopInstances"]["Targets"],
+        json!({"Instances": "WebServers"})
+    );
+    assert_eq!(
+        properties["StopConditions"],
+        json!([{
+            "Source": "aws:cloudwatch:alarm",
+            "Value": {"Fn::GetAtt": ["HighErrorRate", "Arn"]}
+        }])
+    );
+}
@@ -0,0 +1,97 @@
+use caroline::types::AWS::GameLift::MatchmakingRuleSet::MatchmakingRuleSetBuilder;
+use caroline::{AttributeType, Error, Expansion, PlayerAttribute, Rule, RuleSet, Team, Template};
+use serde_json::{json, Value};
+
+fn rule_set() -> RuleSet {
+    RuleSet::new("team-deathmatch")
+        .player_attribute(PlayerAttribute::new("skill", AttributeType::Number).default(10))
+        .team(Team::new("red", 4, 8))
+        .team(Team::new("blue", 4, 8))
+        .rule(
+            Rule::distance("FairTeamSkill")
+                .description("The average skill of players in each team is within 10")
+                .measurements(vec!["avg(teams[*].players.attributes[skill])"])
+                .reference_value("avg(flatten(teams[*].players.attributes[skill]))")
+                .max_distance(10.0),
+        )
+        .expansion(
+            Expansion::new("rules[FairTeamSkill].maxDistance")
+                .step(10, 50.0)
+                .step(20, 100.0),
+        )
+}
+
+#[test]
+fn test_rule_set_body() {
+    let body = rule_set().body().unwrap();
+    let rule_set = MatchmakingRuleSetBuilder::default()
+        .Name("TeamDeathmatch")
+        .RuleSetBody(body)
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("RuleSet", rule_set).unwrap();
+    let properties = &template.get("RuleSet").unwrap().properties;
+    let body = serde_json::from_str::<Value>(properties["RuleSetBody"].as_str().unwrap()).unwrap();
+    assert_eq!(
+        body,
+        json!({
+            "name": "team-deathmatch",
+            "ruleLanguageVersion": "1.0",
+            "playerAttributes": [{"name": "skill", "type": "number", "default": 10}],
+            "teams": [
+                {"name": "red", "minPlayers": 4, "maxPlayers": 8},
+                {"name": "blue", "minPlayers": 4, "maxPlayers": 8}
+            ],
+            "rules": [{
+                "name": "FairTeamSkill",
+                "description": "The average skill of players in each team is within 10",
+                "type": "distance",
+                "measurements": ["avg(teams[*].players.attributes[skill])"],
+                "referenceValue": "avg(flatten(teams[*].players.attributes[skill]))",
+                "maxDistance": 10.0
+            }],
+            "expansions": [{
+                "target": "rules[FairTeamSkill].maxDistance",
+                "steps": [
+                    {"waitTimeSeconds": 10, "value": 50.0},
+                    {"waitTimeSeconds": 20, "value": 100.0}
+                ]
+            }]
+        })
+    );
+}
+
+#[test]
+fn test_invalid_rule_sets() {
+    let invalid = |rule_set: RuleSet| match rule_set.body() {
+        Err(Error::InvalidRuleSet(reason)) => reason,
+        other => panic!("Expected an invalid rule set, got {:?}", other),
+    };
+
+    assert_eq!(
+        invalid(RuleSet::new("empty")),
+        "Rule set 'empty' has no teams"
+    );
+    assert_eq!(
+        invalid(rule_set().team(Team::new("green", 8, 4))),
+        "Team 'green' takes 8 to 4 players"
+    );
+    assert_eq!(
+        invalid(rule_set().rule(Rule::latency("FairTeamSkill", 150))),
+        "Rule 'FairTeamSkill' is defined more than once"
+    );
+    assert_eq!(
+        invalid(rule_set().expansion(Expansion::new("rules[LowLatency].maxLatency").step(10, 250))),
+        "Expansion 'rules[LowLatency].maxLatency' targets the unknown rule 'LowLatency'"
+    );
+    assert_eq!(
+        invalid(rule_set().expansion(Expansion::new("teams[red].minPlayers"))),
+        "Expansion 'teams[red].minPlayers' has no steps"
+    );
+
+    // Team expansions aren't checked against the rules
+    let rule_set = rule_set().expansion(Expansion::new("teams[red].minPlayers").step(30, 2));
+    assert!(rule_set.body().is_ok());
+}
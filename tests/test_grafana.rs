@@ -0,0 +1,29 @@
+use caroline::types::AWS::Grafana::Workspace::{Workspace, WorkspaceBuilder};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_workspace() {
+    let workspace = WorkspaceBuilder::default()
+        .AccountAccessType("CURRENT_ACCOUNT")
+        .AuthenticationProviders(vec!["AWS_SSO".into()])
+        .PermissionType("SERVICE_MANAGED")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Workspace", workspace).unwrap();
+    let entry = template.get("Workspace").unwrap();
+    assert_eq!(entry.type_, "AWS::Grafana::Workspace");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "AccountAccessType": "CURRENT_ACCOUNT",
+            "AuthenticationProviders": ["AWS_SSO"],
+            "PermissionType": "SERVICE_MANAGED"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let workspace = parsed.resource::<Workspace>("Workspace").unwrap();
+    assert_eq!(serde_json::to_value(workspace).unwrap(), entry.properties);
+}
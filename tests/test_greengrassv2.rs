@@ -0,0 +1,70 @@
+use caroline::types::AWS::GreengrassV2::Deployment::{
+    ComponentConfigurationUpdateBuilder, ComponentDeploymentSpecificationBuilder,
+    DeploymentBuilder, DeploymentIoTJobConfigurationBuilder, IoTJobAbortConfigBuilder,
+    IoTJobAbortCriteriaBuilder, IoTJobExecutionsRolloutConfigBuilder,
+};
+use caroline::Template;
+use serde_json::json;
+use std::collections::HashMap;
+
+#[test]
+fn test_fleet_deployment() {
+    let collector = ComponentDeploymentSpecificationBuilder::default()
+        .ComponentVersion("1.2.0")
+        .ConfigurationUpdate(
+            ComponentConfigurationUpdateBuilder::default()
+                .Merge(r#"{"intervalSeconds": 30}"#)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let deployment = DeploymentBuilder::default()
+        .TargetArn("arn:aws:iot:eu-west-1:123456789012:thinggroup/sensors")
+        .DeploymentName("sensors")
+        .Components(
+            vec![("com.example.Collector".to_string(), collector)]
+                .into_iter()
+                .collect::<HashMap<_, _>>(),
+        )
+        .IotJobConfiguration(
+            DeploymentIoTJobConfigurationBuilder::default()
+                .JobExecutionsRolloutConfig(
+                    IoTJobExecutionsRolloutConfigBuilder::default()
+                        .MaximumPerMinute(50)
+                        .build()
+                        .unwrap(),
+                )
+                .AbortConfig(
+                    IoTJobAbortConfigBuilder::default()
+                        .CriteriaList(vec![IoTJobAbortCriteriaBuilder::default()
+                            .FailureType("FAILED")
+                            .Action("CANCEL")
+                            .ThresholdPercentage(10.0)
+                            .MinNumberOfExecutedThings(20)
+                            .build()
+                            .unwrap()])
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Sensors", deployment).unwrap();
+    let properties = &template.get("Sensors").unwrap().properties;
+    assert_eq!(
+        properties["Components"],
+        json!({"com.example.Collector": {
+            "ComponentVersion": "1.2.0",
+            "ConfigurationUpdate": {"Merge": "{\"intervalSeconds\": 30}"}
+        }})
+    );
+    assert_eq!(
+        properties["IotJobConfiguration"]["AbortConfig"]["CriteriaList"][0]["ThresholdPercentage"],
+        json!(10.0)
+    );
+}
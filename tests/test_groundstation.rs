@@ -0,0 +1,140 @@
+use caroline::types::AWS::GroundStation::Config::{
+    Config, ConfigBuilder, ConfigDataBuilder, TrackingConfigBuilder,
+};
+use caroline::types::AWS::GroundStation::DataflowEndpointGroup::{
+    DataflowEndpointBuilder, DataflowEndpointGroup, DataflowEndpointGroupBuilder,
+    EndpointDetailsBuilder, SecurityDetailsBuilder, SocketAddressBuilder,
+};
+use caroline::types::AWS::GroundStation::MissionProfile::{
+    DataflowEdgeBuilder, MissionProfile, MissionProfileBuilder,
+};
+use caroline::{Intrinsic, Template};
+use serde_json::json;
+
+#[test]
+fn test_config() {
+    let config = ConfigBuilder::default()
+        .ConfigData(
+            ConfigDataBuilder::default()
+                .TrackingConfig(
+                    TrackingConfigBuilder::default()
+                        .Autotrack("PREFERRED")
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .Name("tracking")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Config", config).unwrap();
+    let entry = template.get("Config").unwrap();
+    assert_eq!(entry.type_, "AWS::GroundStation::Config");
+    assert_eq!(
+        entry.properties,
+        json!({"ConfigData": {"TrackingConfig": {"Autotrack": "PREFERRED"}}, "Name": "tracking"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let config = parsed.resource::<Config>("Config").unwrap();
+    assert_eq!(serde_json::to_value(config).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_dataflow_endpoint_group() {
+    let dataflow_endpoint_group = DataflowEndpointGroupBuilder::default()
+        .EndpointDetails(vec![EndpointDetailsBuilder::default()
+            .Endpoint(
+                DataflowEndpointBuilder::default()
+                    .Address(
+                        SocketAddressBuilder::default()
+                            .Name("10.0.0.10")
+                            .Port(55888)
+                            .build()
+                            .unwrap(),
+                    )
+                    .Name("downlink")
+                    .build()
+                    .unwrap(),
+            )
+            .SecurityDetails(
+                SecurityDetailsBuilder::default()
+                    .RoleArn("arn:aws:iam::123456789012:role/ground-station")
+                    .SubnetIds(vec!["subnet-0123456789abcdef0".into()])
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("DataflowEndpointGroup", dataflow_endpoint_group)
+        .unwrap();
+    let entry = template.get("DataflowEndpointGroup").unwrap();
+    assert_eq!(entry.type_, "AWS::GroundStation::DataflowEndpointGroup");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "EndpointDetails": [{
+                "Endpoint": {
+                    "Address": {"Name": "10.0.0.10", "Port": 55888},
+                    "Name": "downlink"
+                },
+                "SecurityDetails": {
+                    "RoleArn": "arn:aws:iam::123456789012:role/ground-station",
+                    "SubnetIds": ["subnet-0123456789abcdef0"]
+                }
+            }]
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let dataflow_endpoint_group = parsed
+        .resource::<DataflowEndpointGroup>("DataflowEndpointGroup")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(dataflow_endpoint_group).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_mission_profile() {
+    let mission_profile = MissionProfileBuilder::default()
+        .DataflowEdges(vec![DataflowEdgeBuilder::default()
+            .Destination(Intrinsic::reference("Downlink").unwrap())
+            .Source(Intrinsic::reference("Antenna").unwrap())
+            .build()
+            .unwrap()])
+        .MinimumViableContactDurationSeconds(180)
+        .Name("downlink")
+        .TrackingConfigArn(Intrinsic::reference("Tracking").unwrap())
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("MissionProfile", mission_profile)
+        .unwrap();
+    let entry = template.get("MissionProfile").unwrap();
+    assert_eq!(entry.type_, "AWS::GroundStation::MissionProfile");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "DataflowEdges": [{"Destination": {"Ref": "Downlink"}, "Source": {"Ref": "Antenna"}}],
+            "MinimumViableContactDurationSeconds": 180,
+            "Name": "downlink",
+            "TrackingConfigArn": {"Ref": "Tracking"}
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let mission_profile = parsed.resource::<MissionProfile>("MissionProfile").unwrap();
+    assert_eq!(
+        serde_json::to_value(mission_profile).unwrap(),
+        entry.properties
+    );
+}
@@ -0,0 +1,171 @@
+use caroline::types::AWS::ImageBuilder::Component::{Component, ComponentBuilder};
+use caroline::types::AWS::ImageBuilder::DistributionConfiguration::{
+    DistributionBuilder, DistributionConfiguration, DistributionConfigurationBuilder,
+};
+use caroline::types::AWS::ImageBuilder::ImagePipeline::{
+    ImagePipeline, ImagePipelineBuilder, ScheduleBuilder,
+};
+use caroline::types::AWS::ImageBuilder::ImageRecipe::{
+    ComponentConfigurationBuilder, ImageRecipe, ImageRecipeBuilder,
+};
+use caroline::types::AWS::ImageBuilder::InfrastructureConfiguration::{
+    InfrastructureConfiguration, InfrastructureConfigurationBuilder,
+};
+use caroline::{Intrinsic, ScheduleExpression, Template};
+use serde_json::json;
+
+#[test]
+fn test_component() {
+    let component = ComponentBuilder::default()
+        .Name("hardening")
+        .Platform("Linux")
+        .Version("1.0.0")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Component", component).unwrap();
+    let entry = template.get("Component").unwrap();
+    assert_eq!(entry.type_, "AWS::ImageBuilder::Component");
+    assert_eq!(
+        entry.properties,
+        json!({"Name": "hardening", "Platform": "Linux", "Version": "1.0.0"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let component = parsed.resource::<Component>("Component").unwrap();
+    assert_eq!(serde_json::to_value(component).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_distribution_configuration() {
+    let distribution_configuration = DistributionConfigurationBuilder::default()
+        .Distributions(vec![DistributionBuilder::default()
+            .Region("us-east-1")
+            .build()
+            .unwrap()])
+        .Name("web")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("DistributionConfiguration", distribution_configuration)
+        .unwrap();
+    let entry = template.get("DistributionConfiguration").unwrap();
+    assert_eq!(entry.type_, "AWS::ImageBuilder::DistributionConfiguration");
+    assert_eq!(
+        entry.properties,
+        json!({"Distributions": [{"Region": "us-east-1"}], "Name": "web"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let distribution_configuration = parsed
+        .resource::<DistributionConfiguration>("DistributionConfiguration")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(distribution_configuration).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_image_pipeline() {
+    let image_pipeline = ImagePipelineBuilder::default()
+        .InfrastructureConfigurationArn(Intrinsic::get_att("Infrastructure", "Arn").unwrap())
+        .Name("web")
+        .Schedule(
+            ScheduleBuilder::default()
+                .ScheduleExpression(ScheduleExpression::cron("0 2 ? * SUN *").unwrap())
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("ImagePipeline", image_pipeline)
+        .unwrap();
+    let entry = template.get("ImagePipeline").unwrap();
+    assert_eq!(entry.type_, "AWS::ImageBuilder::ImagePipeline");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "InfrastructureConfigurationArn": {"Fn::GetAtt": ["Infrastructure", "Arn"]},
+            "Name": "web",
+            "Schedule": {"ScheduleExpression": "cron(0 2 ? * SUN *)"}
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let image_pipeline = parsed.resource::<ImagePipeline>("ImagePipeline").unwrap();
+    assert_eq!(
+        serde_json::to_value(image_pipeline).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_image_recipe() {
+    let image_recipe = ImageRecipeBuilder::default()
+        .Components(vec![ComponentConfigurationBuilder::default()
+            .ComponentArn("arn:aws:imagebuilder:us-east-1:aws:component/update-linux/x.x.x")
+            .build()
+            .unwrap()])
+        .Name("web")
+        .ParentImage("arn:aws:imagebuilder:us-east-1:aws:image/amazon-linux-2-x86/x.x.x")
+        .Version("1.0.0")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("ImageRecipe", image_recipe).unwrap();
+    let entry = template.get("ImageRecipe").unwrap();
+    assert_eq!(entry.type_, "AWS::ImageBuilder::ImageRecipe");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "Components": [{
+                "ComponentArn": "arn:aws:imagebuilder:us-east-1:aws:component/update-linux/x.x.x"
+            }],
+            "Name": "web",
+            "ParentImage": "arn:aws:imagebuilder:us-east-1:aws:image/amazon-linux-2-x86/x.x.x",
+            "Version": "1.0.0"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let image_recipe = parsed.resource::<ImageRecipe>("ImageRecipe").unwrap();
+    assert_eq!(
+        serde_json::to_value(image_recipe).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_infrastructure_configuration() {
+    let infrastructure_configuration = InfrastructureConfigurationBuilder::default()
+        .InstanceProfileName("image-builder")
+        .Name("web")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("InfrastructureConfiguration", infrastructure_configuration)
+        .unwrap();
+    let entry = template.get("InfrastructureConfiguration").unwrap();
+    assert_eq!(
+        entry.type_,
+        "AWS::ImageBuilder::InfrastructureConfiguration"
+    );
+    assert_eq!(
+        entry.properties,
+        json!({"InstanceProfileName": "image-builder", "Name": "web"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let infrastructure_configuration = parsed
+        .resource::<InfrastructureConfiguration>("InfrastructureConfiguration")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(infrastructure_configuration).unwrap(),
+        entry.properties
+    );
+}
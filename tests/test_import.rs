@@ -0,0 +1,109 @@
+use caroline::types::AWS::EC2::VPC::VPCBuilder;
+use caroline::types::AWS::ECS::Service::{Service, ServiceBuilder};
+use caroline::types::AWS::S3::Bucket::{Bucket, BucketBuilder};
+use caroline::types::AWS::SSM::Association::AssociationBuilder;
+use caroline::{registry, Error, PhysicalId, Resource, Template};
+use serde_json::json;
+
+fn template() -> Template {
+    let mut template = Template::new();
+    template
+        .add_resource("Logs", BucketBuilder::default().build().unwrap())
+        .unwrap();
+    template
+        .add_resource("Api", ServiceBuilder::default().build().unwrap())
+        .unwrap();
+    template
+        .add_resource(
+            "Vpc",
+            VPCBuilder::default()
+                .CidrBlock("10.0.0.0/16")
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+    template
+        .add_resource(
+            "Patching",
+            AssociationBuilder::default()
+                .Name("AWS-RunPatchBaseline")
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+    for logical_id in ["Logs", "Api", "Patching"] {
+        template
+            .get_mut(logical_id)
+            .unwrap()
+            .set_deletion_policy("Retain");
+    }
+    template
+}
+
+#[test]
+fn test_identifiers() {
+    assert_eq!(Bucket::IDENTIFIERS, ["BucketName"]);
+    assert_eq!(
+        registry::identifiers(Service::TYPE),
+        Some(Service::IDENTIFIERS)
+    );
+    assert!(registry::identifiers("AWS::SSM::Association").is_none());
+    assert!(registry::identifiers("AWS::Nope::Nope").is_none());
+}
+
+#[test]
+fn test_resources_to_import() {
+    let template = template();
+    let import = template
+        .resources_to_import(vec![
+            ("Logs", PhysicalId::from("my-logs")),
+            (
+                "Api",
+                PhysicalId::from([("Cluster", "default"), ("ServiceArn", "arn:aws:ecs:...")]),
+            ),
+        ])
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(&import).unwrap(),
+        json!([
+            {
+                "ResourceType": "AWS::S3::Bucket",
+                "LogicalResourceId": "Logs",
+                "ResourceIdentifier": {"BucketName": "my-logs"}
+            },
+            {
+                "ResourceType": "AWS::ECS::Service",
+                "LogicalResourceId": "Api",
+                "ResourceIdentifier": {"Cluster": "default", "ServiceArn": "arn:aws:ecs:..."}
+            }
+        ])
+    );
+}
+
+#[test]
+fn test_invalid_import() {
+    let template = template();
+    let problems = match template.resources_to_import(vec![
+        ("Vpc", PhysicalId::from("vpc-0123")),
+        ("Api", PhysicalId::from("arn:aws:ecs:...")),
+        ("Patching", PhysicalId::from("0123")),
+        (
+            "Logs",
+            PhysicalId::from([("BucketName", "my-logs"), ("Region", "eu-west-1")]),
+        ),
+        ("Nope", PhysicalId::from("nope")),
+    ]) {
+        Err(Error::InvalidImport(problems)) => problems,
+        other => panic!("Expected an invalid import, got {:?}", other),
+    };
+    assert_eq!(
+        problems,
+        vec![
+            "Vpc: no DeletionPolicy",
+            "Api: identified by ServiceArn, Cluster",
+            "Patching: AWS::SSM::Association can't be imported",
+            "Logs: unknown identifier Region",
+            "Nope: unknown logical ID",
+        ]
+    );
+}
@@ -0,0 +1,85 @@
+use caroline::types::AWS::IoTAnalytics::Dataset::{
+    ActionBuilder, DatasetBuilder, QueryActionBuilder, ScheduleBuilder, TriggerBuilder,
+};
+use caroline::types::AWS::IoTAnalytics::Pipeline::{
+    Activity, ChannelBuilder, DatastoreBuilder, FilterBuilder, PipelineBuilder,
+};
+use caroline::{ScheduleExpression, Template};
+use serde_json::json;
+
+#[test]
+fn test_pipeline_and_daily_dataset() {
+    let pipeline = PipelineBuilder::default()
+        .PipelineName("telemetry")
+        .PipelineActivities(vec![
+            Activity {
+                Channel: Some(
+                    ChannelBuilder::default()
+                        .Name("ingest")
+                        .ChannelName("telemetry")
+                        .Next("valid")
+                        .build()
+                        .unwrap(),
+                ),
+                ..Activity::default()
+            },
+            Activity {
+                Filter: Some(
+                    FilterBuilder::default()
+                        .Name("valid")
+                        .Filter("temperature > -50")
+                        .Next("store")
+                        .build()
+                        .unwrap(),
+                ),
+                ..Activity::default()
+            },
+            Activity {
+                Datastore: Some(
+                    DatastoreBuilder::default()
+                        .Name("store")
+                        .DatastoreName("telemetry")
+                        .build()
+                        .unwrap(),
+                ),
+                ..Activity::default()
+            },
+        ])
+        .build()
+        .unwrap();
+    let dataset = DatasetBuilder::default()
+        .DatasetName("daily_temperatures")
+        .Actions(vec![ActionBuilder::default()
+            .ActionName("query")
+            .QueryAction(
+                QueryActionBuilder::default()
+                    .SqlQuery("SELECT avg(temperature) FROM telemetry")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()])
+        .Triggers(vec![TriggerBuilder::default()
+            .Schedule(
+                ScheduleBuilder::default()
+                    .ScheduleExpression(ScheduleExpression::cron("0 6 * * ? *").unwrap())
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Telemetry", pipeline).unwrap();
+    template.add_resource("DailyTemperatures", dataset).unwrap();
+    assert_eq!(
+        template.get("Telemetry").unwrap().properties["PipelineActivities"][1],
+        json!({"Filter": {"Name": "valid", "Filter": "temperature > -50", "Next": "store"}})
+    );
+    assert_eq!(
+        template.get("DailyTemperatures").unwrap().properties["Triggers"],
+        json!([{"Schedule": {"ScheduleExpression": "cron(0 6 * * ? *)"}}])
+    );
+}
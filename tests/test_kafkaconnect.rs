@@ -0,0 +1,199 @@
+use caroline::types::AWS::KafkaConnect::Connector::{
+    ApacheKafkaClusterBuilder, CapacityBuilder, Connector, ConnectorBuilder,
+    CustomPluginBuilder as ConnectorCustomPluginBuilder, KafkaClusterBuilder,
+    KafkaClusterClientAuthenticationBuilder, KafkaClusterEncryptionInTransitBuilder, PluginBuilder,
+    ProvisionedCapacityBuilder, VpcBuilder,
+};
+use caroline::types::AWS::KafkaConnect::CustomPlugin::{
+    CustomPlugin, CustomPluginBuilder, CustomPluginLocationBuilder, S3LocationBuilder,
+};
+use caroline::types::AWS::KafkaConnect::WorkerConfiguration::{
+    WorkerConfiguration, WorkerConfigurationBuilder,
+};
+use caroline::{Expr, Intrinsic, Template};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[test]
+fn test_connector() {
+    let connector = ConnectorBuilder::default()
+        .Capacity(
+            CapacityBuilder::default()
+                .ProvisionedCapacity(
+                    ProvisionedCapacityBuilder::default()
+                        .WorkerCount(2)
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .ConnectorConfiguration(
+            vec![(
+                "connector.class".to_string(),
+                "io.confluent.connect.s3.S3SinkConnector".into(),
+            )]
+            .into_iter()
+            .collect::<HashMap<String, Expr<String>>>(),
+        )
+        .ConnectorName("orders-sink")
+        .KafkaCluster(
+            KafkaClusterBuilder::default()
+                .ApacheKafkaCluster(
+                    ApacheKafkaClusterBuilder::default()
+                        .BootstrapServers("b-1.kafka.example.com:9092")
+                        .Vpc(
+                            VpcBuilder::default()
+                                .SecurityGroups(vec!["sg-0123456789abcdef0".into()])
+                                .Subnets(vec!["subnet-0123456789abcdef0".into()])
+                                .build()
+                                .unwrap(),
+                        )
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .KafkaClusterClientAuthentication(
+            KafkaClusterClientAuthenticationBuilder::default()
+                .AuthenticationType("NONE")
+                .build()
+                .unwrap(),
+        )
+        .KafkaClusterEncryptionInTransit(
+            KafkaClusterEncryptionInTransitBuilder::default()
+                .EncryptionType("PLAINTEXT")
+                .build()
+                .unwrap(),
+        )
+        .KafkaConnectVersion("2.7.1")
+        .Plugins(vec![PluginBuilder::default()
+            .CustomPlugin(
+                ConnectorCustomPluginBuilder::default()
+                    .CustomPluginArn(Intrinsic::get_att("Plugin", "CustomPluginArn").unwrap())
+                    .Revision(1)
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()])
+        .ServiceExecutionRoleArn("arn:aws:iam::123456789012:role/kafka-connect")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Connector", connector).unwrap();
+    let entry = template.get("Connector").unwrap();
+    assert_eq!(entry.type_, "AWS::KafkaConnect::Connector");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "Capacity": {"ProvisionedCapacity": {"WorkerCount": 2}},
+            "ConnectorConfiguration": {
+                "connector.class": "io.confluent.connect.s3.S3SinkConnector"
+            },
+            "ConnectorName": "orders-sink",
+            "KafkaCluster": {
+                "ApacheKafkaCluster": {
+                    "BootstrapServers": "b-1.kafka.example.com:9092",
+                    "Vpc": {
+                        "SecurityGroups": ["sg-0123456789abcdef0"],
+                        "Subnets": ["subnet-0123456789abcdef0"]
+                    }
+                }
+            },
+            "KafkaClusterClientAuthentication": {"AuthenticationType": "NONE"},
+            "KafkaClusterEncryptionInTransit": {"EncryptionType": "PLAINTEXT"},
+            "KafkaConnectVersion": "2.7.1",
+            "Plugins": [{
+                "CustomPlugin": {
+                    "CustomPluginArn": {"Fn::GetAtt": ["Plugin", "CustomPluginArn"]},
+                    "Revision": 1
+                }
+            }],
+            "ServiceExecutionRoleArn": "arn:aws:iam::123456789012:role/kafka-connect"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let connector = parsed.resource::<Connector>("Connector").unwrap();
+    assert_eq!(serde_json::to_value(connector).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_custom_plugin() {
+    let custom_plugin = CustomPluginBuilder::default()
+        .ContentType("ZIP")
+        .Location(
+            CustomPluginLocationBuilder::default()
+                .S3Location(
+                    S3LocationBuilder::default()
+                        .BucketArn("arn:aws:s3:::connect-plugins")
+                        .FileKey("plugins/connector.zip")
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .Name("s3-sink")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("CustomPlugin", custom_plugin)
+        .unwrap();
+    let entry = template.get("CustomPlugin").unwrap();
+    assert_eq!(entry.type_, "AWS::KafkaConnect::CustomPlugin");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "ContentType": "ZIP",
+            "Location": {
+                "S3Location": {
+                    "BucketArn": "arn:aws:s3:::connect-plugins",
+                    "FileKey": "plugins/connector.zip"
+                }
+            },
+            "Name": "s3-sink"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let custom_plugin = parsed.resource::<CustomPlugin>("CustomPlugin").unwrap();
+    assert_eq!(
+        serde_json::to_value(custom_plugin).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_worker_configuration() {
+    let worker_configuration = WorkerConfigurationBuilder::default()
+        .Name("json-keys")
+        .PropertiesFileContent("key.converter=org.apache.kafka.connect.json.JsonConverter")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("WorkerConfiguration", worker_configuration)
+        .unwrap();
+    let entry = template.get("WorkerConfiguration").unwrap();
+    assert_eq!(entry.type_, "AWS::KafkaConnect::WorkerConfiguration");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "Name": "json-keys",
+            "PropertiesFileContent": "key.converter=org.apache.kafka.connect.json.JsonConverter"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let worker_configuration = parsed
+        .resource::<WorkerConfiguration>("WorkerConfiguration")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(worker_configuration).unwrap(),
+        entry.properties
+    );
+}
@@ -0,0 +1,47 @@
+use caroline::types::AWS::Keyspaces::Table::{
+    ClusteringKeyColumnBuilder, Column, ColumnBuilder, TableBuilder,
+};
+use caroline::Template;
+use serde_json::json;
+
+fn column(name: &str, column_type: &str) -> Column {
+    ColumnBuilder::default()
+        .ColumnName(name)
+        .ColumnType(column_type)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_table_schema() {
+    let table = TableBuilder::default()
+        .KeyspaceName("telemetry")
+        .TableName("readings")
+        .PartitionKeyColumns(vec![column("device_id", "uuid")])
+        .ClusteringKeyColumns(vec![ClusteringKeyColumnBuilder::default()
+            .Column(column("recorded_at", "timestamp"))
+            .OrderBy("DESC")
+            .build()
+            .unwrap()])
+        .RegularColumns(vec![
+            column("value", "double"),
+            column("labels", "map<text, text>"),
+        ])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Readings", table).unwrap();
+    let properties = &template.get("Readings").unwrap().properties;
+    assert_eq!(
+        properties["ClusteringKeyColumns"],
+        json!([{
+            "Column": {"ColumnName": "recorded_at", "ColumnType": "timestamp"},
+            "OrderBy": "DESC"
+        }])
+    );
+    assert_eq!(
+        properties["RegularColumns"][1],
+        json!({"ColumnName": "labels", "ColumnType": "map<text, text>"})
+    );
+}
@@ -0,0 +1,99 @@
+use caroline::types::AWS::LakeFormation::DataLakeSettings::{
+    DataLakeSettings, DataLakeSettingsBuilder,
+};
+use caroline::types::AWS::LakeFormation::Permissions::{
+    DataLakePrincipalBuilder, DatabaseResourceBuilder, Permissions, PermissionsBuilder,
+    ResourceBuilder as PermissionsResourceBuilder,
+};
+use caroline::types::AWS::LakeFormation::Resource::{Resource, ResourceBuilder};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_data_lake_settings() {
+    let data_lake_settings = DataLakeSettingsBuilder::default()
+        .AllowExternalDataFiltering(true)
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("DataLakeSettings", data_lake_settings)
+        .unwrap();
+    let entry = template.get("DataLakeSettings").unwrap();
+    assert_eq!(entry.type_, "AWS::LakeFormation::DataLakeSettings");
+    assert_eq!(
+        entry.properties,
+        json!({"AllowExternalDataFiltering": true})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let data_lake_settings = parsed
+        .resource::<DataLakeSettings>("DataLakeSettings")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(data_lake_settings).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_permissions() {
+    let permissions = PermissionsBuilder::default()
+        .DataLakePrincipal(
+            DataLakePrincipalBuilder::default()
+                .DataLakePrincipalIdentifier("arn:aws:iam::123456789012:role/analyst")
+                .build()
+                .unwrap(),
+        )
+        .Resource(
+            PermissionsResourceBuilder::default()
+                .DatabaseResource(
+                    DatabaseResourceBuilder::default()
+                        .Name("sales")
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Permissions", permissions).unwrap();
+    let entry = template.get("Permissions").unwrap();
+    assert_eq!(entry.type_, "AWS::LakeFormation::Permissions");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "DataLakePrincipal": {
+                "DataLakePrincipalIdentifier": "arn:aws:iam::123456789012:role/analyst"
+            },
+            "Resource": {"DatabaseResource": {"Name": "sales"}}
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let permissions = parsed.resource::<Permissions>("Permissions").unwrap();
+    assert_eq!(serde_json::to_value(permissions).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_resource() {
+    let resource = ResourceBuilder::default()
+        .ResourceArn("arn:aws:s3:::data-lake")
+        .UseServiceLinkedRole(true)
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Resource", resource).unwrap();
+    let entry = template.get("Resource").unwrap();
+    assert_eq!(entry.type_, "AWS::LakeFormation::Resource");
+    assert_eq!(
+        entry.properties,
+        json!({"ResourceArn": "arn:aws:s3:::data-lake", "UseServiceLinkedRole": true})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let resource = parsed.resource::<Resource>("Resource").unwrap();
+    assert_eq!(serde_json::to_value(resource).unwrap(), entry.properties);
+}
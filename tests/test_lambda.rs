@@ -0,0 +1,170 @@
+use caroline::enums::UntrustedArtifactOnDeployment;
+use caroline::types::AWS::Lambda::Alias::{
+    AliasBuilder, AliasRoutingConfigurationBuilder, VersionWeightBuilder,
+};
+use caroline::types::AWS::Lambda::EventSourceMapping::{
+    DestinationConfigBuilder, EventSourceMappingBuilder, FilterBuilder, FilterCriteriaBuilder,
+    OnFailureBuilder, ScalingConfigBuilder,
+};
+use caroline::types::AWS::Lambda::Function::{CodeBuilder, FunctionBuilder};
+use caroline::types::AWS::Lambda::Version::VersionBuilder;
+use caroline::types::AWS::Signer::SigningProfile::{
+    SignatureValidityPeriodBuilder, SigningProfileBuilder,
+};
+use caroline::{Error, Intrinsic, Template};
+use serde_json::json;
+
+fn template() -> Template {
+    let function = FunctionBuilder::default()
+        .Code(
+            CodeBuilder::default()
+                .S3Bucket("artifacts")
+                .S3Key("handler.zip")
+                .build()
+                .unwrap(),
+        )
+        .Handler("index.handler")
+        .Role("arn:aws:iam::123456789012:role/lambda")
+        .Runtime("nodejs18.x")
+        .build()
+        .unwrap();
+    let profile = SigningProfileBuilder::default()
+        .PlatformId("AWSLambda-SHA384-ECDSA")
+        .SignatureValidityPeriod(
+            SignatureValidityPeriodBuilder::default()
+                .Type("MONTHS")
+                .Value(12)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Handler", function).unwrap();
+    template.add_resource("Signing", profile).unwrap();
+    template
+}
+
+#[test]
+fn test_require_code_signing() {
+    let mut template = template();
+    let config = template
+        .require_code_signing("Handler", "Signing", UntrustedArtifactOnDeployment::Enforce)
+        .unwrap();
+    assert_eq!(config.as_str(), "HandlerCodeSigningConfig");
+
+    let entry = template.get("HandlerCodeSigningConfig").unwrap();
+    assert_eq!(entry.type_, "AWS::Lambda::CodeSigningConfig");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "AllowedPublishers": {
+                "SigningProfileVersionArns": [{"Fn::GetAtt": ["Signing", "ProfileVersionArn"]}]
+            },
+            "CodeSigningPolicies": {"UntrustedArtifactOnDeployment": "Enforce"}
+        })
+    );
+    assert_eq!(
+        template.get("Handler").unwrap().properties["CodeSigningConfigArn"],
+        json!({"Fn::GetAtt": ["HandlerCodeSigningConfig", "CodeSigningConfigArn"]})
+    );
+}
+
+#[test]
+fn test_require_code_signing_of_wrong_resources() {
+    let mut template = template();
+    match template.require_code_signing("Signing", "Handler", UntrustedArtifactOnDeployment::Warn) {
+        Err(Error::TypeMismatch { logical_id, .. }) => assert_eq!(logical_id, "Signing"),
+        other => panic!("Expected a type mismatch, got {:?}", other),
+    }
+    assert!(matches!(
+        template.require_code_signing("Missing", "Signing", UntrustedArtifactOnDeployment::Warn),
+        Err(Error::UnknownLogicalId(_))
+    ));
+    assert!(template.get("SigningCodeSigningConfig").is_none());
+}
+
+#[test]
+fn test_canary_alias() {
+    let mut template = template();
+    let version = VersionBuilder::default()
+        .FunctionName(Intrinsic::reference("Handler").unwrap())
+        .build()
+        .unwrap();
+    let alias = AliasBuilder::default()
+        .FunctionName(Intrinsic::reference("Handler").unwrap())
+        .FunctionVersion("1")
+        .Name("live")
+        .RoutingConfig(
+            AliasRoutingConfigurationBuilder::default()
+                .AdditionalVersionWeights(vec![VersionWeightBuilder::default()
+                    .FunctionVersion(Intrinsic::get_att("HandlerVersion", "Version").unwrap())
+                    .FunctionWeight(0.25)
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    template.add_resource("HandlerVersion", version).unwrap();
+    template.add_resource("Live", alias).unwrap();
+    assert_eq!(
+        template.get("Live").unwrap().properties["RoutingConfig"],
+        json!({"AdditionalVersionWeights": [{
+            "FunctionVersion": {"Fn::GetAtt": ["HandlerVersion", "Version"]},
+            "FunctionWeight": 0.25
+        }]})
+    );
+}
+
+#[test]
+fn test_filtered_event_source_mapping() {
+    let mut template = template();
+    let mapping = EventSourceMappingBuilder::default()
+        .EventSourceArn(Intrinsic::get_att("Orders", "Arn").unwrap())
+        .FunctionName(Intrinsic::reference("Handler").unwrap())
+        .FilterCriteria(
+            FilterCriteriaBuilder::default()
+                .Filters(vec![FilterBuilder::default()
+                    .Pattern(r#"{"body": {"status": ["paid"]}}"#)
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap(),
+        )
+        .DestinationConfig(
+            DestinationConfigBuilder::default()
+                .OnFailure(
+                    OnFailureBuilder::default()
+                        .Destination(Intrinsic::get_att("Failed", "Arn").unwrap())
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .ScalingConfig(
+            ScalingConfigBuilder::default()
+                .MaximumConcurrency(5)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    template.add_resource("PaidOrders", mapping).unwrap();
+    let properties = &template.get("PaidOrders").unwrap().properties;
+    assert_eq!(
+        properties["FilterCriteria"],
+        json!({"Filters": [{"Pattern": "{\"body\": {\"status\": [\"paid\"]}}"}]})
+    );
+    assert_eq!(
+        properties["DestinationConfig"],
+        json!({"OnFailure": {"Destination": {"Fn::GetAtt": ["Failed", "Arn"]}}})
+    );
+    assert_eq!(
+        properties["ScalingConfig"],
+        json!({"MaximumConcurrency": 5})
+    );
+}
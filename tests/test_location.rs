@@ -0,0 +1,157 @@
+use caroline::types::AWS::Location::GeofenceCollection::{
+    GeofenceCollection, GeofenceCollectionBuilder,
+};
+use caroline::types::AWS::Location::Map::{Map, MapBuilder, MapConfigurationBuilder};
+use caroline::types::AWS::Location::PlaceIndex::{PlaceIndex, PlaceIndexBuilder};
+use caroline::types::AWS::Location::RouteCalculator::{RouteCalculator, RouteCalculatorBuilder};
+use caroline::types::AWS::Location::Tracker::{Tracker, TrackerBuilder};
+use caroline::types::AWS::Location::TrackerConsumer::{TrackerConsumer, TrackerConsumerBuilder};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_geofence_collection() {
+    let geofence_collection = GeofenceCollectionBuilder::default()
+        .CollectionName("deliveries")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("GeofenceCollection", geofence_collection)
+        .unwrap();
+    let entry = template.get("GeofenceCollection").unwrap();
+    assert_eq!(entry.type_, "AWS::Location::GeofenceCollection");
+    assert_eq!(entry.properties, json!({"CollectionName": "deliveries"}));
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let geofence_collection = parsed
+        .resource::<GeofenceCollection>("GeofenceCollection")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(geofence_collection).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_map() {
+    let map = MapBuilder::default()
+        .Configuration(
+            MapConfigurationBuilder::default()
+                .Style("VectorEsriStreets")
+                .build()
+                .unwrap(),
+        )
+        .MapName("streets")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Map", map).unwrap();
+    let entry = template.get("Map").unwrap();
+    assert_eq!(entry.type_, "AWS::Location::Map");
+    assert_eq!(
+        entry.properties,
+        json!({"Configuration": {"Style": "VectorEsriStreets"}, "MapName": "streets"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let map = parsed.resource::<Map>("Map").unwrap();
+    assert_eq!(serde_json::to_value(map).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_place_index() {
+    let place_index = PlaceIndexBuilder::default()
+        .DataSource("Esri")
+        .IndexName("places")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("PlaceIndex", place_index).unwrap();
+    let entry = template.get("PlaceIndex").unwrap();
+    assert_eq!(entry.type_, "AWS::Location::PlaceIndex");
+    assert_eq!(
+        entry.properties,
+        json!({"DataSource": "Esri", "IndexName": "places"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let place_index = parsed.resource::<PlaceIndex>("PlaceIndex").unwrap();
+    assert_eq!(serde_json::to_value(place_index).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_route_calculator() {
+    let route_calculator = RouteCalculatorBuilder::default()
+        .CalculatorName("routes")
+        .DataSource("Esri")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("RouteCalculator", route_calculator)
+        .unwrap();
+    let entry = template.get("RouteCalculator").unwrap();
+    assert_eq!(entry.type_, "AWS::Location::RouteCalculator");
+    assert_eq!(
+        entry.properties,
+        json!({"CalculatorName": "routes", "DataSource": "Esri"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let route_calculator = parsed
+        .resource::<RouteCalculator>("RouteCalculator")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(route_calculator).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_tracker() {
+    let tracker = TrackerBuilder::default()
+        .TrackerName("vehicles")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Tracker", tracker).unwrap();
+    let entry = template.get("Tracker").unwrap();
+    assert_eq!(entry.type_, "AWS::Location::Tracker");
+    assert_eq!(entry.properties, json!({"TrackerName": "vehicles"}));
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let tracker = parsed.resource::<Tracker>("Tracker").unwrap();
+    assert_eq!(serde_json::to_value(tracker).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_tracker_consumer() {
+    let tracker_consumer = TrackerConsumerBuilder::default()
+        .ConsumerArn("arn:aws:geo:us-east-1:123456789012:geofence-collection/deliveries")
+        .TrackerName("vehicles")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("TrackerConsumer", tracker_consumer)
+        .unwrap();
+    let entry = template.get("TrackerConsumer").unwrap();
+    assert_eq!(entry.type_, "AWS::Location::TrackerConsumer");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "ConsumerArn": "arn:aws:geo:us-east-1:123456789012:geofence-collection/deliveries",
+            "TrackerName": "vehicles"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let tracker_consumer = parsed
+        .resource::<TrackerConsumer>("TrackerConsumer")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(tracker_consumer).unwrap(),
+        entry.properties
+    );
+}
@@ -0,0 +1,103 @@
+use caroline::types::AWS::Macie::CustomDataIdentifier::{
+    CustomDataIdentifier, CustomDataIdentifierBuilder,
+};
+use caroline::types::AWS::Macie::FindingsFilter::{
+    CriterionAdditionalPropertiesBuilder, FindingCriteriaBuilder, FindingsFilter,
+    FindingsFilterBuilder,
+};
+use caroline::types::AWS::Macie::Session::{Session, SessionBuilder};
+use caroline::Template;
+use serde_json::json;
+use std::collections::HashMap;
+
+#[test]
+fn test_custom_data_identifier() {
+    let custom_data_identifier = CustomDataIdentifierBuilder::default()
+        .Name("custom-data-identifier")
+        .Regex("EMP-[0-9]{6}")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("CustomDataIdentifier", custom_data_identifier)
+        .unwrap();
+    let entry = template.get("CustomDataIdentifier").unwrap();
+    assert_eq!(entry.type_, "AWS::Macie::CustomDataIdentifier");
+    assert_eq!(
+        entry.properties,
+        json!({"Name": "custom-data-identifier", "Regex": "EMP-[0-9]{6}"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let custom_data_identifier = parsed
+        .resource::<CustomDataIdentifier>("CustomDataIdentifier")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(custom_data_identifier).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_findings_filter() {
+    let findings_filter = FindingsFilterBuilder::default()
+        .FindingCriteria(
+            FindingCriteriaBuilder::default()
+                .Criterion(
+                    vec![(
+                        "severity.description".to_string(),
+                        CriterionAdditionalPropertiesBuilder::default()
+                            .eq(vec!["High".into()])
+                            .build()
+                            .unwrap(),
+                    )]
+                    .into_iter()
+                    .collect::<HashMap<_, _>>(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .Name("findings-filter")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("FindingsFilter", findings_filter)
+        .unwrap();
+    let entry = template.get("FindingsFilter").unwrap();
+    assert_eq!(entry.type_, "AWS::Macie::FindingsFilter");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "FindingCriteria": {"Criterion": {"severity.description": {"eq": ["High"]}}},
+            "Name": "findings-filter"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let findings_filter = parsed.resource::<FindingsFilter>("FindingsFilter").unwrap();
+    assert_eq!(
+        serde_json::to_value(findings_filter).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_session() {
+    let session = SessionBuilder::default()
+        .FindingPublishingFrequency("ONE_HOUR")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Session", session).unwrap();
+    let entry = template.get("Session").unwrap();
+    assert_eq!(entry.type_, "AWS::Macie::Session");
+    assert_eq!(
+        entry.properties,
+        json!({"FindingPublishingFrequency": "ONE_HOUR"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let session = parsed.resource::<Session>("Session").unwrap();
+    assert_eq!(serde_json::to_value(session).unwrap(), entry.properties);
+}
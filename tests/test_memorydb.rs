@@ -0,0 +1,20 @@
+use caroline::types::AWS::MemoryDB::User::{AuthenticationModeBuilder, UserBuilder};
+use caroline::{DebugUnredacted, Expr};
+
+#[test]
+fn test_passwords_are_redacted() {
+    let user = UserBuilder::default()
+        .UserName("reader")
+        .AccessString("on ~* &* +@read")
+        .AuthenticationMode(
+            AuthenticationModeBuilder::default()
+                .Type("password")
+                .Passwords(vec![Expr::from("correct-horse-battery-staple")])
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    assert!(!format!("{:?}", user).contains("correct-horse"));
+    assert!(format!("{:?}", user.debug_unredacted()).contains("correct-horse"));
+}
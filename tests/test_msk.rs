@@ -0,0 +1,104 @@
+use caroline::types::AWS::MSK::BatchScramSecret::{BatchScramSecret, BatchScramSecretBuilder};
+use caroline::types::AWS::MSK::Configuration::{Configuration, ConfigurationBuilder};
+use caroline::types::AWS::MSK::ServerlessCluster::{
+    ClientAuthenticationBuilder, IamBuilder, SaslBuilder, ServerlessCluster,
+    ServerlessClusterBuilder, VpcConfigBuilder,
+};
+use caroline::{Intrinsic, Template};
+use serde_json::json;
+
+#[test]
+fn test_batch_scram_secret() {
+    let batch_scram_secret = BatchScramSecretBuilder::default()
+        .ClusterArn(Intrinsic::reference("Cluster").unwrap())
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("BatchScramSecret", batch_scram_secret)
+        .unwrap();
+    let entry = template.get("BatchScramSecret").unwrap();
+    assert_eq!(entry.type_, "AWS::MSK::BatchScramSecret");
+    assert_eq!(entry.properties, json!({"ClusterArn": {"Ref": "Cluster"}}));
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let batch_scram_secret = parsed
+        .resource::<BatchScramSecret>("BatchScramSecret")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(batch_scram_secret).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_configuration() {
+    let configuration = ConfigurationBuilder::default()
+        .Name("orders")
+        .ServerProperties("auto.create.topics.enable=false")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("Configuration", configuration)
+        .unwrap();
+    let entry = template.get("Configuration").unwrap();
+    assert_eq!(entry.type_, "AWS::MSK::Configuration");
+    assert_eq!(
+        entry.properties,
+        json!({"Name": "orders", "ServerProperties": "auto.create.topics.enable=false"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let configuration = parsed.resource::<Configuration>("Configuration").unwrap();
+    assert_eq!(
+        serde_json::to_value(configuration).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_serverless_cluster() {
+    let serverless_cluster = ServerlessClusterBuilder::default()
+        .ClientAuthentication(
+            ClientAuthenticationBuilder::default()
+                .Sasl(
+                    SaslBuilder::default()
+                        .Iam(IamBuilder::default().Enabled(true).build().unwrap())
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .ClusterName("orders")
+        .VpcConfigs(vec![VpcConfigBuilder::default()
+            .SubnetIds(vec!["subnet-0123456789abcdef0".into()])
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("ServerlessCluster", serverless_cluster)
+        .unwrap();
+    let entry = template.get("ServerlessCluster").unwrap();
+    assert_eq!(entry.type_, "AWS::MSK::ServerlessCluster");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "ClientAuthentication": {"Sasl": {"Iam": {"Enabled": true}}},
+            "ClusterName": "orders",
+            "VpcConfigs": [{"SubnetIds": ["subnet-0123456789abcdef0"]}]
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let serverless_cluster = parsed
+        .resource::<ServerlessCluster>("ServerlessCluster")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(serverless_cluster).unwrap(),
+        entry.properties
+    );
+}
@@ -0,0 +1,80 @@
+use caroline::types::AWS::OpenSearchServerless::Collection::CollectionBuilder;
+use caroline::types::AWS::OpenSearchServerless::SecurityPolicy::SecurityPolicyBuilder;
+use caroline::types::AWS::OSIS::Pipeline::{
+    CloudWatchLogDestinationBuilder, LogPublishingOptionsBuilder, PipelineBuilder,
+};
+use caroline::Template;
+use serde_json::json;
+
+const PIPELINE: &str = "version: \"2\"
+log-pipeline:
+  source:
+    http:
+      path: /logs
+  sink:
+    - opensearch:
+        hosts: [\"https://logs.eu-west-1.aoss.amazonaws.com\"]
+        index: application-logs
+        aws:
+          serverless: true
+";
+
+#[test]
+fn test_ingestion_into_collection() {
+    let encryption = SecurityPolicyBuilder::default()
+        .Name("logs-encryption")
+        .Type("encryption")
+        .Policy(
+            json!({
+                "Rules": [{"ResourceType": "collection", "Resource": ["collection/logs"]}],
+                "AWSOwnedKey": true
+            })
+            .to_string(),
+        )
+        .build()
+        .unwrap();
+    let collection = CollectionBuilder::default()
+        .Name("logs")
+        .Type("TIMESERIES")
+        .build()
+        .unwrap();
+    let pipeline = PipelineBuilder::default()
+        .PipelineName("logs-ingestion")
+        .MinUnits(1)
+        .MaxUnits(4)
+        .PipelineConfigurationBody(PIPELINE)
+        .LogPublishingOptions(
+            LogPublishingOptionsBuilder::default()
+                .IsLoggingEnabled(true)
+                .CloudWatchLogDestination(
+                    CloudWatchLogDestinationBuilder::default()
+                        .LogGroup("/aws/vendedlogs/OpenSearchIngestion/logs")
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("LogsEncryption", encryption).unwrap();
+    template
+        .add_resource("Logs", collection)
+        .unwrap()
+        .depends_on("LogsEncryption")
+        .unwrap();
+    template.add_resource("LogsIngestion", pipeline).unwrap();
+    assert_eq!(
+        template.get("LogsIngestion").unwrap().properties["LogPublishingOptions"],
+        json!({
+            "IsLoggingEnabled": true,
+            "CloudWatchLogDestination": {"LogGroup": "/aws/vendedlogs/OpenSearchIngestion/logs"}
+        })
+    );
+    assert_eq!(
+        template.get("Logs").unwrap().properties,
+        json!({"Name": "logs", "Type": "TIMESERIES"})
+    );
+}
@@ -0,0 +1,108 @@
+use caroline::parameter_types::{
+    CommaDelimitedList, ImageId, List, Number, ParameterType, SecureString, SsmValue, SubnetId,
+    Text,
+};
+use caroline::types::AWS::AutoScaling::AutoScalingGroup::AutoScalingGroupBuilder;
+use caroline::types::AWS::EC2::Instance::InstanceBuilder;
+use caroline::types::AWS::RDS::DBInstance::DBInstanceBuilder;
+use caroline::{Error, Parameter, Template};
+use serde_json::{json, Value};
+
+#[test]
+fn test_type_names() {
+    assert_eq!(Number::type_name(), "Number");
+    assert_eq!(List::<SubnetId>::type_name(), "List<AWS::EC2::Subnet::Id>");
+    assert_eq!(
+        SsmValue::<ImageId>::type_name(),
+        "AWS::SSM::Parameter::Value<AWS::EC2::Image::Id>"
+    );
+    assert_eq!(
+        SsmValue::<CommaDelimitedList>::type_name(),
+        "AWS::SSM::Parameter::Value<CommaDelimitedList>"
+    );
+}
+
+#[test]
+fn test_typed_parameters() {
+    let mut template = Template::new();
+    let ami = template
+        .add_typed_parameter::<SsmValue<ImageId>>("Ami", Parameter::default())
+        .unwrap();
+    let subnets = template
+        .add_typed_parameter::<List<SubnetId>>("Subnets", Parameter::new("String"))
+        .unwrap();
+    let capacity = template
+        .add_typed_parameter::<Number>("Capacity", Parameter::default())
+        .unwrap();
+
+    let instance = InstanceBuilder::default()
+        .ImageId(ami)
+        .SubnetId(subnets.select(0))
+        .build()
+        .unwrap();
+    template.add_resource("Instance", instance).unwrap();
+    let group = AutoScalingGroupBuilder::default()
+        .MinSize("1")
+        .MaxSize("4")
+        .DesiredCapacity(capacity)
+        .build()
+        .unwrap();
+    template.add_resource("Group", group).unwrap();
+
+    let json: Value = serde_json::from_str(&template.to_json().unwrap()).unwrap();
+    assert_eq!(
+        json["Parameters"]["Subnets"]["Type"],
+        "List<AWS::EC2::Subnet::Id>"
+    );
+    assert_eq!(
+        json["Resources"]["Instance"]["Properties"],
+        json!({
+            "ImageId": {"Ref": "Ami"},
+            "SubnetId": {"Fn::Select": [0, {"Ref": "Subnets"}]}
+        })
+    );
+    assert_eq!(
+        json["Resources"]["Group"]["Properties"]["DesiredCapacity"],
+        json!({"Ref": "Capacity"})
+    );
+}
+
+#[test]
+fn test_typed_parameter_lookup() {
+    let mut template = Template::new();
+    template
+        .add_parameter("Name", Parameter::new("String"))
+        .unwrap();
+    assert_eq!(
+        template
+            .typed_parameter::<Text>("Name")
+            .unwrap()
+            .logical_id(),
+        "Name"
+    );
+    match template.typed_parameter::<Number>("Name") {
+        Err(Error::TypeMismatch {
+            expected, found, ..
+        }) => assert_eq!((expected.as_str(), found.as_str()), ("Number", "String")),
+        other => panic!("Expected a type mismatch, got {:?}", other),
+    }
+    assert!(matches!(
+        template.typed_parameter::<Text>("Nope"),
+        Err(Error::UnknownLogicalId(_))
+    ));
+}
+
+#[test]
+fn test_secure_string() {
+    let database = DBInstanceBuilder::default()
+        .DBInstanceClass("db.t3.micro")
+        .MasterUserPassword(SecureString::new("/db/password").version(3))
+        .build()
+        .unwrap();
+    let mut template = Template::new();
+    template.add_resource("Database", database).unwrap();
+    assert_eq!(
+        template.get("Database").unwrap().properties["MasterUserPassword"],
+        "{{resolve:ssm-secure:/db/password:3}}"
+    );
+}
@@ -0,0 +1,339 @@
+use caroline::types::AWS::EC2::Subnet::SubnetBuilder;
+use caroline::types::AWS::EC2::VPC::VPCBuilder;
+use caroline::types::AWS::S3::Bucket::BucketBuilder;
+use caroline::types::AWS::SNS::Topic::TopicBuilder;
+use caroline::{Error, Intrinsic, Output, Parameter, Template, MAX_RESOURCES};
+use serde_json::json;
+
+fn stack() -> Template {
+    let mut template = Template::new();
+    template
+        .add_parameter("Env", Parameter::new("String"))
+        .unwrap();
+    template
+        .add_condition("IsProd", json!({"Fn::Equals": [{"Ref": "Env"}, "prod"]}))
+        .unwrap();
+    template
+        .add_resource(
+            "Vpc",
+            VPCBuilder::default()
+                .CidrBlock("10.0.0.0/16")
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+    for idx in 0..4 {
+        let subnet = SubnetBuilder::default()
+            .VpcId(Intrinsic::reference("Vpc").unwrap())
+            .CidrBlock(format!("10.0.{}.0/24", idx))
+            .build()
+            .unwrap();
+        template
+            .add_resource(format!("Subnet{}", idx), subnet)
+            .unwrap();
+    }
+    template
+        .get_mut("Subnet3")
+        .unwrap()
+        .depends_on("Subnet0")
+        .unwrap();
+    template
+        .add_resource("Bucket", BucketBuilder::default().build().unwrap())
+        .unwrap();
+    template
+        .add_resource("Topic", TopicBuilder::default().build().unwrap())
+        .unwrap()
+        .set_condition("IsProd");
+    template
+        .add_output("VpcId", Output::new(Intrinsic::reference("Vpc").unwrap()))
+        .unwrap();
+    template
+        .add_output(
+            "Subnets",
+            Output::new(Intrinsic::sub("${Subnet2},${Subnet3}")),
+        )
+        .unwrap();
+    template
+}
+
+#[test]
+fn test_partition() {
+    let template = stack();
+    let partition = template
+        .partition(3, |name| {
+            format!("https://templates.s3.amazonaws.com/{}.json", name)
+        })
+        .unwrap();
+    let ids = |template: &Template| {
+        template
+            .resources()
+            .map(|(id, _)| id.to_string())
+            .collect::<Vec<String>>()
+    };
+
+    // Unrelated resources share a stack, the VPC's resources are split in dependency order
+    assert_eq!(
+        partition.nested.keys().collect::<Vec<&String>>(),
+        vec!["NestedStack0", "NestedStack1", "NestedStack2"]
+    );
+    assert_eq!(
+        ids(&partition.nested["NestedStack0"]),
+        vec!["Bucket", "Topic"]
+    );
+    assert_eq!(
+        ids(&partition.nested["NestedStack1"]),
+        vec!["Subnet0", "Subnet1", "Vpc"]
+    );
+    assert_eq!(
+        ids(&partition.nested["NestedStack2"]),
+        vec!["Subnet2", "Subnet3"]
+    );
+    assert_eq!(
+        ids(&partition.root),
+        vec!["NestedStack0", "NestedStack1", "NestedStack2"]
+    );
+
+    // Conditions are copied along with the parameters they use
+    let first = &partition.nested["NestedStack0"];
+    assert_eq!(
+        first
+            .conditions()
+            .map(|(name, _)| name)
+            .collect::<Vec<&str>>(),
+        vec!["IsProd"]
+    );
+    assert_eq!(
+        first
+            .parameters()
+            .map(|(name, _)| name)
+            .collect::<Vec<&str>>(),
+        vec!["Env"]
+    );
+    assert_eq!(
+        partition.root.get("NestedStack0").unwrap().properties,
+        json!({
+            "TemplateURL": "https://templates.s3.amazonaws.com/NestedStack0.json",
+            "Parameters": {"Env": {"Ref": "Env"}}
+        })
+    );
+
+    // References to other stacks are passed as outputs and parameters
+    let vpc = &partition.nested["NestedStack1"];
+    assert_eq!(
+        vpc.outputs()
+            .map(|(name, output)| (name, output.value.clone()))
+            .collect::<Vec<_>>(),
+        vec![("Vpc", json!({"Ref": "Vpc"}))]
+    );
+    let subnets = &partition.nested["NestedStack2"];
+    assert_eq!(
+        subnets
+            .parameters()
+            .map(|(name, _)| name)
+            .collect::<Vec<&str>>(),
+        vec!["Vpc"]
+    );
+    assert_eq!(
+        subnets.get("Subnet2").unwrap().properties["VpcId"],
+        json!({"Ref": "Vpc"})
+    );
+    assert!(subnets.get("Subnet3").unwrap().depends_on.is_empty());
+    let stack = partition.root.get("NestedStack2").unwrap();
+    assert_eq!(
+        stack.properties["Parameters"],
+        json!({"Vpc": {"Fn::GetAtt": ["NestedStack1", "Outputs.Vpc"]}})
+    );
+    assert_eq!(stack.depends_on, vec!["NestedStack1"]);
+
+    // Outputs of the root get their values from the stacks
+    let outputs = partition.root.outputs().collect::<Vec<_>>();
+    assert_eq!(
+        outputs[0].1.value,
+        json!({"Fn::Sub": "${NestedStack2.Outputs.Subnet2},${NestedStack2.Outputs.Subnet3}"})
+    );
+    assert_eq!(
+        outputs[1].1.value,
+        json!({"Fn::GetAtt": ["NestedStack1", "Outputs.Vpc"]})
+    );
+    assert_eq!(
+        subnets
+            .outputs()
+            .map(|(name, _)| name)
+            .collect::<Vec<&str>>(),
+        vec!["Subnet2", "Subnet3"]
+    );
+    assert_eq!(partition.root.parameters().count(), 1);
+}
+
+#[test]
+fn test_partition_within_limit() {
+    let template = stack();
+    let partition = template
+        .partition(MAX_RESOURCES, |name| name.to_string())
+        .unwrap();
+    assert_eq!(partition.root, template);
+    assert!(partition.nested.is_empty());
+}
+
+/// A VPC with `subnets` subnets in it
+fn vpc(subnets: usize) -> Template {
+    let mut template = Template::new();
+    template
+        .add_resource(
+            "Vpc",
+            VPCBuilder::default()
+                .CidrBlock("10.0.0.0/16")
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+    for idx in 0..subnets {
+        let subnet = SubnetBuilder::default()
+            .VpcId(Intrinsic::reference("Vpc").unwrap())
+            .CidrBlock(format!("10.0.{}.0/24", idx))
+            .build()
+            .unwrap();
+        template
+            .add_resource(format!("Subnet{}", idx), subnet)
+            .unwrap();
+    }
+    template
+}
+
+#[test]
+fn test_partition_after_dependencies() {
+    let mut template = vpc(3);
+    template
+        .add_resource("Bucket", BucketBuilder::default().build().unwrap())
+        .unwrap();
+    let partition = template.partition(3, |name| name.to_string()).unwrap();
+    let ids = |name: &str| {
+        partition.nested[name]
+            .resources()
+            .map(|(id, _)| id.to_string())
+            .collect::<Vec<String>>()
+    };
+
+    // The VPC's last subnet would fit next to the bucket, but goes after the VPC's stack
+    assert_eq!(ids("NestedStack0"), vec!["Bucket"]);
+    assert_eq!(ids("NestedStack1"), vec!["Subnet0", "Subnet1", "Vpc"]);
+    assert_eq!(ids("NestedStack2"), vec!["Subnet2"]);
+    assert!(partition
+        .root
+        .get("NestedStack0")
+        .unwrap()
+        .depends_on
+        .is_empty());
+    assert_eq!(
+        partition.root.get("NestedStack2").unwrap().properties["Parameters"],
+        json!({"Vpc": {"Fn::GetAtt": ["NestedStack1", "Outputs.Vpc"]}})
+    );
+}
+
+#[test]
+fn test_partition_cycle() {
+    let mut template = vpc(3);
+    template
+        .get_mut("Subnet1")
+        .unwrap()
+        .depends_on("Subnet2")
+        .unwrap();
+    template
+        .get_mut("Subnet2")
+        .unwrap()
+        .depends_on("Subnet1")
+        .unwrap();
+    let partition = template.partition(3, |name| name.to_string()).unwrap();
+
+    // The subnets depending on each other stay together, rather than filling the first stack
+    let ids = |name: &str| {
+        partition.nested[name]
+            .resources()
+            .map(|(id, _)| id.to_string())
+            .collect::<Vec<String>>()
+    };
+    assert_eq!(ids("NestedStack0"), vec!["Subnet0", "Vpc"]);
+    assert_eq!(ids("NestedStack1"), vec!["Subnet1", "Subnet2"]);
+
+    match template.partition(1, |name| name.to_string()) {
+        Err(Error::OversizedCycle(cycle)) => assert_eq!(cycle, vec!["Subnet1", "Subnet2"]),
+        other => panic!("Expected an oversized cycle, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_partition_import_names() {
+    let template = Template::from_json(
+        &json!({
+            "Parameters": {"BucketDomainName": {"Type": "String"}},
+            "Resources": {
+                "Bucket": {"Type": "AWS::S3::Bucket"},
+                "BucketArn": {"Type": "AWS::SNS::Topic"},
+                "Alerts": {
+                    "Type": "AWS::SNS::Topic",
+                    "Properties": {
+                        "DisplayName": {"Fn::Sub": [
+                            "${Bucket.Arn}-${!Bucket}-${Local}",
+                            {"Local": {"Fn::GetAtt": "Bucket.DomainName"}}
+                        ]},
+                        "TopicName": {"Fn::Join": ["-", [
+                            {"Fn::GetAtt": ["Bucket", "Arn"]},
+                            {"Ref": "BucketArn"},
+                            {"Fn::GetAtt": ["Bucket", "DomainName"]},
+                            {"Ref": "BucketDomainName"}
+                        ]]}
+                    }
+                }
+            }
+        })
+        .to_string(),
+    )
+    .unwrap();
+    let partition = template.partition(2, |name| name.to_string()).unwrap();
+
+    // Names an attribute would get, but which are taken by a resource or a parameter of the
+    // template, are numbered
+    let alerts = &partition.nested["NestedStack1"];
+    assert_eq!(
+        alerts.get("Alerts").unwrap().properties["TopicName"],
+        json!({"Fn::Join": ["-", [
+            {"Ref": "BucketArn2"},
+            {"Ref": "BucketArn"},
+            {"Ref": "BucketDomainName2"},
+            {"Ref": "BucketDomainName"}
+        ]]})
+    );
+    assert_eq!(
+        alerts.get("Alerts").unwrap().properties["DisplayName"],
+        json!({"Fn::Sub": [
+            "${BucketArn2}-${!Bucket}-${Local}",
+            {"Local": {"Ref": "BucketDomainName2"}}
+        ]})
+    );
+    assert_eq!(
+        alerts
+            .parameters()
+            .map(|(name, _)| name)
+            .collect::<Vec<&str>>(),
+        vec![
+            "BucketArn",
+            "BucketArn2",
+            "BucketDomainName",
+            "BucketDomainName2"
+        ]
+    );
+    assert_eq!(
+        partition.nested["NestedStack0"]
+            .outputs()
+            .map(|(name, output)| (name, output.value.clone()))
+            .collect::<Vec<_>>(),
+        vec![
+            ("BucketArn", json!({"Ref": "BucketArn"})),
+            ("BucketArn2", json!({"Fn::GetAtt": ["Bucket", "Arn"]})),
+            (
+                "BucketDomainName2",
+                json!({"Fn::GetAtt": ["Bucket", "DomainName"]})
+            ),
+        ]
+    );
+}
@@ -0,0 +1,38 @@
+use caroline::prelude::*;
+use caroline::types::AWS::EC2::LaunchTemplate::LaunchTemplateData;
+use serde_json::json;
+
+#[test]
+fn test_prelude_names() {
+    let data: ec2::launch_template::Data = ec2::launch_template::DataBuilder::default()
+        .ImageId("ami-12345678")
+        .InstanceType("t3.micro")
+        .build()
+        .unwrap();
+    let launch_template = ec2::LaunchTemplateBuilder::default()
+        .LaunchTemplateData(data)
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("LaunchTemplate", launch_template)
+        .unwrap();
+    assert_eq!(
+        template.get("LaunchTemplate").unwrap().type_,
+        <ec2::LaunchTemplate as Resource>::TYPE
+    );
+    assert_eq!(
+        template.get("LaunchTemplate").unwrap().properties,
+        json!({"LaunchTemplateData": {"ImageId": "ami-12345678", "InstanceType": "t3.micro"}})
+    );
+
+    // Property types keep their full names, and aliases are the same types
+    let data: LaunchTemplateData = ec2::launch_template::LaunchTemplateData::default();
+    let _: ec2::launch_template::Data = data;
+
+    // Services are named like their Cargo features, resources in snake case
+    let _ = elasticloadbalancingv2::target_group::Matcher::default();
+    let _: rds::db_cluster::Role = rds::db_cluster::DBClusterRole::default();
+    let _ = iam::Role::default();
+}
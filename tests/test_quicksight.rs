@@ -0,0 +1,156 @@
+use caroline::types::AWS::QuickSight::Analysis::{Analysis, AnalysisBuilder};
+use caroline::types::AWS::QuickSight::Dashboard::{Dashboard, DashboardBuilder};
+use caroline::types::AWS::QuickSight::DataSet::{DataSet, DataSetBuilder};
+use caroline::types::AWS::QuickSight::DataSource::{DataSource, DataSourceBuilder};
+use caroline::types::AWS::QuickSight::Template::{
+    DataSetReferenceBuilder, Template as QuickSightTemplate, TemplateBuilder,
+    TemplateSourceAnalysisBuilder, TemplateSourceEntityBuilder,
+};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_analysis() {
+    let analysis = AnalysisBuilder::default()
+        .AnalysisId("sales")
+        .AwsAccountId("123456789012")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Analysis", analysis).unwrap();
+    let entry = template.get("Analysis").unwrap();
+    assert_eq!(entry.type_, "AWS::QuickSight::Analysis");
+    assert_eq!(
+        entry.properties,
+        json!({"AnalysisId": "sales", "AwsAccountId": "123456789012"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let analysis = parsed.resource::<Analysis>("Analysis").unwrap();
+    assert_eq!(serde_json::to_value(analysis).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_dashboard() {
+    let dashboard = DashboardBuilder::default()
+        .AwsAccountId("123456789012")
+        .DashboardId("sales")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Dashboard", dashboard).unwrap();
+    let entry = template.get("Dashboard").unwrap();
+    assert_eq!(entry.type_, "AWS::QuickSight::Dashboard");
+    assert_eq!(
+        entry.properties,
+        json!({"AwsAccountId": "123456789012", "DashboardId": "sales"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let dashboard = parsed.resource::<Dashboard>("Dashboard").unwrap();
+    assert_eq!(serde_json::to_value(dashboard).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_data_set() {
+    let data_set = DataSetBuilder::default()
+        .AwsAccountId("123456789012")
+        .DataSetId("orders")
+        .Name("Orders")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("DataSet", data_set).unwrap();
+    let entry = template.get("DataSet").unwrap();
+    assert_eq!(entry.type_, "AWS::QuickSight::DataSet");
+    assert_eq!(
+        entry.properties,
+        json!({"AwsAccountId": "123456789012", "DataSetId": "orders", "Name": "Orders"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let data_set = parsed.resource::<DataSet>("DataSet").unwrap();
+    assert_eq!(serde_json::to_value(data_set).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_data_source() {
+    let data_source = DataSourceBuilder::default()
+        .AwsAccountId("123456789012")
+        .DataSourceId("warehouse")
+        .Name("Warehouse")
+        .Type("ATHENA")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("DataSource", data_source).unwrap();
+    let entry = template.get("DataSource").unwrap();
+    assert_eq!(entry.type_, "AWS::QuickSight::DataSource");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "AwsAccountId": "123456789012",
+            "DataSourceId": "warehouse",
+            "Name": "Warehouse",
+            "Type": "ATHENA"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let data_source = parsed.resource::<DataSource>("DataSource").unwrap();
+    assert_eq!(serde_json::to_value(data_source).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_template() {
+    let quicksight_template = TemplateBuilder::default()
+        .AwsAccountId("123456789012")
+        .SourceEntity(
+            TemplateSourceEntityBuilder::default()
+                .SourceAnalysis(
+                    TemplateSourceAnalysisBuilder::default()
+                        .Arn("arn:aws:quicksight:us-east-1:123456789012:analysis/sales")
+                        .DataSetReferences(vec![DataSetReferenceBuilder::default()
+                            .DataSetArn("arn:aws:quicksight:us-east-1:123456789012:dataset/orders")
+                            .DataSetPlaceholder("orders")
+                            .build()
+                            .unwrap()])
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .TemplateId("sales")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("Template", quicksight_template)
+        .unwrap();
+    let entry = template.get("Template").unwrap();
+    assert_eq!(entry.type_, "AWS::QuickSight::Template");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "AwsAccountId": "123456789012",
+            "SourceEntity": {
+                "SourceAnalysis": {
+                    "Arn": "arn:aws:quicksight:us-east-1:123456789012:analysis/sales",
+                    "DataSetReferences": [{
+                        "DataSetArn": "arn:aws:quicksight:us-east-1:123456789012:dataset/orders",
+                        "DataSetPlaceholder": "orders"
+                    }]
+                }
+            },
+            "TemplateId": "sales"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let quicksight_template = parsed.resource::<QuickSightTemplate>("Template").unwrap();
+    assert_eq!(
+        serde_json::to_value(quicksight_template).unwrap(),
+        entry.properties
+    );
+}
@@ -0,0 +1,185 @@
+use caroline::types::AWS::RDS::DBCluster::{DBClusterBuilder, ScalingConfiguration};
+use caroline::types::AWS::RDS::DBClusterParameterGroup::{
+    DBClusterParameterGroup, DBClusterParameterGroupBuilder,
+};
+use caroline::types::AWS::RDS::DBParameterGroup::DBParameterGroupBuilder;
+use caroline::types::AWS::RDS::DBProxy::{AuthFormatBuilder, DBProxyBuilder};
+use caroline::types::AWS::RDS::DBProxyTargetGroup::{
+    ConnectionPoolConfigurationInfoFormatBuilder, DBProxyTargetGroupBuilder,
+};
+use caroline::types::AWS::RDS::DBSubnetGroup::{DBSubnetGroup, DBSubnetGroupBuilder};
+use caroline::types::AWS::RDS::EventSubscription::EventSubscriptionBuilder;
+use caroline::types::AWS::RDS::GlobalCluster::GlobalClusterBuilder;
+use caroline::types::AWS::RDS::OptionGroup::OptionGroupBuilder;
+use caroline::{Expr, Intrinsic, Issue, Template, Validator};
+use serde_json::json;
+use std::collections::HashMap;
+
+#[test]
+fn test_database_groups() {
+    let mut parameters = HashMap::new();
+    parameters.insert("max_connections".to_string(), Expr::from("200"));
+    let parameter_group = DBParameterGroupBuilder::default()
+        .Description("Postgres parameters")
+        .Family("postgres12")
+        .Parameters(parameters.clone())
+        .build()
+        .unwrap();
+    let cluster_parameter_group = DBClusterParameterGroupBuilder::default()
+        .Description("Aurora parameters")
+        .Family("aurora-postgresql12")
+        .Parameters(parameters)
+        .build()
+        .unwrap();
+    let subnet_group = DBSubnetGroupBuilder::default()
+        .DBSubnetGroupDescription("Private subnets")
+        .SubnetIds(vec![
+            Intrinsic::Ref("PrivateSubnet0".into()).into(),
+            Intrinsic::Ref("PrivateSubnet1".into()).into(),
+        ])
+        .build()
+        .unwrap();
+    let option_group = OptionGroupBuilder::default()
+        .EngineName("postgres")
+        .MajorEngineVersion("12")
+        .OptionGroupDescription("No options yet")
+        .build()
+        .unwrap();
+    let subscription = EventSubscriptionBuilder::default()
+        .SnsTopicArn(Intrinsic::Ref("Alerts".into()))
+        .SourceType("db-instance")
+        .EventCategories(vec!["failover".into(), "failure".into()])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("Parameters", parameter_group)
+        .unwrap();
+    template
+        .add_resource("ClusterParameters", cluster_parameter_group)
+        .unwrap();
+    template.add_resource("SubnetGroup", subnet_group).unwrap();
+    template.add_resource("Options", option_group).unwrap();
+    template.add_resource("Events", subscription).unwrap();
+
+    assert_eq!(
+        template.get("ClusterParameters").unwrap().properties["Parameters"],
+        json!({"max_connections": "200"})
+    );
+    assert!(template
+        .get("Options")
+        .unwrap()
+        .properties
+        .get("OptionConfigurations")
+        .is_none());
+    let cluster_parameters = template
+        .resource::<DBClusterParameterGroup>("ClusterParameters")
+        .unwrap();
+    assert_eq!(
+        cluster_parameters.Parameters["max_connections"].as_literal(),
+        Some(&"200".to_string())
+    );
+}
+
+#[test]
+fn test_db_proxy() {
+    let proxy = DBProxyBuilder::default()
+        .DBProxyName("app")
+        .EngineFamily("POSTGRESQL")
+        .RoleArn(Intrinsic::get_att("ProxyRole", "Arn").unwrap())
+        .Auth(vec![AuthFormatBuilder::default()
+            .AuthScheme("SECRETS")
+            .IAMAuth("REQUIRED")
+            .SecretArn(Intrinsic::Ref("DatabaseSecret".into()))
+            .build()
+            .unwrap()])
+        .VpcSubnetIds(vec![
+            Intrinsic::Ref("PrivateSubnet0".into()).into(),
+            Intrinsic::Ref("PrivateSubnet1".into()).into(),
+        ])
+        .RequireTLS(true)
+        .build()
+        .unwrap();
+    let target_group = DBProxyTargetGroupBuilder::default()
+        .DBProxyName(Intrinsic::Ref("Proxy".into()))
+        .TargetGroupName("default")
+        .DBClusterIdentifiers(vec![Intrinsic::Ref("Cluster".into()).into()])
+        .ConnectionPoolConfigurationInfo(
+            ConnectionPoolConfigurationInfoFormatBuilder::default()
+                .MaxConnectionsPercent(90)
+                .SessionPinningFilters(vec!["EXCLUDE_VARIABLE_SETS".into()])
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Proxy", proxy).unwrap();
+    template.add_resource("ProxyTargets", target_group).unwrap();
+    let properties = &template.get("ProxyTargets").unwrap().properties;
+    assert_eq!(
+        properties["ConnectionPoolConfigurationInfo"]["MaxConnectionsPercent"],
+        json!(90)
+    );
+    assert_eq!(properties["DBProxyName"], json!({"Ref": "Proxy"}));
+}
+
+#[test]
+fn test_global_cluster_members() {
+    let global = GlobalClusterBuilder::default()
+        .GlobalClusterIdentifier("orders")
+        .Engine("aurora-postgresql")
+        .EngineVersion("12.4")
+        .build()
+        .unwrap();
+    let member = |version: &str| {
+        DBClusterBuilder::default()
+            .Engine("aurora-postgresql")
+            .EngineVersion(version)
+            .GlobalClusterIdentifier(Intrinsic::Ref("Global".into()))
+            .build()
+            .unwrap()
+    };
+
+    let mut template = Template::new();
+    template.add_resource("Global", global).unwrap();
+    template.add_resource("Primary", member("12.4")).unwrap();
+    template.add_resource("Secondary", member("11.9")).unwrap();
+    assert_eq!(
+        template.validate(),
+        vec![Issue::EngineMismatch {
+            logical_id: "Secondary".to_string(),
+            global_cluster: "Global".to_string(),
+            property: "EngineVersion".to_string(),
+        }]
+    );
+
+    // Upgrading the global cluster re-checks its members
+    let mut validator = Validator::new(&template);
+    template.get_mut("Global").unwrap().properties["EngineVersion"] = json!("11.9");
+    let rechecked = validator.update(&template, "Global");
+    assert_eq!(rechecked, vec!["Global", "Primary", "Secondary"]);
+    assert_eq!(validator.issues().next().unwrap().logical_id(), "Primary");
+}
+
+#[test]
+fn test_new_takes_required_properties() {
+    let scaling = ScalingConfiguration::new();
+    assert!(scaling.MinCapacity.is_none());
+    assert_eq!(serde_json::to_value(&scaling).unwrap(), json!({}));
+
+    let group = DBSubnetGroup::new(
+        "Private subnets".into(),
+        vec!["subnet-1".into(), "subnet-2".into()],
+    );
+    assert!(group.DBSubnetGroupName.is_none());
+    assert_eq!(
+        serde_json::to_value(&group).unwrap(),
+        json!({
+            "DBSubnetGroupDescription": "Private subnets",
+            "SubnetIds": ["subnet-1", "subnet-2"]
+        })
+    );
+}
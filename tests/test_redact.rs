@@ -0,0 +1,35 @@
+use caroline::types::AWS::EC2::VPNConnection::{
+    VPNConnectionBuilder, VpnTunnelOptionsSpecificationBuilder,
+};
+use caroline::types::AWS::RDS::DBInstance::DBInstanceBuilder;
+use caroline::DebugUnredacted;
+
+#[test]
+fn test_redacted_debug() {
+    let database = DBInstanceBuilder::default()
+        .DBInstanceClass("db.t3.micro")
+        .MasterUsername("admin")
+        .MasterUserPassword("hunter2")
+        .build()
+        .unwrap();
+    let redacted = format!("{:?}", database);
+    assert!(redacted.contains("MasterUserPassword: Some(<redacted>)"));
+    assert!(redacted.contains("admin"));
+    assert!(!redacted.contains("hunter2"));
+    assert!(format!("{:?}", database.debug_unredacted()).contains("hunter2"));
+    // Redaction is back on once the unredacted output is done
+    assert!(!format!("{:#?}", database).contains("hunter2"));
+
+    let tunnel = VpnTunnelOptionsSpecificationBuilder::default()
+        .PreSharedKey("s3cr3t-key")
+        .build()
+        .unwrap();
+    let connection = VPNConnectionBuilder::default()
+        .Type("ipsec.1")
+        .CustomerGatewayId("cgw-0123")
+        .VpnTunnelOptionsSpecifications(vec![tunnel])
+        .build()
+        .unwrap();
+    assert!(!format!("{:?}", connection).contains("s3cr3t-key"));
+    assert!(format!("{:?}", connection.debug_unredacted()).contains("s3cr3t-key"));
+}
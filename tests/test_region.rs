@@ -0,0 +1,72 @@
+use caroline::types::AWS::EC2::LaunchTemplate::{LaunchTemplateData, PlacementBuilder};
+use caroline::{AvailabilityZone, AwsPartition, Error, Expr, Intrinsic, Region};
+use serde_json::json;
+
+#[test]
+fn test_regions_and_partitions() {
+    let region: Region = "cn-northwest-1".parse().unwrap();
+    assert_eq!(region, Region::CnNorthwest1);
+    assert_eq!(region.partition(), AwsPartition::AwsCn);
+    assert_eq!(region.partition().id(), "aws-cn");
+    assert_eq!(region.partition().dns_suffix(), "amazonaws.com.cn");
+    assert_eq!(region.to_string(), "cn-northwest-1");
+    assert_eq!(
+        serde_json::to_value(Region::UsGovWest1).unwrap(),
+        json!("us-gov-west-1")
+    );
+    assert!(AwsPartition::Aws
+        .regions()
+        .any(|region| region == Region::EuWest1));
+    assert!(AwsPartition::ALL
+        .iter()
+        .all(|partition| partition.regions().count() > 0));
+
+    match "eu-west-9".parse::<Region>() {
+        Err(Error::InvalidRegion(region)) => assert_eq!(region, "eu-west-9"),
+        other => panic!("Unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn test_availability_zone() {
+    let zone: AvailabilityZone = "us-gov-east-1b".parse().unwrap();
+    assert_eq!(zone.region(), Region::UsGovEast1);
+    assert_eq!(zone.zone(), 'b');
+    assert_eq!(zone.to_string(), "us-gov-east-1b");
+
+    for invalid in &[
+        "us-east-1",
+        "us-east-1A",
+        "us-east-11",
+        "us-west-2-lax-1a",
+        "",
+    ] {
+        assert!(invalid.parse::<AvailabilityZone>().is_err(), "{}", invalid);
+    }
+    assert!(AvailabilityZone::new(Region::EuWest1, '1').is_err());
+}
+
+#[test]
+fn test_placement_availability_zone() {
+    let placement = PlacementBuilder::default()
+        .AvailabilityZone(AvailabilityZone::new(Region::EuCentral1, 'c').unwrap())
+        .build()
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(&placement).unwrap(),
+        json!({"AvailabilityZone": "eu-central-1c"})
+    );
+
+    let data: LaunchTemplateData = serde_json::from_value(json!({
+        "Placement": {"AvailabilityZone": {"Fn::Select": [0, {"Fn::GetAZs": ""}]}}
+    }))
+    .unwrap();
+    assert_eq!(
+        data.Placement.unwrap().AvailabilityZone,
+        Some(Expr::from(Intrinsic::select(0, Intrinsic::get_azs(""))))
+    );
+    assert!(serde_json::from_value::<LaunchTemplateData>(json!({
+        "Placement": {"AvailabilityZone": "eu-central-1"}
+    }))
+    .is_err());
+}
@@ -0,0 +1,52 @@
+use caroline::types::AWS::EC2::Subnet::Subnet;
+use caroline::types::AWS::EC2::VPC::VPC;
+use caroline::{registry, Resource, UpdateType};
+
+#[test]
+fn test_property_metadata() {
+    let cidr = VPC::PROPERTIES
+        .iter()
+        .find(|property| property.name == "CidrBlock")
+        .unwrap();
+    assert!(cidr.required);
+    assert_eq!(cidr.update_type, UpdateType::Immutable);
+
+    let properties = registry::properties(Subnet::TYPE).unwrap();
+    assert_eq!(properties, Subnet::PROPERTIES);
+    let dns = registry::property(VPC::TYPE, "EnableDnsSupport").unwrap();
+    assert!(!dns.required);
+    assert_eq!(dns.update_type, UpdateType::Mutable);
+
+    assert!(registry::resource_types().any(|type_| type_ == "AWS::S3::Bucket"));
+    assert!(registry::properties("AWS::Nope::Nope").is_none());
+}
+
+#[test]
+fn test_spec_version() {
+    assert_eq!(caroline::SPEC_VERSION, "6.3.0");
+}
+
+#[test]
+fn test_service_coverage() {
+    let sqs = registry::service("AWS::SQS").unwrap();
+    assert_eq!(
+        sqs.resources,
+        registry::resource_types()
+            .filter(|type_| type_.starts_with("AWS::SQS::"))
+            .count()
+    );
+    // Added by the crate's extensions, and typed by its overrides
+    assert!(sqs.added.contains(&"AWS::SQS::Queue.RedriveAllowPolicy"));
+    assert!(sqs.untyped.is_empty());
+    assert!(sqs.missing.is_empty());
+
+    let iam = registry::service("AWS::IAM").unwrap();
+    assert!(iam
+        .untyped
+        .contains(&"AWS::IAM::Role.AssumeRolePolicyDocument"));
+    assert!(!iam.added.contains(&"AWS::IAM::Role"));
+    assert!(registry::services().all(|coverage| coverage.missing.is_empty()));
+
+    assert!(registry::services().any(|coverage| coverage.service == "AWS::EC2"));
+    assert!(registry::service("AWS::Nope").is_none());
+}
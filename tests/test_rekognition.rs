@@ -0,0 +1,38 @@
+use caroline::types::AWS::Rekognition::Collection::{Collection, CollectionBuilder};
+use caroline::types::AWS::Rekognition::Project::{Project, ProjectBuilder};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_collection() {
+    let collection = CollectionBuilder::default()
+        .CollectionId("faces")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Collection", collection).unwrap();
+    let entry = template.get("Collection").unwrap();
+    assert_eq!(entry.type_, "AWS::Rekognition::Collection");
+    assert_eq!(entry.properties, json!({"CollectionId": "faces"}));
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let collection = parsed.resource::<Collection>("Collection").unwrap();
+    assert_eq!(serde_json::to_value(collection).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_project() {
+    let project = ProjectBuilder::default()
+        .ProjectName("labels")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Project", project).unwrap();
+    let entry = template.get("Project").unwrap();
+    assert_eq!(entry.type_, "AWS::Rekognition::Project");
+    assert_eq!(entry.properties, json!({"ProjectName": "labels"}));
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let project = parsed.resource::<Project>("Project").unwrap();
+    assert_eq!(serde_json::to_value(project).unwrap(), entry.properties);
+}
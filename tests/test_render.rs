@@ -0,0 +1,77 @@
+use caroline::{render, Error, Template};
+use serde_json::{json, Value};
+
+#[test]
+fn test_render() {
+    let spec = json!({
+        "Description": "Orders",
+        "Resources": {
+            "Orders": {
+                "AWS::SQS::Queue": {
+                    "QueueName": "orders",
+                    "RedrivePolicy": {
+                        "deadLetterTargetArn": {"Fn::GetAtt": ["DeadLetters", "Arn"]},
+                        "maxReceiveCount": 5
+                    }
+                },
+                "DependsOn": "DeadLetters"
+            },
+            "DeadLetters": {"AWS::SQS::Queue": null, "DeletionPolicy": "Retain"}
+        }
+    });
+    let rendered = render(&spec.to_string()).unwrap();
+    let template: Value = serde_json::from_str(&rendered).unwrap();
+    assert_eq!(template["AWSTemplateFormatVersion"], json!("2010-09-09"));
+    assert_eq!(template["Description"], json!("Orders"));
+    assert_eq!(
+        template["Resources"]["Orders"],
+        json!({
+            "Type": "AWS::SQS::Queue",
+            "Properties": {
+                "QueueName": "orders",
+                "RedrivePolicy": {
+                    "deadLetterTargetArn": {"Fn::GetAtt": ["DeadLetters", "Arn"]},
+                    "maxReceiveCount": 5
+                }
+            },
+            "DependsOn": ["DeadLetters"]
+        })
+    );
+    assert_eq!(
+        template["Resources"]["DeadLetters"],
+        json!({"Type": "AWS::SQS::Queue", "Properties": {}, "DeletionPolicy": "Retain"})
+    );
+    assert!(Template::from_json(&rendered).is_ok());
+}
+
+#[test]
+fn test_render_problems() {
+    let spec = json!({
+        "Resources": {
+            "Queue": {"AWS::SQS::Queue": {"QueueName": "orders", "DelaySeconds": "soon"}},
+            "Topic": {"AWS::SNS::Topic": {"TopicNam": "orders"}},
+            "Bucket": {"AWS::S3::Bukcet": {}},
+            "Subscription": {
+                "AWS::SNS::Subscription": {
+                    "Protocol": "sqs",
+                    "TopicArn": {"Ref": "Topics"}
+                }
+            }
+        }
+    });
+    match render(&spec.to_string()) {
+        Err(Error::InvalidSpec(problems)) => {
+            assert_eq!(problems.len(), 4);
+            assert_eq!(problems[0], "Bucket: unknown resource type AWS::S3::Bukcet");
+            assert!(problems[1].starts_with("Queue: "));
+            assert_eq!(problems[2], "Topic.TopicNam: unknown property");
+            assert_eq!(
+                problems[3],
+                "Subscription references unknown logical ID Topics"
+            );
+        }
+        other => panic!("Expected an invalid spec, got {:?}", other),
+    }
+
+    assert!(matches!(render("[]"), Err(Error::Json(_))));
+}
@@ -0,0 +1,100 @@
+use caroline::types::AWS::ResilienceHub::App::{
+    App, AppBuilder, PhysicalResourceIdBuilder, ResourceMappingBuilder,
+};
+use caroline::types::AWS::ResilienceHub::ResiliencyPolicy::{
+    FailurePolicy, FailurePolicyBuilder, PolicyMapBuilder, ResiliencyPolicy,
+    ResiliencyPolicyBuilder,
+};
+use caroline::{Intrinsic, Template};
+use serde_json::json;
+
+fn failure_policy(rto: i32, rpo: i32) -> FailurePolicy {
+    FailurePolicyBuilder::default()
+        .RtoInSecs(rto)
+        .RpoInSecs(rpo)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_app() {
+    let app = AppBuilder::default()
+        .AppTemplateBody("{\"resources\": [], \"version\": 2.0}")
+        .Name("web")
+        .ResourceMappings(vec![ResourceMappingBuilder::default()
+            .MappingType("CfnStack")
+            .PhysicalResourceId(
+                PhysicalResourceIdBuilder::default()
+                    .Identifier(Intrinsic::Ref("AWS::StackId".into()))
+                    .Type("Arn")
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("App", app).unwrap();
+    let entry = template.get("App").unwrap();
+    assert_eq!(entry.type_, "AWS::ResilienceHub::App");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "AppTemplateBody": "{\"resources\": [], \"version\": 2.0}",
+            "Name": "web",
+            "ResourceMappings": [{
+                "MappingType": "CfnStack",
+                "PhysicalResourceId": {"Identifier": {"Ref": "AWS::StackId"}, "Type": "Arn"}
+            }]
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let app = parsed.resource::<App>("App").unwrap();
+    assert_eq!(serde_json::to_value(app).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_resiliency_policy() {
+    let resiliency_policy = ResiliencyPolicyBuilder::default()
+        .Policy(
+            PolicyMapBuilder::default()
+                .AZ(failure_policy(3600, 300))
+                .Hardware(failure_policy(3600, 300))
+                .Software(failure_policy(1800, 300))
+                .build()
+                .unwrap(),
+        )
+        .PolicyName("critical")
+        .Tier("MissionCritical")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("ResiliencyPolicy", resiliency_policy)
+        .unwrap();
+    let entry = template.get("ResiliencyPolicy").unwrap();
+    assert_eq!(entry.type_, "AWS::ResilienceHub::ResiliencyPolicy");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "Policy": {
+                "AZ": {"RpoInSecs": 300, "RtoInSecs": 3600},
+                "Hardware": {"RpoInSecs": 300, "RtoInSecs": 3600},
+                "Software": {"RpoInSecs": 300, "RtoInSecs": 1800}
+            },
+            "PolicyName": "critical",
+            "Tier": "MissionCritical"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let resiliency_policy = parsed
+        .resource::<ResiliencyPolicy>("ResiliencyPolicy")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(resiliency_policy).unwrap(),
+        entry.properties
+    );
+}
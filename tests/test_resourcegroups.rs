@@ -0,0 +1,17 @@
+use caroline::types::AWS::ResourceGroups::Group::{Group, GroupBuilder};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_group() {
+    let group = GroupBuilder::default().Name("group").build().unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Group", group).unwrap();
+    let entry = template.get("Group").unwrap();
+    assert_eq!(entry.type_, "AWS::ResourceGroups::Group");
+    assert_eq!(entry.properties, json!({"Name": "group"}));
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let group = parsed.resource::<Group>("Group").unwrap();
+    assert_eq!(serde_json::to_value(group).unwrap(), entry.properties);
+}
@@ -0,0 +1,111 @@
+use caroline::types::Tag::TagBuilder;
+use caroline::types::AWS::EC2::Instance::InstanceBuilder;
+use caroline::types::AWS::EC2::LaunchTemplate::{LaunchTemplateBuilder, LaunchTemplateData};
+use caroline::{Intrinsic, Parameter, PropertyPath, Template};
+use serde_json::json;
+
+fn legacy() -> Template {
+    let instance = InstanceBuilder::default()
+        .ImageId("ami-0123456789")
+        .InstanceType("t3.micro")
+        .Tags(vec![TagBuilder::default()
+            .Key("Name")
+            .Value("web")
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+    let launch_template = LaunchTemplateBuilder::default()
+        .LaunchTemplateData(LaunchTemplateData {
+            ImageId: Some("ami-0123456789".into()),
+            ..Default::default()
+        })
+        .build()
+        .unwrap();
+    let mut template = Template::new();
+    template.add_resource("Web", instance).unwrap();
+    template
+        .add_resource("WorkerTemplate", launch_template)
+        .unwrap();
+    template
+}
+
+#[test]
+fn test_rewrite_by_pattern() {
+    let mut template = legacy();
+    template
+        .add_parameter("ImageId", Parameter::new("AWS::EC2::Image::Id"))
+        .unwrap();
+    let before = template.clone();
+
+    let rewritten = template.rewrite(|path, value| {
+        if path.matches("**.ImageId") {
+            *value = Intrinsic::Ref("ImageId".into()).into();
+        }
+    });
+    assert_eq!(
+        rewritten.into_iter().collect::<Vec<String>>(),
+        vec!["Web", "WorkerTemplate"]
+    );
+    assert_eq!(
+        template.get("Web").unwrap().properties["ImageId"],
+        json!({"Ref": "ImageId"})
+    );
+    assert_eq!(
+        template.get("WorkerTemplate").unwrap().properties["LaunchTemplateData"]["ImageId"],
+        json!({"Ref": "ImageId"})
+    );
+    assert!(template.validate().is_empty());
+    assert_eq!(
+        before.get("Web").unwrap().properties["ImageId"],
+        json!("ami-0123456789")
+    );
+}
+
+#[test]
+fn test_rewrite_by_predicate() {
+    let mut template = legacy();
+    let before = template.clone();
+
+    let rewritten = template.rewrite(|_, value| {
+        if value == &json!("web") {
+            *value = json!("frontend");
+        }
+    });
+    assert_eq!(rewritten.len(), 1);
+    assert_eq!(
+        template.get("Web").unwrap().properties["Tags"][0]["Value"],
+        json!("frontend")
+    );
+    assert!(template.shares_resource(&before, "WorkerTemplate"));
+    assert!(!template.shares_resource(&before, "Web"));
+    assert!(template.rewrite(|_, _| ()).is_empty());
+}
+
+#[test]
+fn test_path_patterns() {
+    let template = legacy();
+    let view = template.view();
+    let paths = view
+        .values()
+        .map(|(path, _)| path)
+        .collect::<Vec<PropertyPath<'_>>>();
+    let matching = |pattern: &str| {
+        paths
+            .iter()
+            .filter(|path| path.matches(pattern))
+            .map(|path| path.to_string())
+            .collect::<Vec<String>>()
+    };
+    assert_eq!(matching("Web.Tags[0].Key"), vec!["Web.Tags[0].Key"]);
+    assert_eq!(
+        matching("*.Tags[*].*"),
+        vec!["Web.Tags[0].Key", "Web.Tags[0].Value"]
+    );
+    assert_eq!(matching("Web.Tags[1].Key"), Vec::<String>::new());
+    assert_eq!(
+        matching("**.ImageId"),
+        vec!["Web.ImageId", "WorkerTemplate.LaunchTemplateData.ImageId"]
+    );
+    assert_eq!(matching("Web.**").len(), 4);
+}
@@ -0,0 +1,103 @@
+use caroline::types::AWS::RoboMaker::Fleet::{Fleet, FleetBuilder};
+use caroline::types::AWS::RoboMaker::RobotApplication::{
+    RobotApplication, RobotApplicationBuilder, RobotSoftwareSuiteBuilder,
+};
+use caroline::types::AWS::RoboMaker::SimulationApplication::{
+    RobotSoftwareSuiteBuilder as SimulationRobotSoftwareSuiteBuilder, SimulationApplication,
+    SimulationApplicationBuilder, SimulationSoftwareSuiteBuilder,
+};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_fleet() {
+    let fleet = FleetBuilder::default().Name("warehouse").build().unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Fleet", fleet).unwrap();
+    let entry = template.get("Fleet").unwrap();
+    assert_eq!(entry.type_, "AWS::RoboMaker::Fleet");
+    assert_eq!(entry.properties, json!({"Name": "warehouse"}));
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let fleet = parsed.resource::<Fleet>("Fleet").unwrap();
+    assert_eq!(serde_json::to_value(fleet).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_robot_application() {
+    let robot_application = RobotApplicationBuilder::default()
+        .Name("navigation")
+        .RobotSoftwareSuite(
+            RobotSoftwareSuiteBuilder::default()
+                .Name("ROS2")
+                .Version("Foxy")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("RobotApplication", robot_application)
+        .unwrap();
+    let entry = template.get("RobotApplication").unwrap();
+    assert_eq!(entry.type_, "AWS::RoboMaker::RobotApplication");
+    assert_eq!(
+        entry.properties,
+        json!({"Name": "navigation", "RobotSoftwareSuite": {"Name": "ROS2", "Version": "Foxy"}})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let robot_application = parsed
+        .resource::<RobotApplication>("RobotApplication")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(robot_application).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_simulation_application() {
+    let simulation_application = SimulationApplicationBuilder::default()
+        .Name("warehouse-world")
+        .RobotSoftwareSuite(
+            SimulationRobotSoftwareSuiteBuilder::default()
+                .Name("ROS2")
+                .Version("Foxy")
+                .build()
+                .unwrap(),
+        )
+        .SimulationSoftwareSuite(
+            SimulationSoftwareSuiteBuilder::default()
+                .Name("Gazebo")
+                .Version("11")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("SimulationApplication", simulation_application)
+        .unwrap();
+    let entry = template.get("SimulationApplication").unwrap();
+    assert_eq!(entry.type_, "AWS::RoboMaker::SimulationApplication");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "Name": "warehouse-world",
+            "RobotSoftwareSuite": {"Name": "ROS2", "Version": "Foxy"},
+            "SimulationSoftwareSuite": {"Name": "Gazebo", "Version": "11"}
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let simulation_application = parsed
+        .resource::<SimulationApplication>("SimulationApplication")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(simulation_application).unwrap(),
+        entry.properties
+    );
+}
@@ -0,0 +1,58 @@
+use caroline::{registry, render, PropertyInfo, PropertyKind};
+use serde_json::{json, Value};
+
+/// Nesting up to which optional properties are filled in, deeper property types only get
+/// their required properties so recursive types end
+const OPTIONAL_DEPTH: usize = 2;
+
+/// Properties of a resource or property type, all given by a `Ref` at the leaves, which any
+/// primitive takes
+fn sample(properties: &[PropertyInfo], depth: usize) -> Value {
+    Value::Object(
+        properties
+            .iter()
+            .filter(|property| property.required || depth < OPTIONAL_DEPTH)
+            .map(|property| (property.name.to_string(), sample_kind(property.kind, depth)))
+            .collect(),
+    )
+}
+
+fn sample_kind(kind: PropertyKind, depth: usize) -> Value {
+    let item = match kind.properties() {
+        Some(properties) => sample(properties, depth + 1),
+        None => json!({"Ref": "Sample"}),
+    };
+    match kind {
+        PropertyKind::Primitive | PropertyKind::Struct(_) => item,
+        PropertyKind::List(_) => json!([item]),
+        PropertyKind::Map(_) => json!({ "Key": item }),
+    }
+}
+
+#[test]
+fn test_round_trip() {
+    let mut failures = vec![];
+    for type_name in registry::resource_types() {
+        let properties = sample(registry::properties(type_name).unwrap(), 0);
+        let spec = json!({
+            "Parameters": {"Sample": {"Type": "String"}},
+            "Resources": {"Resource": {type_name: properties}}
+        });
+        let rendered = match render(&spec.to_string()) {
+            Ok(rendered) => serde_json::from_str::<Value>(&rendered).unwrap(),
+            Err(err) => {
+                failures.push(format!("{}: {}", type_name, err));
+                continue;
+            }
+        };
+        let resource = &rendered["Resources"]["Resource"];
+        assert_eq!(resource["Type"], json!(type_name));
+        if resource["Properties"] != properties {
+            failures.push(format!(
+                "{}: {} != {}",
+                type_name, resource["Properties"], properties
+            ));
+        }
+    }
+    assert!(failures.is_empty(), "{:#?}", failures);
+}
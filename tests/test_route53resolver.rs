@@ -0,0 +1,248 @@
+use caroline::types::AWS::Route53Resolver::FirewallDomainList::{
+    FirewallDomainList, FirewallDomainListBuilder,
+};
+use caroline::types::AWS::Route53Resolver::FirewallRuleGroup::{
+    FirewallRuleGroup, FirewallRuleGroupBuilder,
+};
+use caroline::types::AWS::Route53Resolver::FirewallRuleGroupAssociation::{
+    FirewallRuleGroupAssociation, FirewallRuleGroupAssociationBuilder,
+};
+use caroline::types::AWS::Route53Resolver::ResolverEndpoint::{
+    IpAddressRequestBuilder, ResolverEndpoint, ResolverEndpointBuilder,
+};
+use caroline::types::AWS::Route53Resolver::ResolverQueryLoggingConfig::{
+    ResolverQueryLoggingConfig, ResolverQueryLoggingConfigBuilder,
+};
+use caroline::types::AWS::Route53Resolver::ResolverQueryLoggingConfigAssociation::{
+    ResolverQueryLoggingConfigAssociation, ResolverQueryLoggingConfigAssociationBuilder,
+};
+use caroline::types::AWS::Route53Resolver::ResolverRule::{ResolverRule, ResolverRuleBuilder};
+use caroline::types::AWS::Route53Resolver::ResolverRuleAssociation::{
+    ResolverRuleAssociation, ResolverRuleAssociationBuilder,
+};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_firewall_domain_list() {
+    let firewall_domain_list = FirewallDomainListBuilder::default()
+        .Name("blocked-domains")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("FirewallDomainList", firewall_domain_list)
+        .unwrap();
+    let entry = template.get("FirewallDomainList").unwrap();
+    assert_eq!(entry.type_, "AWS::Route53Resolver::FirewallDomainList");
+    assert_eq!(entry.properties, json!({"Name": "blocked-domains"}));
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let firewall_domain_list = parsed
+        .resource::<FirewallDomainList>("FirewallDomainList")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(firewall_domain_list).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_firewall_rule_group() {
+    let firewall_rule_group = FirewallRuleGroupBuilder::default()
+        .Name("egress")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("FirewallRuleGroup", firewall_rule_group)
+        .unwrap();
+    let entry = template.get("FirewallRuleGroup").unwrap();
+    assert_eq!(entry.type_, "AWS::Route53Resolver::FirewallRuleGroup");
+    assert_eq!(entry.properties, json!({"Name": "egress"}));
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let firewall_rule_group = parsed
+        .resource::<FirewallRuleGroup>("FirewallRuleGroup")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(firewall_rule_group).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_firewall_rule_group_association() {
+    let association = FirewallRuleGroupAssociationBuilder::default()
+        .FirewallRuleGroupId("rslvr-frg-0123456789abcdef0")
+        .Priority(1)
+        .VpcId("vpc-0123456789abcdef0")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("FirewallRuleGroupAssociation", association)
+        .unwrap();
+    let entry = template.get("FirewallRuleGroupAssociation").unwrap();
+    assert_eq!(
+        entry.type_,
+        "AWS::Route53Resolver::FirewallRuleGroupAssociation"
+    );
+    assert_eq!(
+        entry.properties,
+        json!({
+            "FirewallRuleGroupId": "rslvr-frg-0123456789abcdef0",
+            "Priority": 1,
+            "VpcId": "vpc-0123456789abcdef0"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let association = parsed
+        .resource::<FirewallRuleGroupAssociation>("FirewallRuleGroupAssociation")
+        .unwrap();
+    assert_eq!(serde_json::to_value(association).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_resolver_endpoint() {
+    let resolver_endpoint = ResolverEndpointBuilder::default()
+        .Direction("OUTBOUND")
+        .IpAddresses(vec![IpAddressRequestBuilder::default()
+            .SubnetId("subnet-0123456789abcdef0")
+            .build()
+            .unwrap()])
+        .SecurityGroupIds(vec!["sg-0123456789abcdef0".into()])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("ResolverEndpoint", resolver_endpoint)
+        .unwrap();
+    let entry = template.get("ResolverEndpoint").unwrap();
+    assert_eq!(entry.type_, "AWS::Route53Resolver::ResolverEndpoint");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "Direction": "OUTBOUND",
+            "IpAddresses": [{"SubnetId": "subnet-0123456789abcdef0"}],
+            "SecurityGroupIds": ["sg-0123456789abcdef0"]
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let resolver_endpoint = parsed
+        .resource::<ResolverEndpoint>("ResolverEndpoint")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(resolver_endpoint).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_resolver_query_logging_config() {
+    let logging_config = ResolverQueryLoggingConfigBuilder::default()
+        .Name("query-logs")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("QueryLogs", logging_config).unwrap();
+    let entry = template.get("QueryLogs").unwrap();
+    assert_eq!(
+        entry.type_,
+        "AWS::Route53Resolver::ResolverQueryLoggingConfig"
+    );
+    assert_eq!(entry.properties, json!({"Name": "query-logs"}));
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let logging_config = parsed
+        .resource::<ResolverQueryLoggingConfig>("QueryLogs")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(logging_config).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_resolver_query_logging_config_association() {
+    let association = ResolverQueryLoggingConfigAssociationBuilder::default()
+        .ResolverQueryLogConfigId("rslvr-qlc-0123456789abcdef0")
+        .ResourceId("vpc-0123456789abcdef0")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("QueryLogsAssociation", association)
+        .unwrap();
+    let entry = template.get("QueryLogsAssociation").unwrap();
+    assert_eq!(
+        entry.type_,
+        "AWS::Route53Resolver::ResolverQueryLoggingConfigAssociation"
+    );
+    assert_eq!(
+        entry.properties,
+        json!({
+            "ResolverQueryLogConfigId": "rslvr-qlc-0123456789abcdef0",
+            "ResourceId": "vpc-0123456789abcdef0"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let association = parsed
+        .resource::<ResolverQueryLoggingConfigAssociation>("QueryLogsAssociation")
+        .unwrap();
+    assert_eq!(serde_json::to_value(association).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_resolver_rule() {
+    let resolver_rule = ResolverRuleBuilder::default()
+        .DomainName("corp.example.com")
+        .RuleType("FORWARD")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("ResolverRule", resolver_rule)
+        .unwrap();
+    let entry = template.get("ResolverRule").unwrap();
+    assert_eq!(entry.type_, "AWS::Route53Resolver::ResolverRule");
+    assert_eq!(
+        entry.properties,
+        json!({"DomainName": "corp.example.com", "RuleType": "FORWARD"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let resolver_rule = parsed.resource::<ResolverRule>("ResolverRule").unwrap();
+    assert_eq!(
+        serde_json::to_value(resolver_rule).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_resolver_rule_association() {
+    let association = ResolverRuleAssociationBuilder::default()
+        .ResolverRuleId("rslvr-rr-0123456789abcdef0")
+        .VPCId("vpc-0123456789abcdef0")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("ResolverRuleAssociation", association)
+        .unwrap();
+    let entry = template.get("ResolverRuleAssociation").unwrap();
+    assert_eq!(entry.type_, "AWS::Route53Resolver::ResolverRuleAssociation");
+    assert_eq!(
+        entry.properties,
+        json!({"ResolverRuleId": "rslvr-rr-0123456789abcdef0", "VPCId": "vpc-0123456789abcdef0"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let association = parsed
+        .resource::<ResolverRuleAssociation>("ResolverRuleAssociation")
+        .unwrap();
+    assert_eq!(serde_json::to_value(association).unwrap(), entry.properties);
+}
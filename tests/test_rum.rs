@@ -0,0 +1,24 @@
+use caroline::types::AWS::RUM::AppMonitor::{AppMonitor, AppMonitorBuilder};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_app_monitor() {
+    let app_monitor = AppMonitorBuilder::default()
+        .Domain("example.com")
+        .Name("app-monitor")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("AppMonitor", app_monitor).unwrap();
+    let entry = template.get("AppMonitor").unwrap();
+    assert_eq!(entry.type_, "AWS::RUM::AppMonitor");
+    assert_eq!(
+        entry.properties,
+        json!({"Domain": "example.com", "Name": "app-monitor"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let app_monitor = parsed.resource::<AppMonitor>("AppMonitor").unwrap();
+    assert_eq!(serde_json::to_value(app_monitor).unwrap(), entry.properties);
+}
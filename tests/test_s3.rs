@@ -0,0 +1,290 @@
+use caroline::types::AWS::S3::AccessPoint::{AccessPoint, AccessPointBuilder};
+use caroline::types::AWS::S3::Bucket::{
+    BucketBuilder, CorsConfigurationBuilder, CorsRuleBuilder, DestinationBuilder,
+    IntelligentTieringConfigurationBuilder, InventoryConfigurationBuilder,
+    MetricsConfigurationBuilder, OwnershipControlsBuilder, OwnershipControlsRuleBuilder,
+    PublicAccessBlockConfigurationBuilder, TieringBuilder,
+};
+use caroline::types::AWS::S3::MultiRegionAccessPoint::{
+    MultiRegionAccessPoint, MultiRegionAccessPointBuilder, RegionBuilder,
+};
+use caroline::types::AWS::S3::MultiRegionAccessPointPolicy::{
+    MultiRegionAccessPointPolicy, MultiRegionAccessPointPolicyBuilder,
+};
+use caroline::types::AWS::S3::StorageLens::{
+    AccountLevelBuilder, BucketLevelBuilder, StorageLens, StorageLensBuilder,
+    StorageLensConfigurationBuilder,
+};
+use caroline::{render, Intrinsic, Template};
+use serde_json::json;
+
+#[test]
+fn test_bucket_configurations() {
+    let inventory = InventoryConfigurationBuilder::default()
+        .Id("weekly")
+        .Enabled(true)
+        .IncludedObjectVersions("Current")
+        .ScheduleFrequency("Weekly")
+        .Destination(
+            DestinationBuilder::default()
+                .BucketArn(Intrinsic::get_att("Reports", "Arn").unwrap())
+                .Format("CSV")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let tiering = IntelligentTieringConfigurationBuilder::default()
+        .Id("archive")
+        .Status("Enabled")
+        .Tierings(vec![TieringBuilder::default()
+            .AccessTier("ARCHIVE_ACCESS")
+            .Days(90)
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+    let bucket = BucketBuilder::default()
+        .InventoryConfigurations(vec![inventory])
+        .MetricsConfigurations(vec![MetricsConfigurationBuilder::default()
+            .Id("all")
+            .build()
+            .unwrap()])
+        .IntelligentTieringConfigurations(vec![tiering])
+        .OwnershipControls(
+            OwnershipControlsBuilder::default()
+                .Rules(vec![OwnershipControlsRuleBuilder::default()
+                    .ObjectOwnership("BucketOwnerEnforced")
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap(),
+        )
+        .PublicAccessBlockConfiguration(
+            PublicAccessBlockConfigurationBuilder::default()
+                .BlockPublicAcls(true)
+                .BlockPublicPolicy(true)
+                .IgnorePublicAcls(true)
+                .RestrictPublicBuckets(true)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Data", bucket).unwrap();
+    let properties = &template.get("Data").unwrap().properties;
+    assert_eq!(
+        properties["IntelligentTieringConfigurations"][0]["Tierings"],
+        json!([{"AccessTier": "ARCHIVE_ACCESS", "Days": 90}])
+    );
+    assert_eq!(
+        properties["OwnershipControls"],
+        json!({"Rules": [{"ObjectOwnership": "BucketOwnerEnforced"}]})
+    );
+    assert!(template.unknown_properties().is_empty());
+}
+
+#[test]
+fn test_cors_rules() {
+    let bucket = BucketBuilder::default()
+        .CorsConfiguration(
+            CorsConfigurationBuilder::default()
+                .CorsRules(vec![CorsRuleBuilder::default()
+                    .AllowedMethods(vec!["GET".into(), "HEAD".into()])
+                    .AllowedOrigins(vec!["https://example.com".into()])
+                    .MaxAge(3600)
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(&bucket).unwrap()["CorsConfiguration"],
+        json!({
+            "CorsRules": [{
+                "AllowedMethods": ["GET", "HEAD"],
+                "AllowedOrigins": ["https://example.com"],
+                "MaxAge": 3600
+            }]
+        })
+    );
+}
+
+#[test]
+fn test_access_point() {
+    let access_point = AccessPointBuilder::default()
+        .Bucket("assets")
+        .Name("readers")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("AccessPoint", access_point).unwrap();
+    let entry = template.get("AccessPoint").unwrap();
+    assert_eq!(entry.type_, "AWS::S3::AccessPoint");
+    assert_eq!(
+        entry.properties,
+        json!({"Bucket": "assets", "Name": "readers"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let access_point = parsed.resource::<AccessPoint>("AccessPoint").unwrap();
+    assert_eq!(
+        serde_json::to_value(access_point).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_multi_region_access_point() {
+    let multi_region_access_point = MultiRegionAccessPointBuilder::default()
+        .Name("assets")
+        .Regions(vec![RegionBuilder::default()
+            .Bucket("assets")
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("MultiRegionAccessPoint", multi_region_access_point)
+        .unwrap();
+    let entry = template.get("MultiRegionAccessPoint").unwrap();
+    assert_eq!(entry.type_, "AWS::S3::MultiRegionAccessPoint");
+    assert_eq!(
+        entry.properties,
+        json!({"Name": "assets", "Regions": [{"Bucket": "assets"}]})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let multi_region_access_point = parsed
+        .resource::<MultiRegionAccessPoint>("MultiRegionAccessPoint")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(multi_region_access_point).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_multi_region_access_point_policy() {
+    let multi_region_access_point_policy = MultiRegionAccessPointPolicyBuilder::default()
+        .MrapName("assets")
+        .Policy(json!({
+            "Version": "2012-10-17",
+            "Statement": [{
+                "Effect": "Allow",
+                "Principal": {"AWS": "arn:aws:iam::123456789012:root"},
+                "Action": "s3:GetObject",
+                "Resource": "arn:aws:s3::123456789012:accesspoint/assets/object/*"
+            }]
+        }))
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource(
+            "MultiRegionAccessPointPolicy",
+            multi_region_access_point_policy,
+        )
+        .unwrap();
+    let entry = template.get("MultiRegionAccessPointPolicy").unwrap();
+    assert_eq!(entry.type_, "AWS::S3::MultiRegionAccessPointPolicy");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "MrapName": "assets",
+            "Policy": {
+                "Version": "2012-10-17",
+                "Statement": [{
+                    "Effect": "Allow",
+                    "Principal": {"AWS": "arn:aws:iam::123456789012:root"},
+                    "Action": "s3:GetObject",
+                    "Resource": "arn:aws:s3::123456789012:accesspoint/assets/object/*"
+                }]
+            }
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let multi_region_access_point_policy = parsed
+        .resource::<MultiRegionAccessPointPolicy>("MultiRegionAccessPointPolicy")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(multi_region_access_point_policy).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_storage_lens() {
+    let storage_lens = StorageLensBuilder::default()
+        .StorageLensConfiguration(
+            StorageLensConfigurationBuilder::default()
+                .AccountLevel(
+                    AccountLevelBuilder::default()
+                        .BucketLevel(BucketLevelBuilder::default().build().unwrap())
+                        .build()
+                        .unwrap(),
+                )
+                .Id("organization")
+                .IsEnabled(true)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("StorageLens", storage_lens).unwrap();
+    let entry = template.get("StorageLens").unwrap();
+    assert_eq!(entry.type_, "AWS::S3::StorageLens");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "StorageLensConfiguration": {
+                "AccountLevel": {"BucketLevel": {}},
+                "Id": "organization",
+                "IsEnabled": true
+            }
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let storage_lens = parsed.resource::<StorageLens>("StorageLens").unwrap();
+    assert_eq!(
+        serde_json::to_value(storage_lens).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_lifecycle_dates() {
+    // Timestamps are ISO 8601 strings, as the JSON Schemas of the types describe them
+    let spec = json!({
+        "Resources": {
+            "Archive": {
+                "AWS::S3::Bucket": {
+                    "LifecycleConfiguration": {
+                        "Rules": [{
+                            "Status": "Enabled",
+                            "ExpirationDate": "2030-01-01T00:00:00Z",
+                            "Transitions": [{
+                                "StorageClass": "GLACIER",
+                                "TransitionDate": "2029-01-01T00:00:00Z"
+                            }]
+                        }]
+                    }
+                }
+            }
+        }
+    });
+    let rendered = render(&spec.to_string()).unwrap();
+    let template = Template::from_json(&rendered).unwrap();
+    assert_eq!(
+        template.get("Archive").unwrap().properties,
+        spec["Resources"]["Archive"]["AWS::S3::Bucket"]
+    );
+}
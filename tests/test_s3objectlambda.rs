@@ -0,0 +1,107 @@
+use caroline::types::AWS::S3ObjectLambda::AccessPoint::{
+    AccessPoint, AccessPointBuilder, ObjectLambdaConfigurationBuilder,
+    TransformationConfigurationBuilder,
+};
+use caroline::types::AWS::S3ObjectLambda::AccessPointPolicy::{
+    AccessPointPolicy, AccessPointPolicyBuilder,
+};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_access_point() {
+    let access_point = AccessPointBuilder::default()
+        .Name("transform")
+        .ObjectLambdaConfiguration(
+            ObjectLambdaConfigurationBuilder::default()
+                .SupportingAccessPoint("arn:aws:s3:us-east-1:123456789012:accesspoint/assets")
+                .TransformationConfigurations(vec![TransformationConfigurationBuilder::default()
+                    .Actions(vec!["GetObject".into()])
+                    .ContentTransformation(json!({
+                        "AwsLambda": {
+                            "FunctionArn": "arn:aws:lambda:us-east-1:123456789012:function:redact"
+                        }
+                    }))
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("AccessPoint", access_point).unwrap();
+    let entry = template.get("AccessPoint").unwrap();
+    assert_eq!(entry.type_, "AWS::S3ObjectLambda::AccessPoint");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "Name": "transform",
+            "ObjectLambdaConfiguration": {
+                "SupportingAccessPoint": "arn:aws:s3:us-east-1:123456789012:accesspoint/assets",
+                "TransformationConfigurations": [{
+                    "Actions": ["GetObject"],
+                    "ContentTransformation": {
+                        "AwsLambda": {
+                            "FunctionArn": "arn:aws:lambda:us-east-1:123456789012:function:redact"
+                        }
+                    }
+                }]
+            }
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let access_point = parsed.resource::<AccessPoint>("AccessPoint").unwrap();
+    assert_eq!(
+        serde_json::to_value(access_point).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_access_point_policy() {
+    let access_point_policy = AccessPointPolicyBuilder::default()
+        .ObjectLambdaAccessPoint("transform")
+        .PolicyDocument(json!({
+            "Version": "2012-10-17",
+            "Statement": [{
+                "Effect": "Allow",
+                "Principal": {"AWS": "arn:aws:iam::123456789012:role/reader"},
+                "Action": "s3-object-lambda:GetObject",
+                "Resource": {"Fn::GetAtt": ["AccessPoint", "Arn"]}
+            }]
+        }))
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("AccessPointPolicy", access_point_policy)
+        .unwrap();
+    let entry = template.get("AccessPointPolicy").unwrap();
+    assert_eq!(entry.type_, "AWS::S3ObjectLambda::AccessPointPolicy");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "ObjectLambdaAccessPoint": "transform",
+            "PolicyDocument": {
+                "Version": "2012-10-17",
+                "Statement": [{
+                    "Effect": "Allow",
+                    "Principal": {"AWS": "arn:aws:iam::123456789012:role/reader"},
+                    "Action": "s3-object-lambda:GetObject",
+                    "Resource": {"Fn::GetAtt": ["AccessPoint", "Arn"]}
+                }]
+            }
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let access_point_policy = parsed
+        .resource::<AccessPointPolicy>("AccessPointPolicy")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(access_point_policy).unwrap(),
+        entry.properties
+    );
+}
@@ -0,0 +1,51 @@
+use caroline::types::AWS::S3Outposts::Bucket::{Bucket, BucketBuilder};
+use caroline::types::AWS::S3Outposts::Endpoint::{Endpoint, EndpointBuilder};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_bucket() {
+    let bucket = BucketBuilder::default()
+        .BucketName("logs")
+        .OutpostId("op-01ac5d28a6a232904")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Bucket", bucket).unwrap();
+    let entry = template.get("Bucket").unwrap();
+    assert_eq!(entry.type_, "AWS::S3Outposts::Bucket");
+    assert_eq!(
+        entry.properties,
+        json!({"BucketName": "logs", "OutpostId": "op-01ac5d28a6a232904"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let bucket = parsed.resource::<Bucket>("Bucket").unwrap();
+    assert_eq!(serde_json::to_value(bucket).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_endpoint() {
+    let endpoint = EndpointBuilder::default()
+        .OutpostId("op-01ac5d28a6a232904")
+        .SecurityGroupId("sg-0123456789abcdef0")
+        .SubnetId("subnet-0123456789abcdef0")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Endpoint", endpoint).unwrap();
+    let entry = template.get("Endpoint").unwrap();
+    assert_eq!(entry.type_, "AWS::S3Outposts::Endpoint");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "OutpostId": "op-01ac5d28a6a232904",
+            "SecurityGroupId": "sg-0123456789abcdef0",
+            "SubnetId": "subnet-0123456789abcdef0"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let endpoint = parsed.resource::<Endpoint>("Endpoint").unwrap();
+    assert_eq!(serde_json::to_value(endpoint).unwrap(), entry.properties);
+}
@@ -0,0 +1,145 @@
+use caroline::types::AWS::AutoScaling::ScheduledAction::ScheduledActionBuilder;
+use caroline::types::AWS::Backup::BackupPlan::{
+    BackupPlanBuilder, BackupPlanResourceTypeBuilder, BackupRuleResourceTypeBuilder,
+};
+use caroline::types::AWS::Events::Rule::RuleBuilder;
+use caroline::{Error, RateUnit, Recurrence, ScheduleExpression, Template};
+use serde_json::json;
+
+#[test]
+fn test_schedule_expressions() {
+    let schedule = "cron(0 2 ? * SUN *)".parse::<ScheduleExpression>().unwrap();
+    assert_eq!(schedule, ScheduleExpression::cron("0 2 ? * SUN *").unwrap());
+    assert_eq!(schedule.to_string(), "cron(0 2 ? * SUN *)");
+
+    let schedule = "rate(1 day)".parse::<ScheduleExpression>().unwrap();
+    assert_eq!(schedule, ScheduleExpression::Rate(1, RateUnit::Days));
+    assert_eq!(
+        ScheduleExpression::rate(1, RateUnit::Hours)
+            .unwrap()
+            .to_string(),
+        "rate(1 hour)"
+    );
+
+    for invalid in &[
+        "rate(1 days)",
+        "rate(2 hour)",
+        "rate(0 minutes)",
+        "cron(0 2 * *)",
+        "daily",
+    ] {
+        assert!(
+            matches!(
+                invalid.parse::<ScheduleExpression>(),
+                Err(Error::InvalidSchedule(_))
+            ),
+            "{}",
+            invalid
+        );
+    }
+}
+
+#[test]
+fn test_cron_field_ranges() {
+    for valid in &[
+        "0/15 * ? * MON-FRI *",
+        "0 8,12,18 1,15 * ? *",
+        "30 23 L JAN-MAR ? 2030",
+        "0 9 15W * ? *",
+        "0 9 ? * 6L *",
+        "0 9 ? * MON#2 *",
+        "5 4 ? 12 1-7 1970-2199",
+    ] {
+        assert!(ScheduleExpression::cron(valid).is_ok(), "{}", valid);
+    }
+    for invalid in &[
+        "60 * ? * * *",
+        "0 24 ? * * *",
+        "0 0 32 * ? *",
+        "0 0 ? 13 * *",
+        "0 0 ? * 8 *",
+        "0 0 ? * MON#6 *",
+        "0 0 1 * MON *",
+        "0 0 ? * ? *",
+        "0 0 ? * * 1969",
+        "0/0 * ? * * *",
+        "0 0 ? FOO * *",
+    ] {
+        assert!(ScheduleExpression::cron(invalid).is_err(), "{}", invalid);
+    }
+
+    assert!(ScheduleExpression::at("2030-01-01T09:00:00").is_ok());
+    assert!(ScheduleExpression::at("2030-13-01T09:00:00").is_err());
+    assert!(ScheduleExpression::at("2030-01-01 09:00").is_err());
+
+    assert_eq!(
+        "0 9 * * MON-FRI".parse::<Recurrence>().unwrap().to_string(),
+        "0 9 * * MON-FRI"
+    );
+    assert!(Recurrence::cron("0 9 ? * MON-FRI *").is_err());
+    assert!(Recurrence::cron("0 9 * * 7").is_err());
+}
+
+#[test]
+fn test_schedules_across_services() {
+    let nightly = ScheduleExpression::cron("0 2 * * ? *").unwrap();
+    let rule = RuleBuilder::default()
+        .ScheduleExpression(ScheduleExpression::rate(5, RateUnit::Minutes).unwrap())
+        .build()
+        .unwrap();
+    let plan = BackupPlanBuilder::default()
+        .BackupPlan(
+            BackupPlanResourceTypeBuilder::default()
+                .BackupPlanName("nightly")
+                .BackupPlanRule(vec![BackupRuleResourceTypeBuilder::default()
+                    .RuleName("nightly")
+                    .TargetBackupVault("Default")
+                    .ScheduleExpression(nightly)
+                    .build()
+                    .unwrap()])
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let scale_up = ScheduledActionBuilder::default()
+        .AutoScalingGroupName("web")
+        .DesiredCapacity(4)
+        .Recurrence(Recurrence::cron("0 8 * * MON-FRI").unwrap())
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Poll", rule).unwrap();
+    template.add_resource("Backups", plan).unwrap();
+    template.add_resource("ScaleUp", scale_up).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&template.to_json().unwrap()).unwrap();
+    let resources = &json["Resources"];
+    assert_eq!(
+        resources["Poll"]["Properties"]["ScheduleExpression"],
+        json!("rate(5 minutes)")
+    );
+    assert_eq!(
+        resources["Backups"]["Properties"]["BackupPlan"]["BackupPlanRule"][0]["ScheduleExpression"],
+        json!("cron(0 2 * * ? *)")
+    );
+    assert_eq!(
+        resources["ScaleUp"]["Properties"]["Recurrence"],
+        json!("0 8 * * MON-FRI")
+    );
+
+    // Schedules CloudFormation would reject fail to parse into typed resources
+    let invalid = json!({
+        "Resources": {
+            "Poll": {
+                "Type": "AWS::Events::Rule",
+                "Properties": {"ScheduleExpression": "rate(5 minute)"}
+            }
+        }
+    });
+    let template = Template::from_json(&invalid.to_string()).unwrap();
+    assert!(matches!(
+        template.resource::<caroline::types::AWS::Events::Rule::Rule>("Poll"),
+        Err(Error::Json(_))
+    ));
+}
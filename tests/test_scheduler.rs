@@ -0,0 +1,68 @@
+use caroline::types::AWS::Scheduler::Schedule::{
+    FlexibleTimeWindowBuilder, Schedule, ScheduleBuilder, TargetBuilder,
+};
+use caroline::types::AWS::Scheduler::ScheduleGroup::{ScheduleGroup, ScheduleGroupBuilder};
+use caroline::{RateUnit, ScheduleExpression, Template};
+use serde_json::json;
+
+#[test]
+fn test_schedule() {
+    let schedule = ScheduleBuilder::default()
+        .FlexibleTimeWindow(
+            FlexibleTimeWindowBuilder::default()
+                .Mode("OFF")
+                .build()
+                .unwrap(),
+        )
+        .ScheduleExpression(ScheduleExpression::Rate(1, RateUnit::Hours))
+        .Target(
+            TargetBuilder::default()
+                .Arn("arn:aws:sqs:us-east-1:123456789012:reminders")
+                .RoleArn("arn:aws:iam::123456789012:role/schedule")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Schedule", schedule).unwrap();
+    let entry = template.get("Schedule").unwrap();
+    assert_eq!(entry.type_, "AWS::Scheduler::Schedule");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "FlexibleTimeWindow": {"Mode": "OFF"},
+            "ScheduleExpression": "rate(1 hour)",
+            "Target": {
+                "Arn": "arn:aws:sqs:us-east-1:123456789012:reminders",
+                "RoleArn": "arn:aws:iam::123456789012:role/schedule"
+            }
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let schedule = parsed.resource::<Schedule>("Schedule").unwrap();
+    assert_eq!(serde_json::to_value(schedule).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_schedule_group() {
+    let schedule_group = ScheduleGroupBuilder::default()
+        .Name("schedule-group")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("ScheduleGroup", schedule_group)
+        .unwrap();
+    let entry = template.get("ScheduleGroup").unwrap();
+    assert_eq!(entry.type_, "AWS::Scheduler::ScheduleGroup");
+    assert_eq!(entry.properties, json!({"Name": "schedule-group"}));
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let schedule_group = parsed.resource::<ScheduleGroup>("ScheduleGroup").unwrap();
+    assert_eq!(
+        serde_json::to_value(schedule_group).unwrap(),
+        entry.properties
+    );
+}
@@ -0,0 +1,105 @@
+use caroline::{Error, RegistrySchema, Template};
+use serde_json::json;
+
+fn cluster_schema() -> RegistrySchema {
+    let schema = json!({
+      "typeName": "MongoDB::Atlas::Cluster",
+      "description": "A MongoDB Atlas cluster",
+      "sourceUrl": "https://github.com/mongodb/mongodbatlas-cloudformation-resources",
+      "definitions": {
+        "ProviderSettings": {
+          "type": "object",
+          "additionalProperties": false,
+          "properties": {
+            "InstanceSizeName": {"type": "string", "enum": ["M10", "M20", "M30"]},
+            "RegionName": {"type": "string"},
+            "DiskIOPS": {"type": "integer"}
+          },
+          "required": ["InstanceSizeName"]
+        },
+        "Label": {
+          "type": "object",
+          "properties": {"Key": {"type": "string"}, "Value": {"type": "string"}}
+        },
+        "ClusterType": {"type": "string", "enum": ["REPLICASET", "SHARDED"]}
+      },
+      "properties": {
+        "Id": {"type": "string"},
+        "ProjectId": {"type": "string"},
+        "Name": {"type": "string"},
+        "ClusterType": {"$ref": "#/definitions/ClusterType"},
+        "DiskSizeGB": {"type": "number"},
+        "BackupEnabled": {"type": "boolean"},
+        "ProviderSettings": {"$ref": "#/definitions/ProviderSettings"},
+        "Labels": {"type": "array", "items": {"$ref": "#/definitions/Label"}},
+        "Tags": {"type": "array", "items": {"type": "string"}}
+      },
+      "additionalProperties": false,
+      "required": ["ProjectId", "Name"],
+      "readOnlyProperties": ["/properties/Id"],
+      "createOnlyProperties": ["/properties/Name", "/properties/ProjectId"],
+      "primaryIdentifier": ["/properties/Id"]
+    });
+    RegistrySchema::from_json(&schema.to_string()).unwrap()
+}
+
+#[test]
+fn test_registry_resource() {
+    let schema = cluster_schema();
+    assert_eq!(schema.type_name(), "MongoDB::Atlas::Cluster");
+
+    let cluster = schema
+        .resource(json!({
+            "ProjectId": {"Ref": "ProjectId"},
+            "Name": "analytics",
+            "ClusterType": "REPLICASET",
+            "DiskSizeGB": "40",
+            "ProviderSettings": {"InstanceSizeName": "M10", "RegionName": "EU_WEST_1"},
+            "Labels": [{"Key": "team", "Value": "data"}]
+        }))
+        .unwrap();
+    let mut template = Template::new();
+    template.add_unknown_resource("Cluster", cluster).unwrap();
+    assert!(schema.validate_template(&template).is_empty());
+
+    let violations = match schema.resource(json!({
+        "Id": "5f0c",
+        "Name": "analytics",
+        "ClusterType": "STANDALONE",
+        "BackupEnabled": "yes",
+        "ProviderSettings": {"RegionName": "EU_WEST_1", "DiskIops": 3000},
+        "Labels": [{"Key": 1}]
+    })) {
+        Err(Error::SchemaViolations(violations)) => violations,
+        other => panic!("Expected schema violations, got {:?}", other),
+    };
+    assert_eq!(
+        violations,
+        vec![
+            "Id: read-only",
+            "ProjectId: missing",
+            "BackupEnabled: expected boolean",
+            "ClusterType: not one of [\"REPLICASET\",\"SHARDED\"]",
+            "Labels[0].Key: expected string",
+            "ProviderSettings.InstanceSizeName: missing",
+            "ProviderSettings.DiskIops: unknown property",
+        ]
+    );
+}
+
+#[test]
+fn test_registry_template_paths() {
+    let template = json!({
+        "Resources": {
+            "Cluster": {
+                "Type": "MongoDB::Atlas::Cluster",
+                "Properties": {"ProjectId": "5f0c", "Name": "analytics", "Size": "M10"}
+            }
+        }
+    });
+    let template = Template::from_json(&template.to_string()).unwrap();
+    assert_eq!(
+        cluster_schema().validate_template(&template),
+        vec!["Cluster.Size: unknown property"]
+    );
+}
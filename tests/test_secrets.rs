@@ -0,0 +1,125 @@
+use caroline::parameter_types::SecureString;
+use caroline::types::AWS::EC2::VPNConnection::{
+    VPNConnectionBuilder, VpnTunnelOptionsSpecificationBuilder,
+};
+use caroline::types::AWS::RDS::DBCluster::DBClusterBuilder;
+use caroline::types::AWS::RDS::DBInstance::DBInstanceBuilder;
+use caroline::{registry, Intrinsic, Parameter, Template};
+use serde_json::{json, Value};
+
+#[test]
+fn test_plaintext_secrets() {
+    let mut template = Template::new();
+    let mut password = Parameter::new("String");
+    password.no_echo = Some(true);
+    template.add_parameter("Password", password).unwrap();
+    template
+        .add_parameter("Key", Parameter::new("String"))
+        .unwrap();
+
+    let instance = DBInstanceBuilder::default()
+        .DBInstanceClass("db.t3.micro")
+        .MasterUserPassword("hunter2")
+        .build()
+        .unwrap();
+    let cluster = DBClusterBuilder::default()
+        .Engine("aurora-postgresql")
+        .MasterUserPassword(Intrinsic::Ref("Password".into()))
+        .build()
+        .unwrap();
+    let replica = DBInstanceBuilder::default()
+        .DBInstanceClass("db.t3.micro")
+        .MasterUserPassword(SecureString::new("/db/password"))
+        .build()
+        .unwrap();
+    let tunnel = |key: Intrinsic| {
+        VpnTunnelOptionsSpecificationBuilder::default()
+            .PreSharedKey(key)
+            .build()
+            .unwrap()
+    };
+    let connection = VPNConnectionBuilder::default()
+        .Type("ipsec.1")
+        .CustomerGatewayId("cgw-0123")
+        .VpnTunnelOptionsSpecifications(vec![
+            tunnel(Intrinsic::Ref("Password".into())),
+            tunnel(Intrinsic::Ref("Key".into())),
+        ])
+        .build()
+        .unwrap();
+    template.add_resource("Database", instance).unwrap();
+    template.add_resource("Cluster", cluster).unwrap();
+    template.add_resource("Replica", replica).unwrap();
+    template.add_resource("Connection", connection).unwrap();
+
+    assert_eq!(
+        template.plaintext_secrets(),
+        vec![
+            "Connection.VpnTunnelOptionsSpecifications[1].PreSharedKey",
+            "Database.MasterUserPassword",
+        ]
+    );
+    assert!(
+        registry::property("AWS::RDS::DBInstance", "MasterUserPassword")
+            .unwrap()
+            .sensitive
+    );
+    assert!(
+        !registry::property("AWS::RDS::DBInstance", "MasterUsername")
+            .unwrap()
+            .sensitive
+    );
+}
+
+#[test]
+fn test_plaintext_secrets_in_functions() {
+    let passwords = json!({
+        "Plain": "{{resolve:ssm:/db/password}}",
+        "Managed": "{{resolve:secretsmanager:db:SecretString:password}}",
+        "Substituted": {"Fn::Sub": "hunter2"},
+        "Joined": {"Fn::Join": ["", ["hunter", "2"]]},
+        "NoEcho": {"Fn::Sub": "${Password}"},
+        "Named": {"Fn::Sub": [
+            "{{resolve:secretsmanager:${Name}:SecretString:password}}",
+            {"Name": {"Ref": "Key"}}
+        ]},
+        "Fallback": {"Fn::If": ["IsProd", {"Ref": "Password"}, "changeme"]},
+        "Echoed": {"Fn::Sub": "${Key}"}
+    });
+    let resources = passwords
+        .as_object()
+        .unwrap()
+        .iter()
+        .map(|(id, password)| {
+            let instance = json!({
+                "Type": "AWS::RDS::DBInstance",
+                "Properties": {"DBInstanceClass": "db.t3.micro", "MasterUserPassword": password}
+            });
+            (id.clone(), instance)
+        })
+        .collect::<serde_json::Map<String, Value>>();
+    let template = Template::from_json(
+        &json!({
+            "Parameters": {
+                "Password": {"Type": "String", "NoEcho": true},
+                "Key": {"Type": "String"}
+            },
+            "Resources": resources
+        })
+        .to_string(),
+    )
+    .unwrap();
+
+    // Plain SSM parameters aren't secret, literals and echoed parameters are found within
+    // functions building a string
+    assert_eq!(
+        template.plaintext_secrets(),
+        vec![
+            "Echoed.MasterUserPassword",
+            "Fallback.MasterUserPassword",
+            "Joined.MasterUserPassword",
+            "Plain.MasterUserPassword",
+            "Substituted.MasterUserPassword",
+        ]
+    );
+}
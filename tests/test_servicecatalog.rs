@@ -0,0 +1,167 @@
+use caroline::types::AWS::ServiceCatalog::CloudFormationProduct::{
+    CloudFormationProduct, CloudFormationProductBuilder, ProvisioningArtifactPropertiesBuilder,
+};
+use caroline::types::AWS::ServiceCatalog::CloudFormationProvisionedProduct::{
+    CloudFormationProvisionedProduct, CloudFormationProvisionedProductBuilder,
+};
+use caroline::types::AWS::ServiceCatalog::LaunchRoleConstraint::{
+    LaunchRoleConstraint, LaunchRoleConstraintBuilder,
+};
+use caroline::types::AWS::ServiceCatalog::Portfolio::{Portfolio, PortfolioBuilder};
+use caroline::types::AWS::ServiceCatalog::PortfolioProductAssociation::{
+    PortfolioProductAssociation, PortfolioProductAssociationBuilder,
+};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_cloud_formation_product() {
+    let cloud_formation_product = CloudFormationProductBuilder::default()
+        .Name("web")
+        .Owner("Platform team")
+        .ProvisioningArtifactParameters(vec![ProvisioningArtifactPropertiesBuilder::default()
+            .Info(json!({"LoadTemplateFromURL": "https://s3.amazonaws.com/templates/web.yaml"}))
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("CloudFormationProduct", cloud_formation_product)
+        .unwrap();
+    let entry = template.get("CloudFormationProduct").unwrap();
+    assert_eq!(entry.type_, "AWS::ServiceCatalog::CloudFormationProduct");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "Name": "web",
+            "Owner": "Platform team",
+            "ProvisioningArtifactParameters": [{
+                "Info": {"LoadTemplateFromURL": "https://s3.amazonaws.com/templates/web.yaml"}
+            }]
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let cloud_formation_product = parsed
+        .resource::<CloudFormationProduct>("CloudFormationProduct")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(cloud_formation_product).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_cloud_formation_provisioned_product() {
+    let cloud_formation_provisioned_product = CloudFormationProvisionedProductBuilder::default()
+        .ProductName("web")
+        .ProvisioningArtifactName("v1")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource(
+            "CloudFormationProvisionedProduct",
+            cloud_formation_provisioned_product,
+        )
+        .unwrap();
+    let entry = template.get("CloudFormationProvisionedProduct").unwrap();
+    assert_eq!(
+        entry.type_,
+        "AWS::ServiceCatalog::CloudFormationProvisionedProduct"
+    );
+    assert_eq!(
+        entry.properties,
+        json!({"ProductName": "web", "ProvisioningArtifactName": "v1"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let cloud_formation_provisioned_product = parsed
+        .resource::<CloudFormationProvisionedProduct>("CloudFormationProvisionedProduct")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(cloud_formation_provisioned_product).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_launch_role_constraint() {
+    let launch_role_constraint = LaunchRoleConstraintBuilder::default()
+        .PortfolioId("port-0123456789")
+        .ProductId("prod-0123456789")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("LaunchRoleConstraint", launch_role_constraint)
+        .unwrap();
+    let entry = template.get("LaunchRoleConstraint").unwrap();
+    assert_eq!(entry.type_, "AWS::ServiceCatalog::LaunchRoleConstraint");
+    assert_eq!(
+        entry.properties,
+        json!({"PortfolioId": "port-0123456789", "ProductId": "prod-0123456789"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let launch_role_constraint = parsed
+        .resource::<LaunchRoleConstraint>("LaunchRoleConstraint")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(launch_role_constraint).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_portfolio() {
+    let portfolio = PortfolioBuilder::default()
+        .DisplayName("Platform")
+        .ProviderName("Platform team")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Portfolio", portfolio).unwrap();
+    let entry = template.get("Portfolio").unwrap();
+    assert_eq!(entry.type_, "AWS::ServiceCatalog::Portfolio");
+    assert_eq!(
+        entry.properties,
+        json!({"DisplayName": "Platform", "ProviderName": "Platform team"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let portfolio = parsed.resource::<Portfolio>("Portfolio").unwrap();
+    assert_eq!(serde_json::to_value(portfolio).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_portfolio_product_association() {
+    let portfolio_product_association = PortfolioProductAssociationBuilder::default()
+        .PortfolioId("port-0123456789")
+        .ProductId("prod-0123456789")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("PortfolioProductAssociation", portfolio_product_association)
+        .unwrap();
+    let entry = template.get("PortfolioProductAssociation").unwrap();
+    assert_eq!(
+        entry.type_,
+        "AWS::ServiceCatalog::PortfolioProductAssociation"
+    );
+    assert_eq!(
+        entry.properties,
+        json!({"PortfolioId": "port-0123456789", "ProductId": "prod-0123456789"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let portfolio_product_association = parsed
+        .resource::<PortfolioProductAssociation>("PortfolioProductAssociation")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(portfolio_product_association).unwrap(),
+        entry.properties
+    );
+}
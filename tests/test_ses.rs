@@ -0,0 +1,149 @@
+use caroline::types::AWS::SES::ConfigurationSet::{ConfigurationSet, ConfigurationSetBuilder};
+use caroline::types::AWS::SES::EmailIdentity::{EmailIdentity, EmailIdentityBuilder};
+use caroline::types::AWS::SES::ReceiptRule::{ReceiptRule, ReceiptRuleBuilder, RuleBuilder};
+use caroline::types::AWS::SES::ReceiptRuleSet::{ReceiptRuleSet, ReceiptRuleSetBuilder};
+use caroline::types::AWS::SES::Template::{
+    Template as EmailTemplate, TemplateBuilder, TemplatePropertyBuilder,
+};
+use caroline::{registry, PropertyKind, Resource, Template};
+use serde_json::json;
+
+#[test]
+fn test_configuration_set() {
+    let configuration_set = ConfigurationSetBuilder::default()
+        .Name("configuration-set")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("ConfigurationSet", configuration_set)
+        .unwrap();
+    let entry = template.get("ConfigurationSet").unwrap();
+    assert_eq!(entry.type_, "AWS::SES::ConfigurationSet");
+    assert_eq!(entry.properties, json!({"Name": "configuration-set"}));
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let configuration_set = parsed
+        .resource::<ConfigurationSet>("ConfigurationSet")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(configuration_set).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_email_identity() {
+    let email_identity = EmailIdentityBuilder::default()
+        .EmailIdentity("example.com")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("EmailIdentity", email_identity)
+        .unwrap();
+    let entry = template.get("EmailIdentity").unwrap();
+    assert_eq!(entry.type_, "AWS::SES::EmailIdentity");
+    assert_eq!(entry.properties, json!({"EmailIdentity": "example.com"}));
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let email_identity = parsed.resource::<EmailIdentity>("EmailIdentity").unwrap();
+    assert_eq!(
+        serde_json::to_value(email_identity).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_receipt_rule() {
+    let receipt_rule = ReceiptRuleBuilder::default()
+        .Rule(RuleBuilder::default().Name("forward").build().unwrap())
+        .RuleSetName("inbound")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("ReceiptRule", receipt_rule).unwrap();
+    let entry = template.get("ReceiptRule").unwrap();
+    assert_eq!(entry.type_, "AWS::SES::ReceiptRule");
+    assert_eq!(
+        entry.properties,
+        json!({"Rule": {"Name": "forward"}, "RuleSetName": "inbound"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let receipt_rule = parsed.resource::<ReceiptRule>("ReceiptRule").unwrap();
+    assert_eq!(
+        serde_json::to_value(receipt_rule).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_receipt_rule_set() {
+    let receipt_rule_set = ReceiptRuleSetBuilder::default()
+        .RuleSetName("inbound")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("ReceiptRuleSet", receipt_rule_set)
+        .unwrap();
+    let entry = template.get("ReceiptRuleSet").unwrap();
+    assert_eq!(entry.type_, "AWS::SES::ReceiptRuleSet");
+    assert_eq!(entry.properties, json!({"RuleSetName": "inbound"}));
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let receipt_rule_set = parsed.resource::<ReceiptRuleSet>("ReceiptRuleSet").unwrap();
+    assert_eq!(
+        serde_json::to_value(receipt_rule_set).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_template() {
+    // The property type `AWS::SES::Template.Template` is generated as `TemplateProperty`, so
+    // it doesn't collide with the resource `Template` it's a property of
+    let email_template = TemplateBuilder::default()
+        .Template(
+            TemplatePropertyBuilder::default()
+                .SubjectPart("Your order {{id}} has shipped")
+                .TemplateName("order-shipped")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Template", email_template).unwrap();
+    let entry = template.get("Template").unwrap();
+    assert_eq!(entry.type_, "AWS::SES::Template");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "Template": {
+                "SubjectPart": "Your order {{id}} has shipped",
+                "TemplateName": "order-shipped"
+            }
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let email_template = parsed.resource::<EmailTemplate>("Template").unwrap();
+    assert_eq!(
+        serde_json::to_value(email_template).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_template_property() {
+    let property = registry::property(EmailTemplate::TYPE, "Template").unwrap();
+    assert!(matches!(property.kind, PropertyKind::Struct(_)));
+    assert!(property
+        .kind
+        .properties()
+        .unwrap()
+        .iter()
+        .any(|property| property.name == "SubjectPart"));
+}
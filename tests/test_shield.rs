@@ -0,0 +1,91 @@
+use caroline::types::AWS::Shield::ProactiveEngagement::{
+    EmergencyContactBuilder, ProactiveEngagement, ProactiveEngagementBuilder,
+};
+use caroline::types::AWS::Shield::Protection::{Protection, ProtectionBuilder};
+use caroline::types::AWS::Shield::ProtectionGroup::{ProtectionGroup, ProtectionGroupBuilder};
+use caroline::{Intrinsic, Template};
+use serde_json::json;
+
+#[test]
+fn test_proactive_engagement() {
+    let proactive_engagement = ProactiveEngagementBuilder::default()
+        .EmergencyContactList(vec![EmergencyContactBuilder::default()
+            .EmailAddress("oncall@example.com")
+            .build()
+            .unwrap()])
+        .ProactiveEngagementStatus("ENABLED")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("ProactiveEngagement", proactive_engagement)
+        .unwrap();
+    let entry = template.get("ProactiveEngagement").unwrap();
+    assert_eq!(entry.type_, "AWS::Shield::ProactiveEngagement");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "EmergencyContactList": [{"EmailAddress": "oncall@example.com"}],
+            "ProactiveEngagementStatus": "ENABLED"
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let proactive_engagement = parsed
+        .resource::<ProactiveEngagement>("ProactiveEngagement")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(proactive_engagement).unwrap(),
+        entry.properties
+    );
+}
+
+#[test]
+fn test_protection() {
+    let protection = ProtectionBuilder::default()
+        .Name("web")
+        .ResourceArn(Intrinsic::reference("LoadBalancer").unwrap())
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Protection", protection).unwrap();
+    let entry = template.get("Protection").unwrap();
+    assert_eq!(entry.type_, "AWS::Shield::Protection");
+    assert_eq!(
+        entry.properties,
+        json!({"Name": "web", "ResourceArn": {"Ref": "LoadBalancer"}})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let protection = parsed.resource::<Protection>("Protection").unwrap();
+    assert_eq!(serde_json::to_value(protection).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_protection_group() {
+    let protection_group = ProtectionGroupBuilder::default()
+        .Aggregation("SUM")
+        .Pattern("ALL")
+        .ProtectionGroupId("all-resources")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("ProtectionGroup", protection_group)
+        .unwrap();
+    let entry = template.get("ProtectionGroup").unwrap();
+    assert_eq!(entry.type_, "AWS::Shield::ProtectionGroup");
+    assert_eq!(
+        entry.properties,
+        json!({"Aggregation": "SUM", "Pattern": "ALL", "ProtectionGroupId": "all-resources"})
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let protection_group = parsed
+        .resource::<ProtectionGroup>("ProtectionGroup")
+        .unwrap();
+    assert_eq!(
+        serde_json::to_value(protection_group).unwrap(),
+        entry.properties
+    );
+}
@@ -0,0 +1,83 @@
+use caroline::enums::FilterPolicyScope;
+use caroline::types::AWS::SNS::Subscription::{Subscription, SubscriptionBuilder};
+use caroline::{
+    BackoffFunction, DeliveryPolicy, Expr, FilterPolicy, HealthyRetryPolicy, Intrinsic,
+    RedrivePolicy, Template, ThrottlePolicy,
+};
+use serde_json::json;
+
+#[test]
+fn test_filtered_subscription() {
+    let filter = FilterPolicy::new()
+        .equals("store", vec!["example_corp"])
+        .nested("order", FilterPolicy::new().numeric("total", ">=", 100));
+    let subscription = SubscriptionBuilder::default()
+        .TopicArn(Intrinsic::reference("Orders").unwrap())
+        .Protocol("sqs")
+        .Endpoint(Intrinsic::get_att("LargeOrders", "Arn").unwrap())
+        .RawMessageDelivery(true)
+        .FilterPolicyScope(FilterPolicyScope::MessageBody)
+        .FilterPolicy(filter.clone())
+        .RedrivePolicy(RedrivePolicy::dead_letter_target(
+            Intrinsic::get_att("UndeliveredOrders", "Arn").unwrap(),
+        ))
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("LargeOrderFeed", subscription)
+        .unwrap();
+    let properties = &template.get("LargeOrderFeed").unwrap().properties;
+    assert_eq!(properties["FilterPolicyScope"], json!("MessageBody"));
+    assert_eq!(
+        properties["FilterPolicy"],
+        json!({
+            "store": ["example_corp"],
+            "order": {"total": [{"numeric": [">=", 100]}]}
+        })
+    );
+    assert_eq!(
+        properties["RedrivePolicy"],
+        json!({"deadLetterTargetArn": {"Fn::GetAtt": ["UndeliveredOrders", "Arn"]}})
+    );
+    let parsed = template.resource::<Subscription>("LargeOrderFeed").unwrap();
+    assert_eq!(parsed.FilterPolicy, Some(Expr::Literal(filter)));
+}
+
+#[test]
+fn test_delivery_policy() {
+    let delivery = DeliveryPolicy {
+        healthy_retry_policy: Some(HealthyRetryPolicy {
+            min_delay_target: Some(1),
+            max_delay_target: Some(60),
+            num_retries: Some(10),
+            backoff_function: Some(BackoffFunction::Exponential),
+            ..HealthyRetryPolicy::default()
+        }),
+        throttle_policy: Some(ThrottlePolicy {
+            max_receives_per_second: 5,
+        }),
+        ..DeliveryPolicy::default()
+    };
+    assert_eq!(
+        serde_json::to_value(&delivery).unwrap(),
+        json!({
+            "healthyRetryPolicy": {
+                "minDelayTarget": 1,
+                "maxDelayTarget": 60,
+                "numRetries": 10,
+                "backoffFunction": "exponential"
+            },
+            "throttlePolicy": {"maxReceivesPerSecond": 5}
+        })
+    );
+
+    // Intrinsic functions aren't mistaken for policies
+    let policy: Expr<DeliveryPolicy> =
+        serde_json::from_value(json!({"Fn::ImportValue": "SharedDeliveryPolicy"})).unwrap();
+    assert!(policy.as_literal().is_none());
+    let filter: Expr<FilterPolicy> =
+        serde_json::from_value(json!({"Fn::ImportValue": "SharedFilterPolicy"})).unwrap();
+    assert!(filter.as_literal().is_none());
+}
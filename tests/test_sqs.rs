@@ -0,0 +1,101 @@
+use caroline::types::AWS::SQS::Queue::{Queue, QueueBuilder};
+use caroline::types::AWS::SQS::QueuePolicy::QueuePolicyBuilder;
+use caroline::{
+    Expr, Intrinsic, Issue, PolicyDocument, RedriveAllowPolicy, RedrivePolicy, Statement, Template,
+};
+use serde_json::json;
+
+#[test]
+fn test_dead_letter_queue() {
+    let dead_letters = QueueBuilder::default()
+        .QueueName("orders-dlq")
+        // A GetAtt of Orders would make the two queues depend on each other
+        .RedriveAllowPolicy(RedriveAllowPolicy::by_queue(vec![Intrinsic::sub(
+            "arn:${AWS::Partition}:sqs:${AWS::Region}:${AWS::AccountId}:orders",
+        )]))
+        .build()
+        .unwrap();
+    let orders = QueueBuilder::default()
+        .QueueName("orders")
+        .RedrivePolicy(RedrivePolicy::new(
+            Intrinsic::get_att("OrdersDeadLetters", "Arn").unwrap(),
+            5,
+        ))
+        .build()
+        .unwrap();
+    let policy = QueuePolicyBuilder::default()
+        .Queues(vec![Expr::from(Intrinsic::reference("Orders").unwrap())])
+        .PolicyDocument(
+            PolicyDocument::new().statement(
+                Statement::allow()
+                    .principal(json!({"Service": "sns.amazonaws.com"}))
+                    .action("sqs:SendMessage")
+                    .resource(Intrinsic::get_att("Orders", "Arn").unwrap()),
+            ),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("OrdersDeadLetters", dead_letters)
+        .unwrap();
+    template.add_resource("Orders", orders).unwrap();
+    template.add_resource("OrdersPolicy", policy).unwrap();
+    assert_eq!(
+        template.get("Orders").unwrap().properties["RedrivePolicy"],
+        json!({
+            "deadLetterTargetArn": {"Fn::GetAtt": ["OrdersDeadLetters", "Arn"]},
+            "maxReceiveCount": 5
+        })
+    );
+    assert_eq!(
+        template.get("OrdersDeadLetters").unwrap().properties["RedriveAllowPolicy"],
+        json!({
+            "redrivePermission": "byQueue",
+            "sourceQueueArns": [{
+                "Fn::Sub": "arn:${AWS::Partition}:sqs:${AWS::Region}:${AWS::AccountId}:orders"
+            }]
+        })
+    );
+    assert_eq!(
+        template.get("OrdersPolicy").unwrap().properties["PolicyDocument"]["Statement"][0]
+            ["Principal"],
+        json!({"Service": "sns.amazonaws.com"})
+    );
+    let parsed = template.resource::<Queue>("Orders").unwrap();
+    assert_eq!(
+        parsed
+            .RedrivePolicy
+            .unwrap()
+            .as_literal()
+            .unwrap()
+            .max_receive_count,
+        Some(5)
+    );
+    assert!(template.validate().is_empty());
+}
+
+#[test]
+fn test_fifo_settings_are_consistent() {
+    let queue = QueueBuilder::default()
+        .QueueName("payments.fifo")
+        .ContentBasedDeduplication(true)
+        .build()
+        .unwrap();
+    let mut template = Template::new();
+    template.add_resource("Payments", queue).unwrap();
+    let mismatch = |property: &str| Issue::FifoMismatch {
+        logical_id: "Payments".to_string(),
+        property: property.to_string(),
+    };
+    assert_eq!(
+        template.validate(),
+        vec![mismatch("QueueName"), mismatch("ContentBasedDeduplication")]
+    );
+
+    template.get_mut("Payments").unwrap().properties["FifoQueue"] = json!(true);
+    assert!(template.validate().is_empty());
+    template.get_mut("Payments").unwrap().properties["QueueName"] = json!("payments");
+    assert_eq!(template.validate(), vec![mismatch("QueueName")]);
+}
@@ -0,0 +1,53 @@
+use caroline::enums::ComplianceSeverity;
+use caroline::types::AWS::SSM::Association::{Association, AssociationBuilder, TargetBuilder};
+use caroline::{Expr, Intrinsic, RateUnit, ScheduleExpression, Template};
+use serde_json::json;
+
+#[test]
+fn test_association() {
+    let association = AssociationBuilder::default()
+        .Name("AWS-UpdateSSMAgent")
+        .ScheduleExpression(ScheduleExpression::rate(30, RateUnit::Minutes).unwrap())
+        .ComplianceSeverity(ComplianceSeverity::High)
+        .Targets(vec![TargetBuilder::default()
+            .Key("tag:Patch")
+            .Values(vec!["true".into()])
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("UpdateAgent", association).unwrap();
+    let properties = &template.get("UpdateAgent").unwrap().properties;
+    assert_eq!(properties["ScheduleExpression"], json!("rate(30 minutes)"));
+    assert_eq!(properties["ComplianceSeverity"], json!("HIGH"));
+    assert_eq!(
+        properties["Targets"],
+        json!([{"Key": "tag:Patch", "Values": ["true"]}])
+    );
+
+    let association = template.resource::<Association>("UpdateAgent").unwrap();
+    assert_eq!(
+        association.ScheduleExpression,
+        Some(Expr::Literal(ScheduleExpression::Rate(
+            30,
+            RateUnit::Minutes
+        )))
+    );
+
+    // Schedules may still be given by parameters
+    let association = AssociationBuilder::default()
+        .Name("AWS-RunPatchBaseline")
+        .ScheduleExpression(Intrinsic::reference("Schedule").unwrap())
+        .build()
+        .unwrap();
+    template.add_resource("Patch", association).unwrap();
+    assert_eq!(
+        template
+            .resource::<Association>("Patch")
+            .unwrap()
+            .ScheduleExpression,
+        Some(Expr::Intrinsic(Intrinsic::Ref("Schedule".into())))
+    );
+}
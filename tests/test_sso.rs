@@ -0,0 +1,87 @@
+use caroline::types::AWS::SSO::Assignment::AssignmentBuilder;
+use caroline::types::AWS::SSO::PermissionSet::{
+    CustomerManagedPolicyReferenceBuilder, PermissionSet, PermissionSetBuilder,
+};
+use caroline::{Effect, Expr, Intrinsic, PolicyDocument, Statement, Template};
+use serde_json::json;
+
+const INSTANCE: &str = "arn:aws:sso:::instance/ssoins-0123456789abcdef";
+
+#[test]
+fn test_permission_set() {
+    let policy = PolicyDocument::new().statement(
+        Statement::allow()
+            .sid("ReadAssets")
+            .action("s3:GetObject")
+            .resource(Intrinsic::sub("${Assets.Arn}/*"))
+            .condition("Bool", "aws:SecureTransport", "true"),
+    );
+    let permission_set = PermissionSetBuilder::default()
+        .InstanceArn(INSTANCE)
+        .Name("AssetReaders")
+        .SessionDuration("PT4H")
+        .InlinePolicy(policy.clone())
+        .ManagedPolicies(vec![Expr::from("arn:aws:iam::aws:policy/ReadOnlyAccess")])
+        .CustomerManagedPolicyReferences(vec![CustomerManagedPolicyReferenceBuilder::default()
+            .Name("AuditAccess")
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+    let assignment = AssignmentBuilder::default()
+        .InstanceArn(INSTANCE)
+        .PermissionSetArn(Intrinsic::get_att("AssetReaders", "PermissionSetArn").unwrap())
+        .PrincipalType("GROUP")
+        .PrincipalId("906757cfe5-1234-5678-9abc-def012345678")
+        .TargetType("AWS_ACCOUNT")
+        .TargetId("123456789012")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template
+        .add_resource("AssetReaders", permission_set)
+        .unwrap();
+    template.add_resource("Readers", assignment).unwrap();
+    assert_eq!(
+        template.get("AssetReaders").unwrap().properties["InlinePolicy"],
+        json!({
+            "Version": "2012-10-17",
+            "Statement": [{
+                "Sid": "ReadAssets",
+                "Effect": "Allow",
+                "Action": ["s3:GetObject"],
+                "Resource": [{"Fn::Sub": "${Assets.Arn}/*"}],
+                "Condition": {"Bool": {"aws:SecureTransport": "true"}}
+            }]
+        })
+    );
+    let parsed = template.resource::<PermissionSet>("AssetReaders").unwrap();
+    assert_eq!(parsed.InlinePolicy, Some(Expr::Literal(policy)));
+}
+
+#[test]
+fn test_parse_policy_with_single_values() {
+    let policy: PolicyDocument = serde_json::from_value(json!({
+        "Version": "2012-10-17",
+        "Statement": {
+            "Effect": "Deny",
+            "Action": "iam:*",
+            "Resource": "*"
+        }
+    }))
+    .unwrap();
+    let statement = &policy.statement[0];
+    assert_eq!(statement.effect, Effect::Deny);
+    assert_eq!(statement.action, vec!["iam:*".to_string()]);
+    assert_eq!(statement.resource, vec![Expr::from("*")]);
+    assert_eq!(
+        serde_json::to_value(&policy).unwrap()["Statement"],
+        json!([{"Effect": "Deny", "Action": ["iam:*"], "Resource": ["*"]}])
+    );
+
+    // Intrinsic functions aren't mistaken for policy documents
+    let inline: Expr<PolicyDocument> =
+        serde_json::from_value(json!({"Fn::ImportValue": "SharedPolicy"})).unwrap();
+    assert!(inline.as_literal().is_none());
+}
@@ -0,0 +1,56 @@
+use caroline::types::AWS::EC2::VPC::VPC;
+use caroline::{Error, Template};
+use serde_json::json;
+
+fn template_json() -> String {
+    json!({
+        "Resources": {
+            "Vpc": {
+                "Type": "AWS::EC2::VPC",
+                "Properties": {
+                    "CidrBlock": "10.0.0.0/16",
+                    "EnableDnsSuport": true,
+                    "Tags": [{"Key": "Name", "Value": "vpc", "Colour": "blue"}]
+                }
+            },
+            "Subnet": {
+                "Type": "AWS::EC2::Subnet",
+                "Properties": {
+                    "VpcId": {"Ref": "Vpc"},
+                    "CidrBlock": {"Fn::Select": [0, {"Fn::Cidr": ["10.0.0.0/16", 2, 8]}]}
+                }
+            },
+            "Custom": {
+                "Type": "Custom::Thing",
+                "Properties": {"Anything": true}
+            }
+        }
+    })
+    .to_string()
+}
+
+#[test]
+fn test_strict_rejects_unknown_properties() {
+    match Template::from_json_strict(&template_json()) {
+        Err(Error::UnknownProperties(paths)) => {
+            assert_eq!(paths, vec!["Vpc.EnableDnsSuport", "Vpc.Tags[0].Colour"])
+        }
+        other => panic!("Expected unknown properties, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_lenient_keeps_unknown_properties() {
+    let template = Template::from_json(&template_json()).unwrap();
+    assert_eq!(template.unknown_properties().len(), 2);
+
+    let vpc = template.resource::<VPC>("Vpc").unwrap();
+    assert!(vpc.EnableDnsSupport.is_none());
+    assert_eq!(vpc.extra["EnableDnsSuport"], json!(true));
+
+    let reparsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    assert_eq!(
+        reparsed.get("Vpc").unwrap().properties["EnableDnsSuport"],
+        json!(true)
+    );
+}
@@ -0,0 +1,63 @@
+use caroline::types::AWS::Synthetics::Canary::{
+    Canary, CanaryBuilder, CodeBuilder, ScheduleBuilder,
+};
+use caroline::types::AWS::Synthetics::Group::{Group, GroupBuilder};
+use caroline::Template;
+use serde_json::json;
+
+#[test]
+fn test_canary() {
+    let canary = CanaryBuilder::default()
+        .ArtifactS3Location("s3://canary-artifacts/")
+        .Code(
+            CodeBuilder::default()
+                .Handler("index.handler")
+                .Script("exports.handler = async () => {};")
+                .build()
+                .unwrap(),
+        )
+        .ExecutionRoleArn("arn:aws:iam::123456789012:role/canary")
+        .Name("canary")
+        .RuntimeVersion("syn-nodejs-puppeteer-6.2")
+        .Schedule(
+            ScheduleBuilder::default()
+                .Expression("rate(5 minutes)")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Canary", canary).unwrap();
+    let entry = template.get("Canary").unwrap();
+    assert_eq!(entry.type_, "AWS::Synthetics::Canary");
+    assert_eq!(
+        entry.properties,
+        json!({
+            "ArtifactS3Location": "s3://canary-artifacts/",
+            "Code": {"Handler": "index.handler", "Script": "exports.handler = async () => {};"},
+            "ExecutionRoleArn": "arn:aws:iam::123456789012:role/canary",
+            "Name": "canary",
+            "RuntimeVersion": "syn-nodejs-puppeteer-6.2",
+            "Schedule": {"Expression": "rate(5 minutes)"}
+        })
+    );
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let canary = parsed.resource::<Canary>("Canary").unwrap();
+    assert_eq!(serde_json::to_value(canary).unwrap(), entry.properties);
+}
+
+#[test]
+fn test_group() {
+    let group = GroupBuilder::default().Name("group").build().unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Group", group).unwrap();
+    let entry = template.get("Group").unwrap();
+    assert_eq!(entry.type_, "AWS::Synthetics::Group");
+    assert_eq!(entry.properties, json!({"Name": "group"}));
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    let group = parsed.resource::<Group>("Group").unwrap();
+    assert_eq!(serde_json::to_value(group).unwrap(), entry.properties);
+}
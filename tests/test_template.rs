@@ -0,0 +1,506 @@
+use caroline::types::AWS::EC2::Subnet::{Subnet, SubnetBuilder};
+use caroline::types::AWS::EC2::VPC::{VPCBuilder, VPC};
+use caroline::{
+    CollisionPolicy, Error, Expr, Extension, Intrinsic, Issue, LogicalId, Output, Parameter,
+    PrettyPrinter, Template, UnknownResource, Validator,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::convert::TryFrom;
+
+fn network() -> Template {
+    let vpc = VPCBuilder::default()
+        .CidrBlock("10.0.0.0/16")
+        .EnableDnsSupport(true)
+        .build()
+        .unwrap();
+    let subnet = SubnetBuilder::default()
+        .VpcId(Intrinsic::Ref("Vpc".into()))
+        .CidrBlock("10.0.0.0/24")
+        .AvailabilityZone(Intrinsic::select(0, Intrinsic::get_azs("")))
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.set_description("Network");
+    template.add_resource("Vpc", vpc).unwrap();
+    template
+        .add_resource("Subnet", subnet)
+        .unwrap()
+        .depends_on("Vpc")
+        .unwrap();
+    template
+        .add_output("VpcId", Output::new(Intrinsic::Ref("Vpc".into())))
+        .unwrap();
+    template
+}
+
+#[test]
+fn test_template_round_trip() {
+    let mut template = network();
+
+    let json = template.to_json().unwrap();
+    assert_eq!(Template::from_json(&json).unwrap(), template);
+
+    let subnet = template.resource::<Subnet>("Subnet").unwrap();
+    assert_eq!(subnet.VpcId, Expr::Intrinsic(Intrinsic::Ref("Vpc".into())));
+
+    match template.resource::<VPC>("Subnet") {
+        Err(Error::TypeMismatch { found, .. }) => assert_eq!(found, "AWS::EC2::Subnet"),
+        other => panic!("Expected a type mismatch, got {:?}", other.map(|_| ())),
+    }
+    assert!(template.add_resource("Vpc", VPC::default()).is_err());
+}
+
+#[test]
+fn test_expr_forms() {
+    let port: Expr<i32> = serde_json::from_value(json!("22")).unwrap();
+    assert_eq!(port, Expr::Literal(22));
+    let name: Expr<String> = serde_json::from_value(json!("22")).unwrap();
+    assert_eq!(name, Expr::Literal("22".to_string()));
+    assert!(serde_json::from_value::<Expr<i32>>(json!("twenty-two")).is_err());
+
+    let vpc_id: Expr<String> = serde_json::from_value(json!({"Fn::GetAtt": "Vpc.VpcId"})).unwrap();
+    assert_eq!(
+        vpc_id,
+        Expr::from(Intrinsic::get_att("Vpc", "VpcId").unwrap())
+    );
+    let address: Expr<String> =
+        serde_json::from_value(json!({"Fn::GetAtt": "Db.Endpoint.Address"})).unwrap();
+    assert_eq!(
+        address,
+        Expr::from(Intrinsic::get_att("Db", "Endpoint.Address").unwrap())
+    );
+    assert!(serde_json::from_value::<Expr<String>>(json!({"Fn::GetAtt": "Vpc"})).is_err());
+
+    let template = json!({
+        "Resources": {
+            "Vpc": {
+                "Type": "AWS::EC2::VPC",
+                "Properties": {"CidrBlock": "10.0.0.0/16", "EnableDnsSupport": "true"}
+            },
+            "Subnet": {
+                "Type": "AWS::EC2::Subnet",
+                "Properties": {"VpcId": {"Fn::GetAtt": "Vpc.VpcId"}, "CidrBlock": "10.0.0.0/24"}
+            }
+        }
+    });
+    let template = Template::from_json(&template.to_string()).unwrap();
+    let vpc = template.resource::<VPC>("Vpc").unwrap();
+    assert_eq!(vpc.EnableDnsSupport, Some(Expr::Literal(true)));
+    let subnet = template.resource::<Subnet>("Subnet").unwrap();
+    assert_eq!(
+        subnet.VpcId,
+        Expr::from(Intrinsic::get_att("Vpc", "VpcId").unwrap())
+    );
+}
+
+#[test]
+fn test_pretty_print() {
+    let mut template = network();
+    template
+        .add_output(
+            "Missing",
+            Output::new(Intrinsic::get_att("Nope", "Arn").unwrap()),
+        )
+        .unwrap();
+
+    let rendered = template.to_string();
+    assert!(rendered.contains("  Subnet  AWS::EC2::Subnet"));
+    assert!(rendered.contains("VpcId             !Ref Vpc\n"));
+    assert!(rendered.contains("!Select [0, !GetAZs \"\"]"));
+    assert!(rendered.contains("!GetAtt Nope.Arn <unresolved>"));
+
+    let vpc = template.resource::<VPC>("Vpc").unwrap();
+    assert!(vpc.to_string().starts_with("AWS::EC2::VPC\n"));
+
+    let printer = PrettyPrinter::new().max_string_len(8);
+    assert!(printer.template(&template).contains("10.0.0.…"));
+}
+
+#[test]
+fn test_clone_on_write() {
+    let template = network();
+    let mut staging = template.clone();
+    assert!(staging.shares_resource(&template, "Vpc"));
+    assert!(staging.shares_resource(&template, "Subnet"));
+
+    staging
+        .get_mut("Vpc")
+        .unwrap()
+        .set_deletion_policy("Retain");
+    assert!(!staging.shares_resource(&template, "Vpc"));
+    assert!(staging.shares_resource(&template, "Subnet"));
+    assert_eq!(template.get("Vpc").unwrap().deletion_policy, None);
+
+    staging.remove("Subnet");
+    assert!(template.get("Subnet").is_some());
+}
+
+#[test]
+fn test_incremental_validation() {
+    let mut template = network();
+    template
+        .add_resource(
+            "Gateway",
+            VPCBuilder::default()
+                .CidrBlock(Intrinsic::sub("${Cidr}"))
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+
+    let mut validator = Validator::new(&template);
+    assert_eq!(validator.issues().count(), 1);
+    assert_eq!(template.validate().len(), 1);
+
+    template
+        .add_parameter("Cidr", Parameter::new("String"))
+        .unwrap();
+    assert_eq!(validator.update(&template, "Cidr"), vec!["Gateway"]);
+    assert!(validator.is_valid());
+
+    template.remove("Vpc");
+    assert_eq!(validator.update(&template, "Vpc"), vec!["Subnet"]);
+    let issues = validator.issues().collect::<Vec<&Issue>>();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(
+        issues[0].to_string(),
+        "Subnet references unknown logical ID Vpc"
+    );
+}
+
+#[test]
+fn test_condition_validation() {
+    let template = json!({
+        "Parameters": {"Env": {"Type": "String"}},
+        "Conditions": {
+            "IsProd": {"Fn::Equals": [{"Ref": "Env"}, "prod"]},
+            "IsProdEu": {"Fn::And": [{"Condition": "IsProd"}, {"Condition": "IsEu"}]},
+            "IsDev": {"Fn::Equals": [{"Ref": "Env"}, "dev"]}
+        },
+        "Resources": {
+            "Vpc": {
+                "Type": "AWS::EC2::VPC",
+                "Condition": "IsProdEu",
+                "Properties": {"CidrBlock": "10.0.0.0/16"}
+            }
+        },
+        "Outputs": {
+            "Cidr": {
+                "Condition": "IsProd",
+                "Value": {"Fn::If": ["IsStaging", "10.1.0.0/16", {"Fn::GetAtt": ["Vpc", "CidrBlock"]}]}
+            }
+        }
+    });
+    let template = Template::from_json(&template.to_string()).unwrap();
+    let issues = template.validate();
+    assert_eq!(
+        issues,
+        vec![
+            Issue::UnknownCondition {
+                logical_id: "Cidr".to_string(),
+                condition: "IsStaging".to_string(),
+            },
+            Issue::UnknownCondition {
+                logical_id: "IsProdEu".to_string(),
+                condition: "IsEu".to_string(),
+            },
+            Issue::UnusedCondition {
+                condition: "IsDev".to_string(),
+            },
+        ]
+    );
+    assert_eq!(issues[2].to_string(), "Condition IsDev is never used");
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct Provenance {
+    tool: String,
+    commit: String,
+}
+
+impl Extension for Provenance {
+    const KEY: &'static str = "Caroline::Provenance";
+}
+
+#[test]
+fn test_metadata_round_trip() {
+    let json = r#"{
+        "Metadata": {"AWS::CloudFormation::Interface": {"ParameterGroups": []}},
+        "Resources": {
+            "Vpc": {
+                "Type": "AWS::EC2::VPC",
+                "Properties": {"CidrBlock": "10.0.0.0/16"},
+                "Metadata": {"cfn_nag": {"rules_to_suppress": [{"id": "W60"}]}}
+            }
+        }
+    }"#;
+    let mut template = Template::from_json(json).unwrap();
+    let provenance = Provenance {
+        tool: "caroline".to_string(),
+        commit: "abc123".to_string(),
+    };
+    template.metadata_mut().set_extension(&provenance).unwrap();
+    template
+        .get_mut("Vpc")
+        .unwrap()
+        .metadata
+        .insert("Owner", "network-team")
+        .unwrap();
+
+    let parsed = Template::from_json(&template.to_json().unwrap()).unwrap();
+    assert_eq!(parsed, template);
+    assert_eq!(parsed.metadata().len(), 2);
+    assert_eq!(
+        parsed.metadata().extension::<Provenance>().unwrap(),
+        Some(provenance)
+    );
+    let vpc = &parsed.get("Vpc").unwrap().metadata;
+    assert_eq!(vpc.get("Owner"), Some(&json!("network-team")));
+    assert_eq!(
+        vpc.get("cfn_nag"),
+        Some(&json!({"rules_to_suppress": [{"id": "W60"}]}))
+    );
+    assert_eq!(vpc.extension::<Provenance>().unwrap(), None);
+}
+
+#[test]
+fn test_logical_ids() {
+    assert!(LogicalId::new("PublicSubnet0").is_ok());
+    assert!(matches!(
+        LogicalId::new("public-subnet"),
+        Err(Error::InvalidLogicalId(_))
+    ));
+    assert!(LogicalId::new("").is_err());
+    assert!(LogicalId::new("a".repeat(256)).is_err());
+
+    assert_eq!(
+        LogicalId::sanitize("public-subnet-a").unwrap(),
+        "publicSubnetA"
+    );
+    assert_eq!(LogicalId::sanitize("Web Server 2").unwrap(), "WebServer2");
+    assert_eq!(LogicalId::sanitize("Vpc").unwrap(), "Vpc");
+    assert!(LogicalId::sanitize("--").is_err());
+    assert_eq!(LogicalId::try_from("Vpc").unwrap(), "Vpc");
+    assert!(LogicalId::try_from("public-subnet-a").is_err());
+
+    // Strings are validated wherever a logical ID is taken, so they can be looked up as they
+    // are, names are sanitized explicitly
+    let mut template = Template::new();
+    let vpc = VPCBuilder::default()
+        .CidrBlock("10.0.0.0/16")
+        .build()
+        .unwrap();
+    assert!(matches!(
+        template.add_resource("main-vpc", vpc.clone()),
+        Err(Error::InvalidLogicalId(id)) if id == "main-vpc"
+    ));
+    assert!(Intrinsic::reference("main-vpc").is_err());
+    let vpc_id = LogicalId::sanitize("main-vpc").unwrap();
+    template.add_resource(&vpc_id, vpc).unwrap();
+    let subnet = SubnetBuilder::default()
+        .CidrBlock("10.0.0.0/24")
+        .VpcId(Intrinsic::reference(&vpc_id).unwrap())
+        .build()
+        .unwrap();
+    template
+        .add_resource("subnet", subnet)
+        .unwrap()
+        .depends_on(&vpc_id)
+        .unwrap();
+    template
+        .add_output(
+            LogicalId::sanitize("vpc_cidr").unwrap(),
+            Output::new(Intrinsic::get_att(&vpc_id, "CidrBlock").unwrap()),
+        )
+        .unwrap();
+
+    assert!(template.get("mainVpc").is_some());
+    assert_eq!(template.get("subnet").unwrap().depends_on, vec!["mainVpc"]);
+    assert!(template.validate().is_empty());
+    let json: serde_json::Value = serde_json::from_str(&template.to_json().unwrap()).unwrap();
+    assert_eq!(
+        json["Resources"]["subnet"]["Properties"]["VpcId"],
+        json!({"Ref": "mainVpc"})
+    );
+    assert_eq!(
+        json["Outputs"]["vpcCidr"]["Value"],
+        json!({"Fn::GetAtt": ["mainVpc", "CidrBlock"]})
+    );
+
+    // Parsed templates are checked rather than sanitized
+    let invalid = json!({
+        "Resources": {
+            "Subnet": {"Type": "AWS::EC2::Subnet", "DependsOn": "main-vpc"}
+        }
+    });
+    assert!(Template::from_json(&invalid.to_string()).is_err());
+
+    // Invalid logical IDs are rejected rather than panicking, while pseudo parameters are
+    // referred to as they are
+    let vpc = VPCBuilder::default()
+        .CidrBlock("10.0.0.0/16")
+        .build()
+        .unwrap();
+    assert!(matches!(
+        template.add_resource("--", vpc),
+        Err(Error::InvalidLogicalId(id)) if id == "--"
+    ));
+    assert!(LogicalId::try_from("--").is_err());
+    assert!(Intrinsic::get_att("--", "Arn").is_err());
+    assert!(template
+        .get_mut("subnet")
+        .unwrap()
+        .depends_on("--")
+        .is_err());
+    assert_eq!(
+        Intrinsic::reference("AWS::StackId").unwrap(),
+        Intrinsic::Ref("AWS::StackId".to_string())
+    );
+}
+
+#[test]
+fn test_unknown_resources() {
+    let template = json!({
+        "Resources": {
+            "Cluster": {
+                "Type": "MongoDB::Atlas::Cluster",
+                "Properties": {
+                    "ProjectId": {"Ref": "Project"},
+                    "ClusterType": "REPLICASET"
+                }
+            },
+            "Vpc": {
+                "Type": "AWS::EC2::VPC",
+                "Properties": {"CidrBlock": "10.0.0.0/16"}
+            }
+        }
+    });
+    let mut template = Template::from_json(&template.to_string()).unwrap();
+
+    let unknown = template.unknown_resources().collect::<Vec<_>>();
+    assert_eq!(unknown.len(), 1);
+    assert_eq!(unknown[0].0, "Cluster");
+    assert_eq!(unknown[0].1.type_name, "MongoDB::Atlas::Cluster");
+    assert!(!template.get("Cluster").unwrap().is_known());
+    assert!(template.get("Vpc").unwrap().is_known());
+
+    // References of unknown resources are validated like any other
+    assert_eq!(
+        template.validate(),
+        vec![Issue::UnresolvedReference {
+            logical_id: "Cluster".to_string(),
+            target: "Project".to_string(),
+        }]
+    );
+    let project = UnknownResource::new(
+        "MongoDB::Atlas::Project",
+        json!({"Name": "analytics", "OrgId": "5f0c"}),
+    );
+    template.add_unknown_resource("Project", project).unwrap();
+    assert!(template.validate().is_empty());
+
+    let json: serde_json::Value = serde_json::from_str(&template.to_json().unwrap()).unwrap();
+    assert_eq!(
+        json["Resources"]["Project"],
+        json!({
+            "Type": "MongoDB::Atlas::Project",
+            "Properties": {"Name": "analytics", "OrgId": "5f0c"}
+        })
+    );
+    assert_eq!(
+        json["Resources"]["Cluster"]["Properties"]["ProjectId"],
+        json!({"Ref": "Project"})
+    );
+}
+
+#[test]
+fn test_merge() {
+    let mut stack = network();
+    let mut app = Template::new();
+    app.add_parameter("Env", Parameter::new("String")).unwrap();
+    app.add_condition("IsProd", json!({"Fn::Equals": [{"Ref": "Env"}, "prod"]}))
+        .unwrap();
+    app.add_resource(
+        "Vpc",
+        VPCBuilder::default()
+            .CidrBlock("10.1.0.0/16")
+            .build()
+            .unwrap(),
+    )
+    .unwrap()
+    .set_condition("IsProd");
+    let subnet = SubnetBuilder::default()
+        .VpcId(Intrinsic::Ref("Vpc".into()))
+        .CidrBlock(Intrinsic::Sub("${Vpc.CidrBlock}-${Env}".into()))
+        .build()
+        .unwrap();
+    app.add_resource("AppSubnet", subnet)
+        .unwrap()
+        .depends_on("Vpc")
+        .unwrap();
+    let mut output = Output::new(json!({"Fn::GetAtt": ["Vpc", "CidrBlock"]}));
+    output.condition = Some("IsProd".to_string());
+    app.add_output("VpcId", output).unwrap();
+
+    // Nothing is merged when a name collides
+    let err = stack
+        .clone()
+        .merge(app.clone(), CollisionPolicy::Error)
+        .unwrap_err();
+    assert!(matches!(err, Error::DuplicateLogicalId(id) if id == "Vpc"));
+
+    // Skipped names refer to the existing definitions
+    let mut skipped = stack.clone();
+    skipped.merge(app.clone(), CollisionPolicy::Skip).unwrap();
+    assert_eq!(
+        skipped.resources().map(|(id, _)| id).collect::<Vec<&str>>(),
+        vec!["AppSubnet", "Subnet", "Vpc"]
+    );
+    assert_eq!(skipped.get("Vpc"), stack.get("Vpc"));
+    assert_eq!(
+        skipped.outputs().next().unwrap().1,
+        stack.outputs().next().unwrap().1
+    );
+    assert!(skipped.shares_resource(&stack, "Vpc"));
+
+    // Prefixed names are renamed along with their references
+    stack
+        .merge(app, CollisionPolicy::Prefix("App".into()))
+        .unwrap();
+    assert_eq!(
+        stack.resources().map(|(id, _)| id).collect::<Vec<&str>>(),
+        vec!["AppSubnet", "AppVpc", "Subnet", "Vpc"]
+    );
+    let subnet = stack.get("AppSubnet").unwrap();
+    assert_eq!(subnet.properties["VpcId"], json!({"Ref": "AppVpc"}));
+    assert_eq!(
+        subnet.properties["CidrBlock"],
+        json!({"Fn::Sub": "${AppVpc.CidrBlock}-${Env}"})
+    );
+    assert_eq!(subnet.depends_on, vec![LogicalId::new("AppVpc").unwrap()]);
+    assert_eq!(
+        stack.get("AppVpc").unwrap().condition.as_deref(),
+        Some("IsProd")
+    );
+    let (_, output) = stack.outputs().find(|(id, _)| *id == "AppVpcId").unwrap();
+    assert_eq!(output.value, json!({"Fn::GetAtt": ["AppVpc", "CidrBlock"]}));
+    assert_eq!(stack.description(), Some("Network"));
+
+    // Prefixed names have to be free as well
+    let mut again = Template::new();
+    again
+        .add_resource(
+            "Vpc",
+            VPCBuilder::default()
+                .CidrBlock("10.2.0.0/16")
+                .build()
+                .unwrap(),
+        )
+        .unwrap();
+    let err = stack
+        .merge(again, CollisionPolicy::Prefix("App".into()))
+        .unwrap_err();
+    assert!(matches!(err, Error::DuplicateLogicalId(id) if id == "AppVpc"));
+}
@@ -0,0 +1,78 @@
+use caroline::types::AWS::Timestream::ScheduledQuery::{
+    DimensionMappingBuilder, ErrorReportConfigurationBuilder, NotificationConfigurationBuilder,
+    S3ConfigurationBuilder, ScheduleConfigurationBuilder, ScheduledQueryBuilder,
+    SnsConfigurationBuilder, TargetConfigurationBuilder, TimestreamConfigurationBuilder,
+};
+use caroline::{Intrinsic, RateUnit, ScheduleExpression, Template};
+use serde_json::json;
+
+#[test]
+fn test_hourly_rollup() {
+    let query = ScheduledQueryBuilder::default()
+        .QueryString(
+            "SELECT region, bin(time, 1h) AS hour, avg(cpu) AS cpu FROM metrics.hosts \
+             WHERE time > ago(1h) GROUP BY region, bin(time, 1h)",
+        )
+        .ScheduleConfiguration(
+            ScheduleConfigurationBuilder::default()
+                .ScheduleExpression(ScheduleExpression::rate(1, RateUnit::Hours).unwrap())
+                .build()
+                .unwrap(),
+        )
+        .NotificationConfiguration(
+            NotificationConfigurationBuilder::default()
+                .SnsConfiguration(
+                    SnsConfigurationBuilder::default()
+                        .TopicArn(Intrinsic::reference("QueryRuns").unwrap())
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .ErrorReportConfiguration(
+            ErrorReportConfigurationBuilder::default()
+                .S3Configuration(
+                    S3ConfigurationBuilder::default()
+                        .BucketName("query-errors")
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .ScheduledQueryExecutionRoleArn(Intrinsic::get_att("QueryRole", "Arn").unwrap())
+        .TargetConfiguration(
+            TargetConfigurationBuilder::default()
+                .TimestreamConfiguration(
+                    TimestreamConfigurationBuilder::default()
+                        .DatabaseName("metrics")
+                        .TableName("hourly")
+                        .TimeColumn("hour")
+                        .MeasureNameColumn("cpu")
+                        .DimensionMappings(vec![DimensionMappingBuilder::default()
+                            .Name("region")
+                            .DimensionValueType("VARCHAR")
+                            .build()
+                            .unwrap()])
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("HourlyRollup", query).unwrap();
+    let properties = &template.get("HourlyRollup").unwrap().properties;
+    assert_eq!(
+        properties["ScheduleConfiguration"],
+        json!({"ScheduleExpression": "rate(1 hour)"})
+    );
+    assert_eq!(
+        properties["TargetConfiguration"]["TimestreamConfiguration"]["DimensionMappings"],
+        json!([{"Name": "region", "DimensionValueType": "VARCHAR"}])
+    );
+}
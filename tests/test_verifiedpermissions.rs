@@ -0,0 +1,69 @@
+use caroline::types::AWS::VerifiedPermissions::IdentitySource::{
+    CognitoUserPoolConfigurationBuilder, IdentitySourceBuilder, IdentitySourceConfigurationBuilder,
+};
+use caroline::types::AWS::VerifiedPermissions::Policy::{
+    PolicyBuilder, PolicyDefinitionBuilder, StaticPolicyDefinitionBuilder,
+};
+use caroline::types::AWS::VerifiedPermissions::PolicyStore::{
+    PolicyStoreBuilder, ValidationSettingsBuilder,
+};
+use caroline::{Intrinsic, Template};
+use serde_json::json;
+
+#[test]
+fn test_policy_store_with_cognito_identities() {
+    let store = PolicyStoreBuilder::default()
+        .ValidationSettings(
+            ValidationSettingsBuilder::default()
+                .Mode("STRICT")
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let policy = PolicyBuilder::default()
+        .PolicyStoreId(Intrinsic::get_att("Store", "PolicyStoreId").unwrap())
+        .Definition(
+            PolicyDefinitionBuilder::default()
+                .Static(
+                    StaticPolicyDefinitionBuilder::default()
+                        .Statement(r#"permit(principal, action == Action::"view", resource);"#)
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let identities = IdentitySourceBuilder::default()
+        .PolicyStoreId(Intrinsic::get_att("Store", "PolicyStoreId").unwrap())
+        .Configuration(
+            IdentitySourceConfigurationBuilder::default()
+                .CognitoUserPoolConfiguration(
+                    CognitoUserPoolConfigurationBuilder::default()
+                        .UserPoolArn("arn:aws:cognito-idp:eu-west-1:123456789012:userpool/pool")
+                        .build()
+                        .unwrap(),
+                )
+                .build()
+                .unwrap(),
+        )
+        .PrincipalEntityType("User")
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Store", store).unwrap();
+    template.add_resource("ViewAnything", policy).unwrap();
+    template.add_resource("Users", identities).unwrap();
+    assert_eq!(
+        template.get("ViewAnything").unwrap().properties,
+        json!({
+            "PolicyStoreId": {"Fn::GetAtt": ["Store", "PolicyStoreId"]},
+            "Definition": {"Static": {
+                "Statement": "permit(principal, action == Action::\"view\", resource);"
+            }}
+        })
+    );
+}
@@ -0,0 +1,80 @@
+use caroline::types::Tag::TagBuilder;
+use caroline::types::AWS::EC2::Subnet::{Subnet, SubnetBuilder};
+use caroline::types::AWS::EC2::VPC::VPCBuilder;
+use caroline::{Intrinsic, PropertyPath, Segment, Template};
+use serde_json::{json, Value};
+
+fn network() -> Template {
+    let vpc = VPCBuilder::default()
+        .CidrBlock("10.0.0.0/16")
+        .Tags(vec![TagBuilder::default()
+            .Key("Name")
+            .Value("vpc")
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+    let subnet = SubnetBuilder::default()
+        .VpcId(Intrinsic::Ref("Vpc".into()))
+        .CidrBlock("10.0.0.0/24")
+        .build()
+        .unwrap();
+    let mut template = Template::new();
+    template.add_resource("Vpc", vpc).unwrap();
+    template.add_resource("Subnet", subnet).unwrap();
+    template
+}
+
+#[test]
+fn test_template_view() {
+    let template = network();
+    let view = template.view();
+
+    let ids = view
+        .resources()
+        .map(|resource| resource.logical_id())
+        .collect::<Vec<&str>>();
+    assert_eq!(ids, vec!["Subnet", "Vpc"]);
+    assert_eq!(view.resources_of::<Subnet>().count(), 1);
+
+    let subnet = view.get("Subnet").unwrap();
+    assert_eq!(subnet.resource_type(), "AWS::EC2::Subnet");
+    assert_eq!(subnet.property("VpcId"), Some(&json!({"Ref": "Vpc"})));
+    assert!(view.get("Missing").is_none());
+}
+
+#[test]
+fn test_view_values() {
+    let template = network();
+    let values = template
+        .view()
+        .values()
+        .map(|(path, value)| (path.to_string(), value))
+        .collect::<Vec<(String, &Value)>>();
+    assert_eq!(
+        values,
+        vec![
+            ("Subnet.CidrBlock".to_string(), &json!("10.0.0.0/24")),
+            ("Subnet.VpcId".to_string(), &json!({"Ref": "Vpc"})),
+            ("Vpc.CidrBlock".to_string(), &json!("10.0.0.0/16")),
+            ("Vpc.Tags[0].Key".to_string(), &json!("Name")),
+            ("Vpc.Tags[0].Value".to_string(), &json!("vpc")),
+        ]
+    );
+}
+
+#[test]
+fn test_view_walk() {
+    let template = network();
+    let mut refs = vec![];
+    template
+        .view()
+        .walk(&mut |path: &PropertyPath<'_>, value: &Value| {
+            if let Some(target) = value.get("Ref").and_then(Value::as_str) {
+                assert_eq!(path.segments()[0], Segment::Key("Subnet"));
+                assert_eq!(path.key(), Some("VpcId"));
+                refs.push(target.to_string());
+            }
+        });
+    assert_eq!(refs, vec!["Vpc"]);
+}
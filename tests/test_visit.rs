@@ -0,0 +1,87 @@
+use caroline::types::Tag::TagBuilder;
+use caroline::types::AWS::EC2::VPC::VPCBuilder;
+use caroline::types::AWS::S3::Bucket::{
+    BucketBuilder, BucketEncryptionBuilder, ServerSideEncryptionByDefaultBuilder,
+    ServerSideEncryptionRule,
+};
+use caroline::{Intrinsic, PropertyPath, Template};
+use serde_json::{json, Value};
+
+#[test]
+fn test_visit_properties() {
+    let vpc = VPCBuilder::default()
+        .CidrBlock("10.0.0.0/16")
+        .EnableDnsSupport(true)
+        .Tags(vec![TagBuilder::default()
+            .Key("Name")
+            .Value("vpc")
+            .build()
+            .unwrap()])
+        .build()
+        .unwrap();
+
+    let mut values = vec![];
+    vpc.visit_properties(&mut |path: &PropertyPath<'_>, value: &Value| {
+        values.push((path.to_string(), value.clone()))
+    });
+    assert_eq!(
+        values,
+        vec![
+            ("CidrBlock".to_string(), json!("10.0.0.0/16")),
+            ("EnableDnsSupport".to_string(), json!(true)),
+            ("Tags[0].Key".to_string(), json!("Name")),
+            ("Tags[0].Value".to_string(), json!("vpc")),
+        ]
+    );
+
+    // Walking the typed resource matches walking its serialized form
+    let mut template = Template::new();
+    template.add_resource("Vpc", vpc).unwrap();
+    let serialized = template
+        .view()
+        .values()
+        .map(|(path, value)| (path.to_string(), value.clone()))
+        .collect::<Vec<(String, Value)>>();
+    let prefixed = values
+        .into_iter()
+        .map(|(path, value)| (format!("Vpc.{}", path), value))
+        .collect::<Vec<(String, Value)>>();
+    assert_eq!(serialized, prefixed);
+}
+
+#[test]
+fn test_collect_nested_fields() {
+    let rule = ServerSideEncryptionRule {
+        ServerSideEncryptionByDefault: Some(
+            ServerSideEncryptionByDefaultBuilder::default()
+                .KMSMasterKeyID(Intrinsic::get_att("Key", "Arn").unwrap())
+                .SSEAlgorithm("aws:kms")
+                .build()
+                .unwrap(),
+        ),
+        ..Default::default()
+    };
+    let bucket = BucketBuilder::default()
+        .BucketEncryption(
+            BucketEncryptionBuilder::default()
+                .ServerSideEncryptionConfiguration(vec![rule])
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut kms_keys = vec![];
+    bucket.visit_properties(&mut |path: &PropertyPath<'_>, value: &Value| {
+        if path.key() == Some("KMSMasterKeyID") {
+            kms_keys.push((path.to_string(), value.clone()));
+        }
+    });
+    assert_eq!(
+        kms_keys,
+        vec![(
+            "BucketEncryption.ServerSideEncryptionConfiguration[0].ServerSideEncryptionByDefault.KMSMasterKeyID".to_string(),
+            json!({"Fn::GetAtt": ["Key", "Arn"]})
+        )]
+    );
+}
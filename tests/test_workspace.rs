@@ -0,0 +1,75 @@
+use caroline::types::AWS::S3::Bucket::BucketBuilder;
+use caroline::{Error, Template, Workspace};
+use std::sync::Arc;
+use std::thread;
+
+fn assert_send_sync<T: Send + Sync>() {}
+
+#[test]
+fn test_concurrent_generation() {
+    assert_send_sync::<Workspace>();
+
+    let workspace = Arc::new(Workspace::new());
+    let workers = (0..8)
+        .map(|worker| {
+            let workspace = workspace.clone();
+            thread::spawn(move || {
+                for idx in 0..25 {
+                    let bucket = BucketBuilder::default()
+                        .BucketName(format!("tenant-{}-{}", worker % 4, idx))
+                        .build()
+                        .unwrap();
+                    let logical_id = format!("Bucket{}x{}", worker, idx);
+                    let tenant = format!("tenant-{}", worker % 4);
+                    workspace.add_resource(&tenant, logical_id, bucket).unwrap();
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    workers
+        .into_iter()
+        .for_each(|worker| worker.join().unwrap());
+
+    assert_eq!(
+        workspace.names(),
+        vec!["tenant-0", "tenant-1", "tenant-2", "tenant-3"]
+    );
+    let templates = Arc::try_unwrap(workspace).unwrap().into_templates();
+    assert!(templates.values().all(|template| template.len() == 50));
+
+    // Output doesn't depend on the order threads added resources in
+    let ids = templates["tenant-0"]
+        .resources()
+        .map(|(id, _)| id.to_string())
+        .collect::<Vec<String>>();
+    let mut sorted = ids.clone();
+    sorted.sort();
+    assert_eq!(ids, sorted);
+}
+
+#[test]
+fn test_workspace_templates() {
+    let workspace = Workspace::new();
+    assert!(workspace.is_empty());
+
+    let mut template = Template::new();
+    template.set_description("Shared");
+    assert!(workspace.insert("shared", template).is_none());
+    workspace.update("shared", |template| {
+        template.set_description("Shared resources");
+    });
+    assert_eq!(
+        workspace.template("shared").unwrap().description(),
+        Some("Shared resources")
+    );
+
+    let bucket = || BucketBuilder::default().build().unwrap();
+    workspace
+        .add_resource("shared", "Bucket", bucket())
+        .unwrap();
+    match workspace.add_resource("shared", "Bucket", bucket()) {
+        Err(Error::DuplicateLogicalId(id)) => assert_eq!(id, "Bucket"),
+        other => panic!("Expected duplicate logical ID, got {:?}", other),
+    }
+    assert_eq!(workspace.len(), 1);
+}
@@ -0,0 +1,53 @@
+use caroline::types::AWS::DirectoryService::MicrosoftAD::{MicrosoftADBuilder, VpcSettingsBuilder};
+use caroline::types::AWS::WorkSpaces::Workspace::{WorkspaceBuilder, WorkspacePropertiesBuilder};
+use caroline::{Intrinsic, Template};
+use serde_json::json;
+
+#[test]
+fn test_workspace_in_directory() {
+    let directory = MicrosoftADBuilder::default()
+        .Name("corp.example.com")
+        .Password(Intrinsic::Ref("DirectoryPassword".into()))
+        .Edition("Standard")
+        .VpcSettings(
+            VpcSettingsBuilder::default()
+                .VpcId("vpc-0123")
+                .SubnetIds(vec!["subnet-0123".into(), "subnet-4567".into()])
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+    let workspace = WorkspaceBuilder::default()
+        .BundleId("wsb-0123")
+        .DirectoryId(Intrinsic::Ref("Directory".into()))
+        .UserName("jdoe")
+        .WorkspaceProperties(
+            WorkspacePropertiesBuilder::default()
+                .ComputeTypeName("STANDARD")
+                .RunningMode("AUTO_STOP")
+                .RunningModeAutoStopTimeoutInMinutes(60)
+                .build()
+                .unwrap(),
+        )
+        .build()
+        .unwrap();
+
+    let mut template = Template::new();
+    template.add_resource("Directory", directory).unwrap();
+    template.add_resource("Workspace", workspace).unwrap();
+    let issues = template.validate();
+    assert_eq!(issues.len(), 1);
+    assert_eq!(
+        issues[0].to_string(),
+        "Directory references unknown logical ID DirectoryPassword"
+    );
+    assert_eq!(
+        template.get("Workspace").unwrap().properties["WorkspaceProperties"],
+        json!({
+            "ComputeTypeName": "STANDARD",
+            "RunningMode": "AUTO_STOP",
+            "RunningModeAutoStopTimeoutInMinutes": 60
+        })
+    );
+}
@@ -0,0 +1,170 @@
+use caroline::types::AWS::EC2::VPC::{VPCBuilder, VPC};
+use caroline::types::AWS::S3::Bucket::BucketBuilder;
+use caroline::{Error, Template, COMMENT_KEY};
+use serde_json::json;
+
+const TEMPLATE: &str = r#"
+AWSTemplateFormatVersion: "2010-09-09"
+Description: Shared network, owned by the platform team
+Parameters:
+  Cidr:
+    Type: String
+    Description: Address range of the VPC
+Resources:
+  # The one VPC every service is deployed into.
+  # Peered with the on-premises network, don't change the range.
+  Vpc:
+    Type: AWS::EC2::VPC
+    Properties:
+      CidrBlock:
+        Ref: Cidr
+
+  Bucket:
+    # Not a resource comment
+    Type: AWS::S3::Bucket
+Outputs:
+  # Not a resource comment either
+  VpcId:
+    Value:
+      Ref: Vpc
+"#;
+
+#[test]
+fn test_yaml_comments_round_trip() {
+    let template = Template::from_yaml_with_comments(TEMPLATE).unwrap();
+    assert_eq!(
+        template.description(),
+        Some("Shared network, owned by the platform team")
+    );
+    assert_eq!(
+        template.get("Vpc").unwrap().metadata.get(COMMENT_KEY),
+        Some(&json!(
+            "The one VPC every service is deployed into.\nPeered with the on-premises network, don't change the range."
+        ))
+    );
+    assert!(template.get("Bucket").unwrap().metadata.is_empty());
+    assert!(template.resource::<VPC>("Vpc").is_ok());
+
+    let mut modified = template.clone();
+    modified.set_description("Shared network");
+    let yaml = modified.to_yaml().unwrap();
+    assert!(yaml.contains(
+        "  # The one VPC every service is deployed into.\n  # Peered with the on-premises network, don't change the range.\n  Vpc:\n"
+    ));
+    assert!(!yaml.contains(COMMENT_KEY));
+    assert!(yaml.contains("Description: Address range of the VPC"));
+
+    let parsed = Template::from_yaml_with_comments(&yaml).unwrap();
+    assert_eq!(parsed, modified);
+    assert!(Template::from_yaml(&yaml)
+        .unwrap()
+        .get("Vpc")
+        .unwrap()
+        .metadata
+        .is_empty());
+}
+
+#[test]
+fn test_short_form_intrinsics() {
+    let yaml = r#"
+Conditions:
+  IsProd: !Equals [!Ref Env, prod]
+Resources:
+  Subnet:
+    Type: AWS::EC2::Subnet
+    Properties:
+      VpcId: !Ref Vpc
+      CidrBlock: !GetAtt Vpc.CidrBlock
+      AvailabilityZone: !Select [0, !GetAZs ""]
+      Tags:
+        - Key: Name
+          Value: !Join ["-", [!Sub "${Env}-subnet", !GetAtt Db.Endpoint.Port]]
+        - Key: Tier
+          Value: !If [IsProd, !Ref "AWS::NoValue", !GetAtt [Vpc, DefaultSecurityGroup]]
+  Vpc:
+    Type: AWS::EC2::VPC
+    Condition: IsProd
+    Properties:
+      CidrBlock: 10.0.0.0/16
+"#;
+    let template = Template::from_yaml(yaml).unwrap();
+    assert_eq!(
+        template.get("Subnet").unwrap().properties,
+        json!({
+            "VpcId": {"Ref": "Vpc"},
+            "CidrBlock": {"Fn::GetAtt": ["Vpc", "CidrBlock"]},
+            "AvailabilityZone": {"Fn::Select": [0, {"Fn::GetAZs": ""}]},
+            "Tags": [
+                {
+                    "Key": "Name",
+                    "Value": {"Fn::Join": ["-", [
+                        {"Fn::Sub": "${Env}-subnet"},
+                        {"Fn::GetAtt": ["Db", "Endpoint.Port"]}
+                    ]]}
+                },
+                {
+                    "Key": "Tier",
+                    "Value": {"Fn::If": [
+                        "IsProd",
+                        {"Ref": "AWS::NoValue"},
+                        {"Fn::GetAtt": ["Vpc", "DefaultSecurityGroup"]}
+                    ]}
+                }
+            ]
+        })
+    );
+    assert_eq!(
+        template.conditions().collect::<Vec<_>>()[0].1,
+        &json!({"Fn::Equals": [{"Ref": "Env"}, "prod"]})
+    );
+
+    // Short and long forms read the same
+    let long_form = Template::from_yaml(&yaml.replace("!Ref Vpc", "{Ref: Vpc}").replace(
+        "!GetAtt Vpc.CidrBlock",
+        "{\"Fn::GetAtt\": [Vpc, CidrBlock]}",
+    ))
+    .unwrap();
+    assert_eq!(long_form, template);
+
+    match Template::from_yaml("Resources: [") {
+        Err(Error::Yaml(_)) => {}
+        other => panic!("Expected a YAML error, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_yaml_comments_layout() {
+    // Comments are placed by the layout serde_yaml writes, which this pins down
+    let mut template = Template::new();
+    template
+        .add_resource(
+            "Vpc",
+            VPCBuilder::default()
+                .CidrBlock("10.0.0.0/16")
+                .build()
+                .unwrap(),
+        )
+        .unwrap()
+        .metadata
+        .insert(COMMENT_KEY, "Shared\n\nby all services")
+        .unwrap();
+    template
+        .add_resource("Bucket", BucketBuilder::default().build().unwrap())
+        .unwrap();
+    assert_eq!(
+        template.to_yaml().unwrap(),
+        "AWSTemplateFormatVersion: 2010-09-09
+Resources:
+  Bucket:
+    Type: AWS::S3::Bucket
+    Properties: {}
+  # Shared
+  #
+  # by all services
+  Vpc:
+    Type: AWS::EC2::VPC
+    Properties:
+      CidrBlock: 10.0.0.0/16
+"
+    );
+}